@@ -0,0 +1,82 @@
+use crate::dining_philosophers::adversary_impl::crasher::Crasher;
+use crate::dining_philosophers::adversary_impl::greedy::Greedy;
+use crate::dining_philosophers::adversary_impl::hoarder::Hoarder;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// A philosopher who does not play by the rules, to seat among the philosophers of a strategy.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Adversary {
+    /// Never puts a fork down.
+    Greedy,
+    /// Grabs every fork it can and eats for `meal` steps at a time.
+    Hoarder { meal: usize },
+    /// Stops for good holding a fork, the first time it does after `after` steps.
+    Crasher { after: usize },
+}
+
+impl Adversary {
+    /// One of each, as the robustness report runs them.
+    pub const ALL: [Adversary; 3] = [Adversary::Greedy, Adversary::Hoarder { meal: 10 }, Adversary::Crasher { after: 10 }];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Adversary::Greedy => "greedy",
+            Adversary::Hoarder { .. } => "hoarder",
+            Adversary::Crasher { .. } => "crasher",
+        }
+    }
+}
+
+impl Strategy for Adversary {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        match *self {
+            Adversary::Greedy => Box::new(Greedy::new(table_interaction)),
+            Adversary::Hoarder { meal } => Box::new(Hoarder::new(table_interaction, meal)),
+            Adversary::Crasher { after } => {
+                let position = table_interaction.position;
+                Box::new(Crasher::new(position, Phase::Thinking(Thinking::new(table_interaction)), after))
+            }
+        }
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::adversary_impl::adversary::Adversary;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+
+    #[test]
+    fn every_adversary_has_its_own_name() {
+        let names: Vec<&str> = Adversary::ALL.iter().map(|adversary| adversary.name()).collect();
+
+        assert_eq!(names, vec!["greedy", "hoarder", "crasher"]);
+    }
+
+    #[test]
+    fn a_table_of_greedy_philosophers_deadlocks() {
+        let results = run_in_lockstep(&mut Adversary::Greedy, 5, 10);
+
+        for history in results.values() {
+            assert_eq!(history[10], State::LeftThinking);
+        }
+    }
+
+    #[test]
+    fn every_adversary_ends_up_holding_a_fork() {
+        for &adversary in Adversary::ALL.iter() {
+            let results = run_in_lockstep(&mut { adversary }, 2, 40);
+
+            assert!(results.values().any(|history| history[40] != State::Thinking), "{:?}", adversary);
+        }
+    }
+}
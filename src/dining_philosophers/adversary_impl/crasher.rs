@@ -0,0 +1,80 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+
+/// Gives way like the philosophers of the original state machine until `after` steps have gone
+/// by, then crashes the next time it holds a fork: it never moves again, and never puts the fork
+/// back.
+#[derive(Debug, PartialEq)]
+pub struct Crasher {
+    position: usize,
+    phase: Option<Phase>,
+    steps: usize,
+    after: usize,
+}
+
+impl Crasher {
+    pub fn new(position: usize, phase: Phase, after: usize) -> Crasher {
+        Crasher {
+            position,
+            phase: Some(phase),
+            steps: 0,
+            after,
+        }
+    }
+
+    fn crashed(&self) -> bool {
+        self.steps >= self.after && self.state() != State::Thinking
+    }
+}
+
+impl StateMachine for Crasher {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        let crashed = self.crashed();
+        match self.phase.take() {
+            None => { panic!("No longer valid") }
+            Some(phase) if crashed => {
+                debug!("{}: Crashed, still {:?}", self.position, phase.state());
+                Box::new(Crasher { phase: Some(phase), ..*self })
+            }
+            Some(phase) => Box::new(Crasher { phase: Some(phase.next()), steps: self.steps + 1, ..*self }),
+        }
+    }
+
+    fn state(&self) -> State {
+        match &self.phase {
+            None => { panic!("No longer valid") }
+            Some(phase) => phase.state(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::adversary_impl::crasher::Crasher;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::typestate_impl::phase::Phase;
+    use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+    fn crasher(after: usize) -> Box<dyn StateMachine> {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        Box::new(Crasher::new(1, Phase::Thinking(Thinking::new(table_interaction)), after))
+    }
+
+    fn states(mut unit: Box<dyn StateMachine>, steps: usize) -> Vec<State> {
+        (0..steps).map(|_| {
+            unit = unit.transition();
+            unit.state()
+        }).collect()
+    }
+
+    #[test]
+    fn gives_way_before_crashing() {
+        assert_eq!(states(crasher(10), 4), vec![State::LeftThinking, State::Eating, State::RightThinking, State::Eating]);
+    }
+
+    #[test]
+    fn stops_for_good_holding_a_fork() {
+        assert_eq!(states(crasher(3), 6), vec![State::LeftThinking, State::Eating, State::RightThinking, State::RightThinking, State::RightThinking, State::RightThinking]);
+    }
+}
@@ -0,0 +1,103 @@
+use std::mem;
+
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::hands::Hands;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Takes whichever of its forks is on the table, one at a time, and never puts a fork down.
+#[derive(Debug, PartialEq)]
+pub struct Greedy {
+    state: State,
+    hands: Hands,
+    table_interaction: Option<TableInteraction>,
+}
+
+impl Greedy {
+    pub fn new(table_interaction: TableInteraction) -> Greedy {
+        Greedy {
+            state: State::Thinking,
+            hands: Hands::default(),
+            table_interaction: Some(table_interaction),
+        }
+    }
+
+    /// The forks still missing, in the order it reaches for them.
+    fn wanted(&self) -> &'static [Action] {
+        match self.state {
+            State::Thinking => &[Action::TakeLeft, Action::TakeRight],
+            State::LeftThinking => &[Action::TakeRight],
+            State::RightThinking => &[Action::TakeLeft],
+            _ => &[],
+        }
+    }
+}
+
+impl StateMachine for Greedy {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.table_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(t) => {
+                let taken = self.wanted().iter().find(|action| self.hands.act(**action, &t));
+                let state = match taken {
+                    Some(action) => action.next(&self.state).unwrap(),
+                    None => self.state.clone(),
+                };
+                debug!("{}: Greedy, {:?} to {:?}", t.position, self.state, state);
+                Box::new(Greedy {
+                    state,
+                    hands: mem::take(&mut self.hands),
+                    table_interaction: Some(t),
+                })
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        self.state.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::adversary_impl::greedy::Greedy;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+
+    #[test]
+    fn takes_the_right_fork_when_the_left_is_taken() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let _left_fork = table_interaction.get_left_fork().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Greedy::new(table_interaction));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::RightThinking);
+    }
+
+    #[test]
+    fn keeps_its_forks_forever() {
+        let mut interactions = Table::new(2).get_interactions();
+        let mut unit: Box<dyn StateMachine> = Box::new(Greedy::new(interactions.pop().unwrap()));
+        let neighbour = interactions.pop().unwrap();
+
+        for _ in 0..10 {
+            unit = unit.transition();
+        }
+
+        assert_eq!(unit.state(), State::Eating);
+        assert!(!neighbour.left_fork_on_table());
+        assert!(!neighbour.right_fork_on_table());
+    }
+
+    #[test]
+    fn holds_one_fork_while_waiting_for_the_other() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Greedy::new(table_interaction));
+
+        unit = unit.transition();
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+}
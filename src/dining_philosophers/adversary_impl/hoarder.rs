@@ -0,0 +1,121 @@
+use std::mem;
+
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::hands::Hands;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Grabs both forks it shares with its neighbours whenever they are on the table, and any one of
+/// them it can get otherwise. It never gives a fork back before eating, then keeps both for a
+/// long meal and grabs them again as soon as it has put them down.
+#[derive(Debug, PartialEq)]
+pub struct Hoarder {
+    state: State,
+    hands: Hands,
+    table_interaction: Option<TableInteraction>,
+    meal: usize,
+    eaten: usize,
+}
+
+impl Hoarder {
+    pub fn new(table_interaction: TableInteraction, meal: usize) -> Hoarder {
+        Hoarder {
+            state: State::Thinking,
+            hands: Hands::default(),
+            table_interaction: Some(table_interaction),
+            meal,
+            eaten: 0,
+        }
+    }
+
+    /// The actions it tries, in order, until one goes through.
+    fn grabs(&self) -> &'static [Action] {
+        match self.state {
+            State::Thinking => &[Action::TakeBoth, Action::TakeLeft, Action::TakeRight],
+            State::LeftThinking => &[Action::TakeRight],
+            State::RightThinking => &[Action::TakeLeft],
+            _ => &[],
+        }
+    }
+}
+
+impl StateMachine for Hoarder {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.table_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(t) => {
+                let mut eaten = 0;
+                let state = match self.state {
+                    State::Eating if self.eaten + 1 < self.meal => {
+                        eaten = self.eaten + 1;
+                        State::Eating
+                    }
+                    State::Eating => {
+                        self.hands.act(Action::PutBoth, &t);
+                        State::Thinking
+                    }
+                    _ => match self.grabs().iter().find(|action| self.hands.act(**action, &t)) {
+                        Some(action) => action.next(&self.state).unwrap(),
+                        None => self.state.clone(),
+                    },
+                };
+                debug!("{}: Hoarding, {:?} to {:?}", t.position, self.state, state);
+                Box::new(Hoarder {
+                    state,
+                    hands: mem::take(&mut self.hands),
+                    table_interaction: Some(t),
+                    meal: self.meal,
+                    eaten,
+                })
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        self.state.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::adversary_impl::hoarder::Hoarder;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+
+    fn states(unit: Box<dyn StateMachine>, steps: usize) -> Vec<State> {
+        let mut unit = unit;
+        (0..steps).map(|_| {
+            unit = unit.transition();
+            unit.state()
+        }).collect()
+    }
+
+    #[test]
+    fn grabs_both_forks_at_once() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+
+        assert_eq!(states(Box::new(Hoarder::new(table_interaction, 1)), 1), vec![State::Eating]);
+    }
+
+    #[test]
+    fn eats_a_whole_meal_then_grabs_the_forks_again() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+
+        assert_eq!(
+            states(Box::new(Hoarder::new(table_interaction, 3)), 6),
+            vec![State::Eating, State::Eating, State::Eating, State::Thinking, State::Eating, State::Eating]
+        );
+    }
+
+    #[test]
+    fn keeps_the_one_fork_it_could_get() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let _right_fork = table_interaction.get_right_fork().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Hoarder::new(table_interaction, 1));
+
+        unit = unit.transition();
+        assert_eq!(unit.state(), State::LeftThinking);
+        unit = unit.transition();
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+}
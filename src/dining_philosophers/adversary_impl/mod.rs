@@ -0,0 +1,5 @@
+pub mod greedy;
+pub mod hoarder;
+pub mod crasher;
+pub mod adversary;
+pub mod robustness;
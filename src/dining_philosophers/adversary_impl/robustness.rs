@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::dining_philosophers::adversary_impl::adversary::Adversary;
+use crate::dining_philosophers::analysis::{score_one_run, RunResults};
+use crate::dining_philosophers::mixed::Mixed;
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::registry::Registry;
+use crate::dining_philosophers::simulation::{run_in_lockstep, NewStrategy};
+
+/// What one adversary at the last seat did to the philosophers of a strategy.
+#[derive(Debug, PartialEq)]
+pub struct Damage {
+    pub strategy: &'static str,
+    pub adversary: &'static str,
+    /// `score_one_run` of the other seats, with a philosopher of the strategy at the last seat.
+    pub baseline: f32,
+    /// `score_one_run` of the other seats, with the adversary at the last seat.
+    pub score: f32,
+    /// The adversary's neighbours who did not eat in the second half of the run.
+    pub starved_neighbours: usize,
+}
+
+impl Damage {
+    pub fn score_change(&self) -> f32 {
+        self.score - self.baseline
+    }
+}
+
+/// Runs the strategy once on its own and once with the adversary at the last seat, both in
+/// lockstep so the report comes out the same every time.
+pub fn stress(strategy: &'static str, new_strategy: &NewStrategy, adversary: Adversary, number_of_philosophers: usize, rounds: usize) -> Damage {
+    let last = number_of_philosophers - 1;
+    let baseline = run_in_lockstep(new_strategy(number_of_philosophers).as_mut(), number_of_philosophers, rounds);
+    let mut mixed = Mixed::new()
+        .seating(strategy, new_strategy(number_of_philosophers), last)
+        .seating(adversary.name(), Box::new(adversary), 1);
+    let stressed = run_in_lockstep(&mut mixed, number_of_philosophers, rounds);
+
+    Damage {
+        strategy,
+        adversary: adversary.name(),
+        baseline: score_one_run(&without(&baseline, last)),
+        score: score_one_run(&without(&stressed, last)),
+        starved_neighbours: neighbours(last, number_of_philosophers).iter()
+            .filter(|position| starved(&stressed[position]))
+            .count(),
+    }
+}
+
+/// Every registered strategy against every adversary. Strategies that do not share the forks of
+/// the `Table` cannot seat an adversary and are left out.
+///
+/// # Panics
+///
+/// Panics if there are fewer than three philosophers, so that the adversary has two neighbours.
+pub fn robustness_report(registry: &Registry, adversaries: &[Adversary], number_of_philosophers: usize, rounds: usize) -> Vec<Damage> {
+    assert!(number_of_philosophers > 2);
+    registry.names().into_iter()
+        .filter(|name| registry.get(name).unwrap()(number_of_philosophers).shares_table())
+        .flat_map(|name| {
+            let new_strategy = registry.get(name).unwrap();
+            adversaries.iter()
+                .map(move |adversary| stress(name, &new_strategy, *adversary, number_of_philosophers, rounds))
+                .collect::<Vec<Damage>>()
+        })
+        .collect()
+}
+
+fn without(results: &RunResults, position: usize) -> RunResults {
+    results.iter()
+        .filter(|(id, _)| **id != position)
+        .map(|(id, history)| (*id, history.clone()))
+        .collect::<HashMap<usize, Vec<State>>>()
+}
+
+fn neighbours(position: usize, number_of_philosophers: usize) -> [usize; 2] {
+    [(position + number_of_philosophers - 1) % number_of_philosophers, (position + 1) % number_of_philosophers]
+}
+
+fn starved(history: &[State]) -> bool {
+    !history[history.len() / 2..].contains(&State::Eating)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::adversary_impl::adversary::Adversary;
+    use crate::dining_philosophers::adversary_impl::robustness::{robustness_report, stress};
+    use crate::dining_philosophers::registry::Registry;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::NewStrategy;
+
+    fn resource_hierarchy() -> NewStrategy {
+        Arc::new(|_| Box::new(ResourceHierarchy::new()))
+    }
+
+    #[test]
+    fn a_greedy_neighbour_starves_the_resource_hierarchy() {
+        let damage = stress("resource-hierarchy", &resource_hierarchy(), Adversary::Greedy, 5, 100);
+
+        assert_eq!(damage.adversary, "greedy");
+        assert_eq!(damage.starved_neighbours, 2);
+        assert!(damage.score_change() < 0.0, "{:?}", damage);
+    }
+
+    #[test]
+    fn the_baseline_is_the_strategy_on_its_own() {
+        let greedy = stress("resource-hierarchy", &resource_hierarchy(), Adversary::Greedy, 5, 100);
+        let crasher = stress("resource-hierarchy", &resource_hierarchy(), Adversary::Crasher { after: 10 }, 5, 100);
+
+        assert_eq!(greedy.baseline, crasher.baseline);
+    }
+
+    #[test]
+    fn reports_every_strategy_against_every_adversary() {
+        let registry = Registry::new();
+
+        let report = robustness_report(&registry, &Adversary::ALL, 5, 50);
+
+        assert_eq!(report.len(), (registry.names().len() - 1) * Adversary::ALL.len());
+        assert!(report.iter().all(|damage| damage.strategy != "chandy-misra"));
+        assert_eq!((report[0].strategy, report[0].adversary), ("resource-hierarchy", "greedy"));
+        assert!(report.iter().all(|damage| damage.starved_neighbours <= 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn needs_two_neighbours_for_the_adversary() {
+        robustness_report(&Registry::new(), &Adversary::ALL, 2, 10);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// The baseline: philosophers who never reach for a fork, and so never eat.
+pub struct AlwaysThinking {}
+
+impl StateMachine for AlwaysThinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        Box::new(AlwaysThinking {})
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+impl Strategy for AlwaysThinking {
+    fn seat(&mut self, _table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(AlwaysThinking {})
+    }
+
+    /// Never reaches for a fork, so it can sit anywhere.
+    fn fits(&self, _topology: &Topology) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::always_thinking::AlwaysThinking;
+    use crate::dining_philosophers::analysis::score_one_run;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+
+    #[test]
+    fn keeps_thinking() {
+        let mut unit: Box<dyn StateMachine> = Box::new(AlwaysThinking {});
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    fn scores_nothing() {
+        let results = run_in_lockstep(&mut AlwaysThinking {}, 5, 10);
+
+        assert_eq!(score_one_run(&results), 0.0);
+    }
+}
@@ -2,9 +2,19 @@ use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 
 use crate::dining_philosophers::philosopher::state_machine::State;
-use crate::dining_philosophers::philosopher::state_machine::State::Eating;
+use crate::dining_philosophers::philosopher::state_machine::State::{Drinking, Eating, Thirsty};
 
-fn calculate_percentage(history: &Vec<State>) -> f32 {
+/// The history of every philosopher in one run, keyed by philosopher id.
+pub type RunResults = HashMap<usize, Vec<State>>;
+
+/// Named counts kept by a strategy during one run, such as requests granted or refused.
+pub type Counters = Vec<(&'static str, usize)>;
+
+/// The name of the strategy seated at every position of a table, in seat order.
+pub type Seating = Vec<&'static str>;
+
+/// The share of the history spent not eating, whether thinking, hungry or holding one fork.
+fn calculate_percentage(history: &[State]) -> f32 {
     let total: f32 = history.len() as f32;
     let no_of_thinking: i32 = history.iter()
         .map(|s| { if s != &Eating { 1 } else { 0 } })
@@ -17,19 +27,29 @@ fn score(percentage: f32) -> f32 {
     1_f32 - (percentage / 50_f32 - 1_f32).abs()
 }
 
-pub fn score_one_run(results: &HashMap<usize, Vec<State>>) -> f32 {
-    let total_score: f32 = results.iter()
-        .map(|(_key, value)| {
-            let perc = calculate_percentage(value);
-            score(perc)
-        })
+/// One philosopher's part of the score: 1 when it ate for half its history, falling to 0 when it
+/// always or never ate.
+pub fn score_history(history: &[State]) -> f32 {
+    score(calculate_percentage(history))
+}
+
+/// The histories of a run in seat order, so that sums over them come out the same every time.
+fn in_seat_order(results: &RunResults) -> Vec<&Vec<State>> {
+    let mut seats: Vec<(&usize, &Vec<State>)> = results.iter().collect();
+    seats.sort_by_key(|(id, _)| **id);
+    seats.into_iter().map(|(_, history)| history).collect()
+}
+
+pub fn score_one_run(results: &RunResults) -> f32 {
+    let total_score: f32 = in_seat_order(results).into_iter()
+        .map(|value| score_history(value))
         .sum();
     let normalised_score = total_score / results.keys().len() as f32;
 
     normalised_score
 }
 
-pub fn compute_average_score(results: &Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>>) -> f32 {
+pub fn compute_average_score(results: &[Arc<Mutex<RunResults>>]) -> f32 {
     let total_score: f32 = results.iter()
         .map(|map| {
             score_one_run(&map.lock().unwrap())
@@ -38,14 +58,168 @@ pub fn compute_average_score(results: &Vec<Arc<Mutex<HashMap<usize, Vec<State>>>
     total_score / results.len() as f32
 }
 
+/// Every philosopher's part of the score, in seat order.
+pub fn score_by_philosopher(results: &RunResults) -> Vec<(usize, f32)> {
+    let mut scores: Vec<(usize, f32)> = results.iter()
+        .map(|(id, history)| (*id, score_history(history)))
+        .collect();
+    scores.sort_by_key(|(id, _)| *id);
+    scores
+}
+
+/// The mean score of the philosophers of every strategy at the table, in the order the strategies
+/// were first seated.
+pub fn score_by_strategy(results: &RunResults, seating: &[&'static str]) -> Vec<(&'static str, f32)> {
+    let mut totals: Vec<(&'static str, f32, usize)> = Vec::new();
+    for (id, score) in score_by_philosopher(results) {
+        let name = seating[id];
+        match totals.iter_mut().find(|(n, _, _)| *n == name) {
+            Some((_, total, seats)) => {
+                *total += score;
+                *seats += 1;
+            }
+            None => totals.push((name, score, 1)),
+        }
+    }
+    totals.into_iter()
+        .map(|(name, total, seats)| (name, total / seats as f32))
+        .collect()
+}
+
+pub fn compute_average_score_by_philosopher(results: &[Arc<Mutex<RunResults>>]) -> Vec<(usize, f32)> {
+    average_by_key(results.iter().map(|map| score_by_philosopher(&map.lock().unwrap())).collect())
+}
+
+pub fn compute_average_score_by_strategy(results: &[Arc<Mutex<RunResults>>], seating: &[&'static str]) -> Vec<(&'static str, f32)> {
+    average_by_key(results.iter().map(|map| score_by_strategy(&map.lock().unwrap(), seating)).collect())
+}
+
+/// Averages the scores of several runs that list the same keys in the same order.
+fn average_by_key<K: Copy>(runs: Vec<Vec<(K, f32)>>) -> Vec<(K, f32)> {
+    let count = runs.len() as f32;
+    let mut totals: Vec<(K, f32)> = match runs.first() {
+        Some(first) => first.iter().map(|(key, _)| (*key, 0_f32)).collect(),
+        None => return Vec::new(),
+    };
+    for run in runs {
+        for (total, (_, score)) in totals.iter_mut().zip(run) {
+            total.1 += score;
+        }
+    }
+    totals.into_iter()
+        .map(|(key, total)| (key, total / count))
+        .collect()
+}
+
+/// Jain's fairness index of the meals eaten in one run: 1 when every philosopher ate equally
+/// often, falling towards 1/N as the meals go to fewer philosophers. A run without meals is fair.
+pub fn fairness_one_run(results: &RunResults) -> f32 {
+    let meals: Vec<f32> = in_seat_order(results).into_iter()
+        .map(|history| history.iter().filter(|s| **s == Eating).count() as f32)
+        .collect();
+    let total: f32 = meals.iter().sum();
+    let squares: f32 = meals.iter().map(|m| m * m).sum();
+    if squares == 0_f32 {
+        return 1_f32;
+    }
+    total * total / (meals.len() as f32 * squares)
+}
+
+pub fn compute_average_fairness(results: &[Arc<Mutex<RunResults>>]) -> f32 {
+    let total_fairness: f32 = results.iter()
+        .map(|map| fairness_one_run(&map.lock().unwrap()))
+        .sum();
+    total_fairness / results.len() as f32
+}
+
+/// The most consecutive steps any philosopher of one run spent without eating, or without
+/// drinking at a table of drinking philosophers.
+pub fn longest_starvation_one_run(results: &RunResults) -> usize {
+    results.values()
+        .flat_map(|history| history.split(|s| *s == Eating || *s == Drinking))
+        .map(|streak| streak.len())
+        .max()
+        .unwrap_or(0)
+}
+
+/// The longest starvation streak over all the runs.
+pub fn compute_longest_starvation(results: &[Arc<Mutex<RunResults>>]) -> usize {
+    results.iter()
+        .map(|map| longest_starvation_one_run(&map.lock().unwrap()))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The drinking sessions in one history: every stretch of consecutive `Drinking` steps.
+fn sessions(history: &[State]) -> usize {
+    history.iter().enumerate()
+        .filter(|(i, s)| **s == Drinking && (*i == 0 || history[i - 1] != Drinking))
+        .count()
+}
+
+/// The drinking sessions of the whole table per step of one run.
+pub fn session_throughput_one_run(results: &RunResults) -> f32 {
+    let steps = results.values().map(|history| history.len().saturating_sub(1)).max().unwrap_or(0);
+    if steps == 0 {
+        return 0_f32;
+    }
+    let total: usize = results.values().map(|history| sessions(history)).sum();
+    total as f32 / steps as f32
+}
+
+pub fn compute_average_session_throughput(results: &[Arc<Mutex<RunResults>>]) -> f32 {
+    let total_throughput: f32 = results.iter()
+        .map(|map| session_throughput_one_run(&map.lock().unwrap()))
+        .sum();
+    total_throughput / results.len() as f32
+}
+
+/// The mean steps a drinking philosopher of one run spent thirsty before each session, counting a
+/// thirst still unquenched when the run ended. A run without thirst waited for nothing.
+pub fn wait_time_one_run(results: &RunResults) -> f32 {
+    let thirsty: usize = results.values()
+        .map(|history| history.iter().filter(|s| **s == Thirsty).count())
+        .sum();
+    let thirsts: usize = results.values()
+        .map(|history| sessions(history) + if history.last() == Some(&Thirsty) { 1 } else { 0 })
+        .sum();
+    if thirsts == 0 {
+        return 0_f32;
+    }
+    thirsty as f32 / thirsts as f32
+}
+
+pub fn compute_average_wait_time(results: &[Arc<Mutex<RunResults>>]) -> f32 {
+    let total_wait: f32 = results.iter()
+        .map(|map| wait_time_one_run(&map.lock().unwrap()))
+        .sum();
+    total_wait / results.len() as f32
+}
+
+/// Averages every counter over the runs, keeping the order in which the counters were first seen.
+pub fn average_counters(counters: &[Arc<Mutex<Counters>>]) -> Vec<(&'static str, f32)> {
+    let mut totals: Vec<(&'static str, usize)> = Vec::new();
+    for run in counters {
+        for &(name, count) in run.lock().unwrap().iter() {
+            match totals.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, total)) => *total += count,
+                None => totals.push((name, count)),
+            }
+        }
+    }
+    totals.into_iter()
+        .map(|(name, total)| (name, total as f32 / counters.len() as f32))
+        .collect()
+}
+
 
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
 
-    use crate::dining_philosophers::analysis::{calculate_percentage, compute_average_score, score, score_one_run};
-    use crate::dining_philosophers::philosopher::state_machine::State::{Eating, LeftThinking, RightThinking, Thinking};
+    use crate::dining_philosophers::analysis::{average_counters, calculate_percentage, compute_average_fairness, compute_average_score, compute_average_score_by_philosopher, compute_average_score_by_strategy, compute_average_session_throughput, compute_average_wait_time, compute_longest_starvation, fairness_one_run, longest_starvation_one_run, score, score_by_philosopher, score_by_strategy, score_history, score_one_run, session_throughput_one_run, wait_time_one_run, Counters, RunResults};
+    use crate::dining_philosophers::philosopher::state_machine::State::{Drinking, Eating, Hungry, LeftThinking, RightThinking, Thinking, Thirsty, Tranquil};
     use crate::dining_philosophers::philosopher::state_machine::State;
 
     #[test]
@@ -72,6 +246,14 @@ mod tests {
         assert_eq!(50_f32, percentage);
     }
 
+    #[test]
+    fn calculate_percentage_counts_hungry_as_not_eating() {
+        let results = vec![Thinking, Hungry, Hungry, Eating];
+        let percentage = calculate_percentage(&results);
+
+        assert_eq!(75_f32, percentage);
+    }
+
     #[test]
     fn calculate_score_based_on_percentage() {
         assert_eq!(0.5_f32, score(75_f32));
@@ -92,6 +274,13 @@ mod tests {
         assert_eq!(0_f32, score(0_f32));
     }
 
+    #[test]
+    fn score_of_one_history() {
+        assert_eq!(1_f32, score_history(&[Thinking, Eating]));
+        assert_eq!(0.5_f32, score_history(&[Thinking, Thinking, Thinking, Eating]));
+        assert_eq!(0_f32, score_history(&[Eating]));
+    }
+
     #[test]
     fn min_score_a_run() {
         let mut results: HashMap<usize, Vec<State>> = HashMap::new();
@@ -124,9 +313,9 @@ mod tests {
 
     #[test]
     fn average_score_over_several_runs() {
-        let mut results: Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>> = Vec::new();
-        let run_one: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
-        let run_two: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
+        let mut results: Vec<Arc<Mutex<RunResults>>> = Vec::new();
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
 
         run_one.lock().unwrap().insert(0, vec![Thinking, Thinking]);
         run_one.lock().unwrap().insert(1, vec![Thinking, Eating]);
@@ -141,9 +330,9 @@ mod tests {
 
     #[test]
     fn max_average_score_over_several_runs() {
-        let mut results: Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>> = Vec::new();
-        let run_one: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
-        let run_two: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
+        let mut results: Vec<Arc<Mutex<RunResults>>> = Vec::new();
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
 
         run_one.lock().unwrap().insert(0, vec![Thinking, Eating]);
         run_one.lock().unwrap().insert(1, vec![Thinking, Eating]);
@@ -158,9 +347,9 @@ mod tests {
 
     #[test]
     fn min_average_score_over_several_runs() {
-        let mut results: Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>> = Vec::new();
-        let run_one: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
-        let run_two: Arc<Mutex<HashMap<usize, Vec<State>>>> = Arc::new(Mutex::new(HashMap::new()));
+        let mut results: Vec<Arc<Mutex<RunResults>>> = Vec::new();
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
 
         run_one.lock().unwrap().insert(0, vec![Thinking, Thinking]);
         run_one.lock().unwrap().insert(1, vec![Eating, Eating]);
@@ -172,4 +361,179 @@ mod tests {
 
         assert_eq!(0_f32, compute_average_score(&results));
     }
+
+    #[test]
+    fn fair_run_when_everyone_eats_equally() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Thinking, Eating]);
+        results.insert(1, vec![Eating, Thinking]);
+
+        assert_eq!(1_f32, fairness_one_run(&results));
+    }
+
+    #[test]
+    fn least_fair_run_when_one_philosopher_eats_every_meal() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Eating, Eating]);
+        results.insert(1, vec![Thinking, Thinking]);
+        results.insert(2, vec![Thinking, Thinking]);
+        results.insert(3, vec![Thinking, Thinking]);
+
+        assert_eq!(0.25_f32, fairness_one_run(&results));
+    }
+
+    #[test]
+    fn run_without_meals_is_fair() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Thinking]);
+        results.insert(1, vec![Thinking]);
+
+        assert_eq!(1_f32, fairness_one_run(&results));
+    }
+
+    #[test]
+    fn average_fairness_over_several_runs() {
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        run_one.lock().unwrap().insert(0, vec![Eating]);
+        run_one.lock().unwrap().insert(1, vec![Eating]);
+        run_two.lock().unwrap().insert(0, vec![Eating]);
+        run_two.lock().unwrap().insert(1, vec![Thinking]);
+
+        assert_eq!(0.75_f32, compute_average_fairness(&[run_one, run_two]));
+    }
+
+    #[test]
+    fn longest_starvation_is_the_longest_streak_between_meals() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Thinking, Eating, Thinking, LeftThinking, Hungry, Eating]);
+        results.insert(1, vec![Eating, Thinking, Eating, Thinking]);
+
+        assert_eq!(3, longest_starvation_one_run(&results));
+    }
+
+    #[test]
+    fn score_of_every_philosopher_in_seat_order() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(2, vec![Eating, Eating]);
+        results.insert(0, vec![Eating, Thinking]);
+        results.insert(1, vec![Thinking, Thinking]);
+
+        assert_eq!(score_by_philosopher(&results), vec![(0, 1_f32), (1, 0_f32), (2, 0_f32)]);
+    }
+
+    #[test]
+    fn score_of_every_strategy_is_the_mean_of_its_philosophers() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Eating, Thinking]);
+        results.insert(1, vec![Eating, Eating]);
+        results.insert(2, vec![Thinking, Eating]);
+
+        let seating = vec!["greedy", "waiter", "greedy"];
+
+        assert_eq!(score_by_strategy(&results, &seating), vec![("greedy", 1_f32), ("waiter", 0_f32)]);
+    }
+
+    #[test]
+    fn score_of_every_strategy_over_several_runs() {
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        run_one.lock().unwrap().insert(0, vec![Eating, Thinking]);
+        run_one.lock().unwrap().insert(1, vec![Thinking, Thinking]);
+        run_two.lock().unwrap().insert(0, vec![Eating, Eating]);
+        run_two.lock().unwrap().insert(1, vec![Thinking, Eating]);
+        let runs = [run_one, run_two];
+
+        assert_eq!(compute_average_score_by_strategy(&runs, &["waiter", "greedy"]), vec![("waiter", 0.5_f32), ("greedy", 0.5_f32)]);
+        assert_eq!(compute_average_score_by_philosopher(&runs), vec![(0, 0.5_f32), (1, 0.5_f32)]);
+    }
+
+    #[test]
+    fn longest_starvation_of_a_philosopher_who_never_ate_is_its_whole_history() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Thinking; 4]);
+        results.insert(1, vec![Eating; 4]);
+
+        assert_eq!(4, longest_starvation_one_run(&results));
+    }
+
+    #[test]
+    fn longest_starvation_counts_a_drink_as_a_meal() {
+        let mut results: RunResults = HashMap::new();
+        results.insert(0, vec![Tranquil, Thirsty, Drinking, Thirsty, Thirsty, Thirsty, Drinking]);
+
+        assert_eq!(3, longest_starvation_one_run(&results));
+    }
+
+    #[test]
+    fn longest_starvation_over_several_runs() {
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        run_one.lock().unwrap().insert(0, vec![Thinking, Eating]);
+        run_two.lock().unwrap().insert(0, vec![Thinking, Thinking, Eating]);
+
+        assert_eq!(2, compute_longest_starvation(&[run_one, run_two]));
+    }
+
+    #[test]
+    fn average_counters_over_several_runs() {
+        let run_one: Arc<Mutex<Counters>> = Arc::new(Mutex::new(vec![("grants", 4), ("refusals", 1)]));
+        let run_two: Arc<Mutex<Counters>> = Arc::new(Mutex::new(vec![("grants", 2), ("refusals", 3)]));
+
+        assert_eq!(average_counters(&[run_one, run_two]), vec![("grants", 3_f32), ("refusals", 2_f32)]);
+    }
+
+    #[test]
+    fn average_counters_treats_a_missing_counter_as_zero() {
+        let run_one: Arc<Mutex<Counters>> = Arc::new(Mutex::new(vec![("grants", 4)]));
+        let run_two: Arc<Mutex<Counters>> = Arc::new(Mutex::new(vec![]));
+
+        assert_eq!(average_counters(&[run_one, run_two]), vec![("grants", 2_f32)]);
+    }
+
+    #[test]
+    fn session_throughput_counts_every_stretch_of_drinking() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Tranquil, Drinking, Drinking, Tranquil, Drinking]);
+        results.insert(1, vec![Tranquil, Thirsty, Thirsty, Drinking, Tranquil]);
+
+        assert_eq!(0.75_f32, session_throughput_one_run(&results));
+    }
+
+    #[test]
+    fn no_session_throughput_without_steps() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Tranquil]);
+
+        assert_eq!(0_f32, session_throughput_one_run(&results));
+    }
+
+    #[test]
+    fn wait_time_is_the_mean_thirst_before_each_session() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Tranquil, Drinking, Tranquil, Thirsty, Thirsty, Thirsty, Drinking]);
+        results.insert(1, vec![Tranquil, Thirsty, Drinking, Tranquil, Thirsty, Thirsty]);
+
+        assert_eq!(1.5_f32, wait_time_one_run(&results));
+    }
+
+    #[test]
+    fn no_wait_time_without_thirst() {
+        let mut results: HashMap<usize, Vec<State>> = HashMap::new();
+        results.insert(0, vec![Tranquil, Tranquil]);
+
+        assert_eq!(0_f32, wait_time_one_run(&results));
+    }
+
+    #[test]
+    fn average_session_metrics_over_several_runs() {
+        let run_one: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        let run_two: Arc<Mutex<RunResults>> = Arc::new(Mutex::new(HashMap::new()));
+        run_one.lock().unwrap().insert(0, vec![Tranquil, Drinking, Tranquil]);
+        run_two.lock().unwrap().insert(0, vec![Tranquil, Thirsty, Thirsty]);
+        let runs = [run_one, run_two];
+
+        assert_eq!(0.25_f32, compute_average_session_throughput(&runs));
+        assert_eq!(1_f32, compute_average_wait_time(&runs));
+    }
 }
@@ -0,0 +1,139 @@
+use crate::dining_philosophers::chandy_misra_impl::neighbours::Neighbours;
+use crate::dining_philosophers::chandy_misra_impl::shared_fork::SharedFork;
+use crate::dining_philosophers::chandy_misra_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// The Chandy–Misra solution: forks belong to the philosophers and only change hands
+/// through messages between neighbours, so the shared table is never touched.
+pub struct ChandyMisra {
+    seats: Vec<Option<Neighbours>>,
+}
+
+impl ChandyMisra {
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two philosophers, who would have to ask themselves for forks.
+    pub fn new(number_of_philosophers: usize) -> ChandyMisra {
+        ChandyMisra {
+            seats: ChandyMisra::neighbours(number_of_philosophers).into_iter().map(Some).collect(),
+        }
+    }
+
+    /// Hands out every fork of the round table, dirty, to the lower-numbered of the two philosophers
+    /// sharing it. The request token goes to the other one.
+    pub fn neighbours(number_of_philosophers: usize) -> Vec<Neighbours> {
+        assert!(number_of_philosophers > 1);
+
+        ChandyMisra::neighbours_on(&Topology::ring(number_of_philosophers))
+    }
+
+    /// Hands out the forks of any conflict graph the same way.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fork is not shared by exactly two philosophers.
+    pub fn neighbours_on(topology: &Topology) -> Vec<Neighbours> {
+        ChandyMisra::shared_forks_on(topology).into_iter()
+            .enumerate()
+            .map(|(position, forks)| Neighbours::with_forks(position, forks))
+            .collect()
+    }
+
+    /// Creates both ends of every fork of a conflict graph. The lower-numbered of the two
+    /// philosophers sharing a fork starts with it, and each philosopher gets its ends in the order
+    /// it needs the forks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a fork is not shared by exactly two philosophers.
+    pub fn shared_forks_on(topology: &Topology) -> Vec<Vec<SharedFork>> {
+        assert!(topology.is_conflict_graph(), "Every fork must be shared by exactly two philosophers");
+
+        let mut ends: Vec<Vec<(usize, SharedFork)>> = (0..topology.philosophers()).map(|_| Vec::new()).collect();
+        for fork in 0..topology.forks() {
+            let sharing = topology.sharing(fork);
+            let (holder, other) = SharedFork::pair(fork);
+            ends[sharing[0]].push((fork, holder));
+            ends[sharing[1]].push((fork, other));
+        }
+
+        ends.into_iter()
+            .enumerate()
+            .map(|(position, mut forks)| {
+                let needs = topology.needs(position);
+                forks.sort_by_key(|(fork, _)| needs.iter().position(|needed| needed == fork));
+                forks.into_iter().map(|(_, end)| end).collect()
+            })
+            .collect()
+    }
+}
+
+impl Strategy for ChandyMisra {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Thinking::new(self.seats[table_interaction.position].take().unwrap()))
+    }
+
+    fn shares_table(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn lower_numbered_philosopher_starts_with_the_shared_fork() {
+        let neighbours = ChandyMisra::neighbours(4);
+
+        let held: Vec<(bool, bool)> = neighbours.iter().map(|n| (n.has_left(), n.has_right())).collect();
+
+        assert_eq!(held, vec![(true, true), (false, true), (false, true), (false, false)]);
+    }
+
+    #[test]
+    fn lower_numbered_philosopher_starts_with_the_shared_fork_of_any_graph() {
+        let neighbours = ChandyMisra::neighbours_on(&Topology::star(4));
+
+        let held: Vec<Vec<bool>> = neighbours.iter().map(|n| (0..n.forks()).map(|i| n.has(i)).collect()).collect();
+
+        assert_eq!(held, vec![vec![true, true, true], vec![false], vec![false], vec![false]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Every fork must be shared by exactly two philosophers")]
+    fn forks_must_join_two_philosophers() {
+        ChandyMisra::neighbours_on(&Topology::ring(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn needs_at_least_two_philosophers() {
+        ChandyMisra::new(1);
+    }
+
+    #[test]
+    fn everyone_eats_at_any_table_size() {
+        for size in 2..=32 {
+            let results = run_in_lockstep(&mut ChandyMisra::new(size), size, 4 * size);
+
+            for (id, history) in &results {
+                assert!(history.contains(&State::Eating), "philosopher {} of {} never ate", id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let size = 7;
+        let results = run_in_lockstep(&mut ChandyMisra::new(size), size, 200);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+}
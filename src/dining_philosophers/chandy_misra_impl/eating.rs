@@ -0,0 +1,85 @@
+use crate::dining_philosophers::chandy_misra_impl::neighbours::Neighbours;
+use crate::dining_philosophers::chandy_misra_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+#[derive(Debug)]
+pub struct Eating {
+    neighbours: Option<Neighbours>,
+}
+
+impl Eating {
+    pub fn new(neighbours: Neighbours) -> Eating {
+        Eating {
+            neighbours: Some(neighbours),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.neighbours.take() {
+            None => { panic!("No longer valid") }
+            Some(mut n) => {
+                n.dirty_forks();
+                n.receive();
+                n.give_up_requested_forks();
+                debug!("{}: Done eating, back to thinking", n.position);
+                Box::new(Thinking::new(n))
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+    use crate::dining_philosophers::chandy_misra_impl::eating::Eating;
+    use crate::dining_philosophers::chandy_misra_impl::thinking::Thinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+    #[test]
+    fn state_is_eating() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let unit = Eating::new(neighbours.remove(0));
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn keeps_forks_nobody_asked_for() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::new(neighbours.remove(0)));
+
+        unit = unit.transition(); // thinking
+        unit = unit.transition(); // still holds both, eats again
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn hands_over_requested_forks() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::new(neighbours.remove(0)));
+        let mut neighbour: Box<dyn StateMachine> = Box::new(Thinking::new(neighbours.remove(0)));
+
+        let _neighbour = neighbour.transition(); // requests both forks
+        unit = unit.transition(); // thinking, forks handed over
+        unit = unit.transition(); // hungry again, with no fork in hand
+
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit = Eating::new(neighbours.remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,87 @@
+use crate::dining_philosophers::chandy_misra_impl::eating::Eating;
+use crate::dining_philosophers::chandy_misra_impl::neighbours::Neighbours;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Waiting for the missing forks to arrive from the neighbours.
+#[derive(Debug)]
+pub struct Hungry {
+    neighbours: Option<Neighbours>,
+    state: State,
+}
+
+impl Hungry {
+    pub fn new(neighbours: Neighbours) -> Hungry {
+        let state = match (neighbours.has_left(), neighbours.has_right()) {
+            (true, false) => State::LeftThinking,
+            (false, true) => State::RightThinking,
+            _ => State::Hungry,
+        };
+        Hungry {
+            neighbours: Some(neighbours),
+            state,
+        }
+    }
+}
+
+impl StateMachine for Hungry {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.neighbours.take() {
+            None => { panic!("No longer valid") }
+            Some(mut n) => {
+                n.receive();
+                n.give_up_requested_forks();
+                if n.has_both() {
+                    debug!("{}: Forks arrived. Eating!", n.position);
+                    Box::new(Eating::new(n))
+                } else {
+                    n.request_missing_forks();
+                    Box::new(Hungry::new(n))
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        self.state.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+    use crate::dining_philosophers::chandy_misra_impl::hungry::Hungry;
+    use crate::dining_philosophers::chandy_misra_impl::thinking::Thinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+    #[test]
+    fn state_follows_the_forks_in_hand() {
+        let mut neighbours = ChandyMisra::neighbours(3);
+
+        assert_eq!(Hungry::new(neighbours.remove(2)).state(), State::Hungry);
+        assert_eq!(Hungry::new(neighbours.remove(1)).state(), State::RightThinking);
+    }
+
+    #[test]
+    fn eats_once_the_requested_fork_arrives() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut holder: Box<dyn StateMachine> = Box::new(Thinking::new(neighbours.remove(0)));
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(neighbours.remove(0)));
+
+        unit = unit.transition(); // hungry, requests both forks
+        holder = holder.transition(); // eats, forks are dirty
+        let _holder = holder.transition(); // hands both forks over
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit = Hungry::new(neighbours.remove(1));
+
+        unit.transition();
+        unit.transition();
+    }
+}
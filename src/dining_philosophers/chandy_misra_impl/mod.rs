@@ -0,0 +1,6 @@
+pub mod shared_fork;
+pub mod neighbours;
+pub mod thinking;
+pub mod hungry;
+pub mod eating;
+pub mod chandy_misra;
@@ -0,0 +1,65 @@
+use crate::dining_philosophers::chandy_misra_impl::shared_fork::SharedFork;
+
+/// The forks a philosopher shares with its neighbours, in the order it needs them: at the round
+/// table one on either side.
+#[derive(Debug)]
+pub struct Neighbours {
+    pub position: usize,
+    forks: Vec<SharedFork>,
+}
+
+impl Neighbours {
+    pub fn new(position: usize, left: SharedFork, right: SharedFork) -> Neighbours {
+        Neighbours::with_forks(position, vec![left, right])
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the philosopher shares no fork.
+    pub fn with_forks(position: usize, forks: Vec<SharedFork>) -> Neighbours {
+        assert!(!forks.is_empty(), "Philosopher {} shares no fork", position);
+        Neighbours {
+            position,
+            forks,
+        }
+    }
+
+    /// How many forks it shares.
+    pub fn forks(&self) -> usize {
+        self.forks.len()
+    }
+
+    pub fn has_left(&self) -> bool {
+        self.has(0)
+    }
+
+    pub fn has_right(&self) -> bool {
+        self.has(self.forks.len() - 1)
+    }
+
+    /// Whether it holds the fork at this place in the order it needs them.
+    pub fn has(&self, index: usize) -> bool {
+        self.forks[index].is_held()
+    }
+
+    /// Whether it holds every fork it shares, which at the round table are both.
+    pub fn has_both(&self) -> bool {
+        self.forks.iter().all(SharedFork::is_held)
+    }
+
+    pub fn receive(&mut self) {
+        self.forks.iter_mut().for_each(SharedFork::receive);
+    }
+
+    pub fn give_up_requested_forks(&mut self) {
+        self.forks.iter_mut().for_each(SharedFork::give_up_if_requested);
+    }
+
+    pub fn request_missing_forks(&mut self) {
+        self.forks.iter_mut().for_each(SharedFork::request);
+    }
+
+    pub fn dirty_forks(&mut self) {
+        self.forks.iter_mut().for_each(SharedFork::dirty);
+    }
+}
@@ -0,0 +1,176 @@
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use crate::dining_philosophers::fork::Fork;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Condition {
+    Clean,
+    Dirty,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Message {
+    /// The request token: the sender would like the fork.
+    Request,
+    /// The fork itself, which always travels clean.
+    Fork(Fork),
+}
+
+/// One philosopher's end of the fork it shares with a neighbour.
+///
+/// Exactly one of the two ends holds the fork at any time, and the request token
+/// lives at one end too. A hungry philosopher spends the token to ask for the fork;
+/// the holder hands the fork over once it is dirty.
+#[derive(Debug)]
+pub struct SharedFork {
+    fork: Option<Fork>,
+    condition: Condition,
+    request_token: bool,
+    to_neighbour: Sender<Message>,
+    from_neighbour: Receiver<Message>,
+}
+
+impl SharedFork {
+    /// Creates both ends of the numbered fork. The first end starts with the fork, dirty, and the
+    /// second with the request token.
+    pub fn pair(id: usize) -> (SharedFork, SharedFork) {
+        let (to_other, from_holder) = channel();
+        let (to_holder, from_other) = channel();
+
+        let holder = SharedFork {
+            fork: Some(Fork::new(id)),
+            condition: Condition::Dirty,
+            request_token: false,
+            to_neighbour: to_other,
+            from_neighbour: from_other,
+        };
+        let other = SharedFork {
+            fork: None,
+            condition: Condition::Dirty,
+            request_token: true,
+            to_neighbour: to_holder,
+            from_neighbour: from_holder,
+        };
+        (holder, other)
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.fork.is_some()
+    }
+
+    pub fn condition(&self) -> Condition {
+        self.condition
+    }
+
+    pub fn has_request_token(&self) -> bool {
+        self.request_token
+    }
+
+    pub fn receive(&mut self) {
+        while let Ok(message) = self.from_neighbour.try_recv() {
+            match message {
+                Message::Request => self.request_token = true,
+                Message::Fork(fork) => {
+                    self.fork = Some(fork);
+                    self.condition = Condition::Clean;
+                }
+            }
+        }
+    }
+
+    /// Hands the fork over if the neighbour asked for it and it has been used since it arrived.
+    pub fn give_up_if_requested(&mut self) {
+        if self.condition == Condition::Dirty {
+            self.hand_over_if_requested();
+        }
+    }
+
+    /// Hands the fork over if the neighbour asked for it, clean or dirty.
+    pub fn hand_over_if_requested(&mut self) {
+        if self.request_token {
+            if let Some(fork) = self.fork.take() {
+                // The neighbour may already have finished its run.
+                self.to_neighbour.send(Message::Fork(fork)).ok();
+            }
+        }
+    }
+
+    pub fn request(&mut self) {
+        if self.request_token && self.fork.is_none() {
+            self.request_token = false;
+            self.to_neighbour.send(Message::Request).ok();
+        }
+    }
+
+    pub fn dirty(&mut self) {
+        self.condition = Condition::Dirty;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::chandy_misra_impl::shared_fork::{Condition, SharedFork};
+
+    #[test]
+    fn holder_starts_with_a_dirty_fork() {
+        let (holder, other) = SharedFork::pair(0);
+
+        assert!(holder.is_held());
+        assert_eq!(holder.condition(), Condition::Dirty);
+        assert!(!other.is_held());
+        assert!(other.has_request_token());
+    }
+
+    #[test]
+    fn dirty_fork_moves_on_request_and_arrives_clean() {
+        let (mut holder, mut other) = SharedFork::pair(0);
+
+        other.request();
+        holder.receive();
+        holder.give_up_if_requested();
+        other.receive();
+
+        assert!(!holder.is_held());
+        assert!(holder.has_request_token());
+        assert!(other.is_held());
+        assert_eq!(other.condition(), Condition::Clean);
+    }
+
+    #[test]
+    fn clean_fork_is_kept_until_used() {
+        let (mut holder, mut other) = SharedFork::pair(0);
+        other.request();
+        holder.receive();
+        holder.give_up_if_requested();
+        other.receive();
+
+        holder.request();
+        other.receive();
+        other.give_up_if_requested();
+
+        assert!(other.is_held());
+
+        other.dirty();
+        other.give_up_if_requested();
+        holder.receive();
+
+        assert!(holder.is_held());
+    }
+
+    #[test]
+    fn clean_fork_is_handed_over_when_cleanliness_does_not_matter() {
+        let (mut holder, mut other) = SharedFork::pair(0);
+        other.request();
+        holder.receive();
+        holder.give_up_if_requested();
+        other.receive();
+
+        holder.request();
+        other.receive();
+        other.hand_over_if_requested();
+        holder.receive();
+
+        assert!(!other.is_held());
+        assert!(holder.is_held());
+    }
+}
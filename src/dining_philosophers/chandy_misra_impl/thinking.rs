@@ -0,0 +1,86 @@
+use crate::dining_philosophers::chandy_misra_impl::eating::Eating;
+use crate::dining_philosophers::chandy_misra_impl::hungry::Hungry;
+use crate::dining_philosophers::chandy_misra_impl::neighbours::Neighbours;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+#[derive(Debug)]
+pub struct Thinking {
+    neighbours: Option<Neighbours>,
+}
+
+impl Thinking {
+    pub fn new(neighbours: Neighbours) -> Thinking {
+        Thinking {
+            neighbours: Some(neighbours),
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.neighbours.take() {
+            None => { panic!("No longer valid") }
+            Some(mut n) => {
+                n.receive();
+                n.give_up_requested_forks();
+                if n.has_both() {
+                    debug!("{}: Has both forks. Eating!", n.position);
+                    Box::new(Eating::new(n))
+                } else {
+                    n.request_missing_forks();
+                    debug!("{}: Hungry, asked for missing forks", n.position);
+                    Box::new(Hungry::new(n))
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+    use crate::dining_philosophers::chandy_misra_impl::thinking::Thinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+    #[test]
+    fn state_is_thinking() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let unit = Thinking::new(neighbours.remove(0));
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    fn holder_of_both_forks_eats_straight_away() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(neighbours.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn philosopher_without_forks_becomes_hungry() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(neighbours.remove(1)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut neighbours = ChandyMisra::neighbours(2);
+        let mut unit = Thinking::new(neighbours.remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,169 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::dining_philosophers::chandy_misra_impl::neighbours::Neighbours;
+use crate::dining_philosophers::chandy_misra_impl::shared_fork::SharedFork;
+use crate::dining_philosophers::random::SeededRng;
+
+/// Where a drinking philosopher stands in the dining philosophers problem underneath. A thirsty
+/// philosopher grows hungry and stays hungry until it has eaten, and it eats until it drinks.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Dining {
+    Thinking,
+    Hungry,
+    Eating,
+}
+
+/// What every state of a drinking philosopher carries: the Chandy–Misra forks it shares with its
+/// neighbours, a bottle on the edge of each fork, where it stands in the dining problem and the
+/// dice that choose the bottles of each session.
+///
+/// A bottle travels on request like a fork, but is handed over clean or dirty unless its holder is
+/// drinking, or needs it and holds the fork on the same edge. Whoever holds the fork has priority,
+/// and an eating philosopher holds all of them.
+#[derive(Debug)]
+pub struct Drinker {
+    neighbours: Neighbours,
+    bottles: Vec<SharedFork>,
+    /// The number of each bottle, in the order of the forks and bottles it shares.
+    numbers: Vec<usize>,
+    dining: Dining,
+    rng: SeededRng,
+}
+
+impl Drinker {
+    /// # Panics
+    ///
+    /// Panics unless there is a bottle and a number for every fork.
+    pub fn new(neighbours: Neighbours, bottles: Vec<SharedFork>, numbers: Vec<usize>, rng: SeededRng) -> Drinker {
+        assert_eq!(bottles.len(), neighbours.forks());
+        assert_eq!(numbers.len(), neighbours.forks());
+        Drinker {
+            neighbours,
+            bottles,
+            numbers,
+            dining: Dining::Thinking,
+            rng,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.neighbours.position
+    }
+
+    pub fn dining(&self) -> Dining {
+        self.dining
+    }
+
+    /// The bottles of the next session: at least one of the bottles it shares, all of them equally
+    /// likely to be among them, in the order it shares them.
+    pub fn thirst(&mut self) -> Vec<usize> {
+        let count = self.rng.gen_range(1, self.numbers.len() + 1);
+        let mut session: Vec<usize> = self.numbers.choose_multiple(&mut self.rng, count).cloned().collect();
+        let numbers = &self.numbers;
+        session.sort_by_key(|bottle| numbers.iter().position(|b| b == bottle));
+        session
+    }
+
+    /// One step of both protocols for a philosopher that is not drinking, thirsty for the bottles
+    /// of the session or, with an empty one, tranquil. Takes in what the neighbours sent, moves on
+    /// in the dining problem, hands over what it may not keep and asks for what it is missing.
+    ///
+    /// Returns whether it holds every bottle of the session.
+    pub fn tend(&mut self, session: &[usize]) -> bool {
+        let thirsty = !session.is_empty();
+        self.neighbours.receive();
+        self.bottles.iter_mut().for_each(SharedFork::receive);
+
+        if self.dining == Dining::Thinking && thirsty {
+            self.dining = Dining::Hungry;
+        }
+        if self.dining == Dining::Hungry && self.neighbours.has_both() {
+            debug!("{}: Has every fork, eating until it drinks", self.position());
+            self.dining = Dining::Eating;
+        }
+        if self.dining == Dining::Eating && !thirsty {
+            self.neighbours.dirty_forks();
+            self.dining = Dining::Thinking;
+        }
+        if self.dining != Dining::Eating {
+            self.neighbours.give_up_requested_forks();
+        }
+        if self.dining == Dining::Hungry {
+            self.neighbours.request_missing_forks();
+        }
+
+        let mut has_session = true;
+        for (index, bottle) in self.bottles.iter_mut().enumerate() {
+            let needed = session.contains(&self.numbers[index]);
+            if !needed || !self.neighbours.has(index) {
+                bottle.hand_over_if_requested();
+            }
+            if needed {
+                bottle.request();
+                has_session &= bottle.is_held();
+            }
+        }
+        has_session
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::drinking_impl::drinker::Dining;
+    use crate::dining_philosophers::drinking_impl::drinkers::Drinkers;
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn thirsts_for_some_of_its_bottles() {
+        let mut unit = Drinkers::drinkers(&Topology::complete(5), 0).remove(0);
+
+        let sessions: Vec<Vec<usize>> = (0..50).map(|_| unit.thirst()).collect();
+
+        assert!(sessions.iter().all(|session| !session.is_empty() && session.windows(2).all(|pair| pair[0] < pair[1])));
+        assert!(sessions.iter().all(|session| session.iter().all(|bottle| [0, 1, 2, 3].contains(bottle))));
+        assert!(sessions.iter().any(|session| session.len() == 1));
+        assert!(sessions.iter().any(|session| session.len() == 4));
+    }
+
+    #[test]
+    fn same_seed_same_sessions() {
+        let mut a = Drinkers::drinkers(&Topology::ring(3), 7).remove(0);
+        let mut b = Drinkers::drinkers(&Topology::ring(3), 7).remove(0);
+
+        assert!((0..10).all(|_| a.thirst() == b.thirst()));
+    }
+
+    #[test]
+    fn hands_over_a_bottle_it_does_not_need() {
+        let mut drinkers = Drinkers::drinkers(&Topology::line(3), 0);
+
+        assert!(!drinkers[1].tend(&[0]));
+        assert!(drinkers[0].tend(&[]));
+        assert!(drinkers[1].tend(&[0]));
+    }
+
+    #[test]
+    fn holder_of_the_fork_keeps_a_bottle_both_need() {
+        let mut drinkers = Drinkers::drinkers(&Topology::line(2), 0);
+
+        assert!(!drinkers[1].tend(&[0]));
+        assert!(drinkers[0].tend(&[0]));
+        assert!(!drinkers[1].tend(&[0]));
+    }
+
+    #[test]
+    fn eater_keeps_its_forks_and_bottles_until_it_drinks() {
+        let mut drinkers = Drinkers::drinkers(&Topology::line(3), 0);
+        drinkers[0].tend(&[0]); // eats: it holds its only fork
+        assert_eq!(drinkers[0].dining(), Dining::Eating);
+
+        assert!(!drinkers[1].tend(&[0, 1])); // hungry, asks philosopher 0 for its fork and bottle
+        assert_eq!(drinkers[1].dining(), Dining::Hungry);
+        assert!(drinkers[0].tend(&[0])); // still eating, keeps both
+        assert!(drinkers[0].tend(&[])); // has drunk: stops eating and hands both over
+
+        assert!(drinkers[1].tend(&[0, 1]));
+        assert_eq!(drinkers[1].dining(), Dining::Eating);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+use crate::dining_philosophers::drinking_impl::drinker::Drinker;
+use crate::dining_philosophers::drinking_impl::tranquil::Tranquil;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// The drinking philosophers problem of Chandy and Misra and their solution: every fork of the
+/// topology lies on an edge of the conflict graph, next to a bottle shared by the same two
+/// philosophers, and each session needs only some of a philosopher's bottles, chosen at random.
+/// Bottles and forks change hands only through messages between neighbours, so neighbours whose
+/// sessions share no bottle drink together, and the Chandy–Misra forks underneath settle who gets
+/// a bottle both want, so every thirsty philosopher drinks in the end.
+///
+/// Each seat rolls its own dice, seeded from `seed` and its position.
+pub struct Drinkers {
+    topology: Topology,
+    seats: Vec<Option<Drinker>>,
+}
+
+impl Drinkers {
+    /// # Panics
+    ///
+    /// Panics if a bottle is not shared by exactly two philosophers.
+    pub fn new(topology: &Topology, seed: u64) -> Drinkers {
+        Drinkers {
+            topology: topology.clone(),
+            seats: Drinkers::drinkers(topology, seed).into_iter().map(Some).collect(),
+        }
+    }
+
+    /// Hands out every fork and every bottle to the lower-numbered of the two philosophers sharing
+    /// it, with the request tokens at the other end.
+    pub fn drinkers(topology: &Topology, seed: u64) -> Vec<Drinker> {
+        ChandyMisra::neighbours_on(topology).into_iter()
+            .zip(ChandyMisra::shared_forks_on(topology))
+            .enumerate()
+            .map(|(position, (neighbours, bottles))| {
+                let rng = SeededRng::new(seed.wrapping_add(position as u64));
+                Drinker::new(neighbours, bottles, topology.needs(position).to_vec(), rng)
+            })
+            .collect()
+    }
+}
+
+impl Strategy for Drinkers {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Tranquil::new(self.seats[table_interaction.position].take().unwrap()))
+    }
+
+    /// Fits the topology it handed out the forks and bottles for.
+    fn fits(&self, topology: &Topology) -> bool {
+        *topology == self.topology
+    }
+
+    fn shares_table(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::longest_starvation_one_run;
+    use crate::dining_philosophers::drinking_impl::drinkers::Drinkers;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::run_in_lockstep_on;
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn every_philosopher_drinks() {
+        let topology = Topology::ring(5);
+        let results = run_in_lockstep_on(&mut Drinkers::new(&topology, 0), &topology, 50);
+
+        assert!(results.values().all(|history| history.contains(&State::Drinking)));
+    }
+
+    #[test]
+    fn neighbours_drink_together_from_different_bottles() {
+        let topology = Topology::ring(5);
+        let results = run_in_lockstep_on(&mut Drinkers::new(&topology, 0), &topology, 100);
+
+        let together = (0..5).any(|a| {
+            let b = (a + 1) % 5;
+            results[&a].iter().zip(&results[&b]).any(|(x, y)| *x == State::Drinking && *y == State::Drinking)
+        });
+        assert!(together);
+    }
+
+    #[test]
+    fn same_seed_same_run() {
+        let topology = Topology::grid(2, 3);
+
+        assert_eq!(run_in_lockstep_on(&mut Drinkers::new(&topology, 3), &topology, 40), run_in_lockstep_on(&mut Drinkers::new(&topology, 3), &topology, 40));
+    }
+
+    #[test]
+    fn every_thirsty_philosopher_drinks_in_the_end() {
+        for topology in [Topology::line(6), Topology::star(6), Topology::complete(6)].iter() {
+            for seed in 0..10 {
+                let results = run_in_lockstep_on(&mut Drinkers::new(topology, seed), topology, 300);
+
+                for (id, history) in &results {
+                    let drinks = history.iter().filter(|state| **state == State::Drinking).count();
+                    assert!(drinks >= 10, "philosopher {} drank {} times on {:?}", id, drinks, topology);
+                }
+                assert!(longest_starvation_one_run(&results) <= 4 * topology.philosophers(), "{:?} with seed {}", topology, seed);
+            }
+        }
+    }
+
+    #[test]
+    fn does_not_fit_another_topology() {
+        let unit = Drinkers::new(&Topology::line(4), 0);
+
+        assert!(!unit.fits(&Topology::star(4)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Every fork must be shared by exactly two philosophers")]
+    fn every_bottle_joins_two_philosophers() {
+        Drinkers::new(&Topology::ring(1), 0);
+    }
+}
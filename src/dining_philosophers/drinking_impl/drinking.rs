@@ -0,0 +1,73 @@
+use crate::dining_philosophers::drinking_impl::drinker::Drinker;
+use crate::dining_philosophers::drinking_impl::tranquil::Tranquil;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Holding every bottle of its session, which nobody can ask away. Tranquil after one step, when it
+/// hands over the bottles its neighbours asked for in the meantime.
+#[derive(Debug)]
+pub struct Drinking {
+    drinker: Option<Drinker>,
+    session: Vec<usize>,
+}
+
+impl Drinking {
+    pub fn new(drinker: Drinker, session: Vec<usize>) -> Drinking {
+        Drinking {
+            drinker: Some(drinker),
+            session,
+        }
+    }
+}
+
+impl StateMachine for Drinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.drinker.take() {
+            None => { panic!("No longer valid") }
+            Some(mut drinker) => {
+                debug!("{}: Done with bottles {:?}, tranquil", drinker.position(), self.session);
+                drinker.tend(&[]);
+                Box::new(Tranquil::new(drinker))
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Drinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::drinking_impl::drinkers::Drinkers;
+    use crate::dining_philosophers::drinking_impl::drinking::Drinking;
+    use crate::dining_philosophers::drinking_impl::thirsty::Thirsty;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn state_is_drinking() {
+        assert_eq!(Drinking::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0), vec![0]).state(), State::Drinking);
+    }
+
+    #[test]
+    fn hands_over_the_bottles_asked_for_while_drinking() {
+        let mut drinkers = Drinkers::drinkers(&Topology::line(2), 0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Drinking::new(drinkers.remove(0), vec![0]));
+        let mut neighbour: Box<dyn StateMachine> = Box::new(Thirsty::new(drinkers.remove(0), vec![0]));
+
+        neighbour = neighbour.transition(); // asks for the bottle
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Tranquil);
+        assert_eq!(neighbour.transition().state(), State::Drinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Drinking::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0), vec![0]);
+
+        unit.transition();
+        unit.transition();
+    }
+}
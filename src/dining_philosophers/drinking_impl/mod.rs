@@ -0,0 +1,5 @@
+pub mod drinker;
+pub mod tranquil;
+pub mod thirsty;
+pub mod drinking;
+pub mod drinkers;
@@ -0,0 +1,92 @@
+use std::mem;
+
+use crate::dining_philosophers::drinking_impl::drinker::Drinker;
+use crate::dining_philosophers::drinking_impl::drinking::Drinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Waiting for every bottle of its session, asking for the ones it is missing.
+#[derive(Debug)]
+pub struct Thirsty {
+    drinker: Option<Drinker>,
+    session: Vec<usize>,
+}
+
+impl Thirsty {
+    pub fn new(drinker: Drinker, session: Vec<usize>) -> Thirsty {
+        Thirsty {
+            drinker: Some(drinker),
+            session,
+        }
+    }
+
+    /// Drinks if it holds every bottle of the session, or stays thirsty for the same bottles.
+    pub fn drink(mut self) -> Box<dyn StateMachine + Send> {
+        let mut drinker = self.drinker.take().expect("No longer valid");
+        if drinker.tend(&self.session) {
+            debug!("{}: Has bottles {:?}. Drinking!", drinker.position(), self.session);
+            Box::new(Drinking::new(drinker, self.session))
+        } else {
+            debug!("{}: Missing a bottle, still thirsty", drinker.position());
+            Box::new(Thirsty::new(drinker, self.session))
+        }
+    }
+}
+
+impl StateMachine for Thirsty {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.drinker.take() {
+            None => { panic!("No longer valid") }
+            Some(drinker) => Thirsty::new(drinker, mem::take(&mut self.session)).drink(),
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thirsty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::drinking_impl::drinkers::Drinkers;
+    use crate::dining_philosophers::drinking_impl::drinking::Drinking;
+    use crate::dining_philosophers::drinking_impl::thirsty::Thirsty;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn state_is_thirsty() {
+        assert_eq!(Thirsty::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0), vec![0]).state(), State::Thirsty);
+    }
+
+    #[test]
+    fn waits_for_the_neighbour_to_finish_drinking() {
+        let mut drinkers = Drinkers::drinkers(&Topology::line(2), 0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thirsty::new(drinkers.remove(1), vec![0]));
+        let mut neighbour: Box<dyn StateMachine> = Box::new(Drinking::new(drinkers.remove(0), vec![0]));
+
+        unit = unit.transition(); // asks for the bottle
+        assert_eq!(unit.state(), State::Thirsty);
+
+        let _neighbour = neighbour.transition(); // tranquil, hands the bottle over
+        unit = unit.transition();
+        assert_eq!(unit.state(), State::Drinking);
+    }
+
+    #[test]
+    fn drinks_while_a_neighbour_holds_a_bottle_it_does_not_need() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thirsty::new(Drinkers::drinkers(&Topology::line(3), 0).remove(1), vec![1]));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Drinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thirsty::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0), vec![0]);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,74 @@
+use crate::dining_philosophers::drinking_impl::drinker::Drinker;
+use crate::dining_philosophers::drinking_impl::thirsty::Thirsty;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Needs no bottle. Grows thirsty for a fresh session on its next step.
+#[derive(Debug)]
+pub struct Tranquil {
+    drinker: Option<Drinker>,
+}
+
+impl Tranquil {
+    pub fn new(drinker: Drinker) -> Tranquil {
+        Tranquil {
+            drinker: Some(drinker),
+        }
+    }
+}
+
+impl StateMachine for Tranquil {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.drinker.take() {
+            None => { panic!("No longer valid") }
+            Some(mut drinker) => {
+                let session = drinker.thirst();
+                debug!("{}: Thirsty for bottles {:?}", drinker.position(), session);
+                Thirsty::new(drinker, session).drink()
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Tranquil
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::drinking_impl::drinkers::Drinkers;
+    use crate::dining_philosophers::drinking_impl::tranquil::Tranquil;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn state_is_tranquil() {
+        assert_eq!(Tranquil::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0)).state(), State::Tranquil);
+    }
+
+    #[test]
+    fn drinks_straight_away_when_it_holds_the_bottles() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Tranquil::new(Drinkers::drinkers(&Topology::star(3), 0).remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Drinking);
+    }
+
+    #[test]
+    fn grows_thirsty_without_the_bottles() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Tranquil::new(Drinkers::drinkers(&Topology::star(3), 0).remove(1)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thirsty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Tranquil::new(Drinkers::drinkers(&Topology::line(2), 0).remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
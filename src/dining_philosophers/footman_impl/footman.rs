@@ -0,0 +1,101 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::footman_impl::guarded::Guarded;
+use crate::dining_philosophers::footman_impl::semaphore::Semaphore;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+use crate::dining_philosophers::resource_hierarchy_impl::release::Release;
+use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// The footman solution: a footman lets at most `limit` philosophers reach for forks at once.
+///
+/// Everyone admitted takes the left fork first, waits for the right one and puts both down
+/// after eating, which deadlocks only when the whole table is admitted together. With the
+/// default limit of N-1 one philosopher is always left out of the circle.
+pub struct Footman {
+    footman: Arc<Mutex<Semaphore>>,
+}
+
+impl Footman {
+    pub fn new(number_of_philosophers: usize) -> Footman {
+        Footman::with_limit(number_of_philosophers, number_of_philosophers.saturating_sub(1).max(1))
+    }
+
+    pub fn with_limit(number_of_philosophers: usize, limit: usize) -> Footman {
+        assert!(limit > 0, "The footman must admit at least one of {} philosophers", number_of_philosophers);
+        Footman {
+            footman: Arc::new(Mutex::new(Semaphore::new(limit))),
+        }
+    }
+}
+
+impl Strategy for Footman {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let philosopher = Thinking::with_policy(table_interaction, Policy::new(ForkChoice::AlwaysLeft).waiting().releasing(Release::BothAtOnce));
+        Box::new(Guarded::new(Box::new(philosopher), Arc::clone(&self.footman)))
+    }
+
+    fn counters(&self) -> Counters {
+        let footman = self.footman.lock().unwrap();
+        vec![("admissions", footman.admissions()), ("turned away", footman.refusals())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::RunResults;
+    use crate::dining_philosophers::footman_impl::footman::Footman;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::strategy::Strategy;
+
+    fn everyone_has_eaten(results: &RunResults) -> bool {
+        results.values().all(|history| history.contains(&State::Eating))
+    }
+
+    #[test]
+    fn admitting_all_but_one_never_deadlocks() {
+        for size in 2..=32 {
+            let results = run_in_lockstep(&mut Footman::new(size), size, 4 * size);
+
+            assert!(everyone_has_eaten(&results), "deadlock with {} philosophers", size);
+        }
+    }
+
+    #[test]
+    fn admitting_everyone_deadlocks() {
+        let results = run_in_lockstep(&mut Footman::with_limit(5, 5), 5, 20);
+
+        assert!(!everyone_has_eaten(&results));
+        assert!(results.values().all(|history| history.last() == Some(&State::LeftThinking)));
+    }
+
+    #[test]
+    fn admitting_one_lets_only_one_eat_at_a_time() {
+        let results = run_in_lockstep(&mut Footman::with_limit(5, 1), 5, 50);
+
+        for step in 0..=50 {
+            let eating = results.values().filter(|history| history[step] == State::Eating).count();
+            assert!(eating <= 1, "{} philosophers eating at step {}", eating, step);
+        }
+        assert!(everyone_has_eaten(&results));
+    }
+
+    #[test]
+    fn counts_admissions_and_philosophers_turned_away() {
+        let mut unit = Footman::new(3);
+        run_in_lockstep(&mut unit, 3, 1);
+
+        assert_eq!(unit.counters(), vec![("admissions", 2), ("turned away", 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_admit_nobody() {
+        Footman::with_limit(5, 0);
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::footman_impl::semaphore::Semaphore;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Wraps another state machine so that it may only leave `Thinking` with a permit from the footman.
+///
+/// The permit is handed back as soon as the wrapped philosopher is thinking again.
+pub struct Guarded {
+    inner: Option<Box<dyn StateMachine + Send>>,
+    footman: Option<Arc<Mutex<Semaphore>>>,
+    admitted: bool,
+}
+
+impl Guarded {
+    pub fn new(inner: Box<dyn StateMachine + Send>, footman: Arc<Mutex<Semaphore>>) -> Guarded {
+        Guarded {
+            inner: Some(inner),
+            footman: Some(footman),
+            admitted: false,
+        }
+    }
+}
+
+impl StateMachine for Guarded {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match (self.inner.take(), self.footman.take()) {
+            (Some(inner), Some(footman)) => {
+                let admitted = self.admitted || footman.lock().unwrap().try_acquire();
+                if !admitted {
+                    return Box::new(Guarded { inner: Some(inner), footman: Some(footman), admitted: false });
+                }
+
+                let mut inner = inner;
+                inner = inner.transition();
+                let still_admitted = inner.state() != State::Thinking;
+                if !still_admitted {
+                    footman.lock().unwrap().release();
+                }
+                Box::new(Guarded { inner: Some(inner), footman: Some(footman), admitted: still_admitted })
+            }
+            _ => { panic!("No longer valid") }
+        }
+    }
+
+    fn state(&self) -> State {
+        match &self.inner {
+            None => { panic!("No longer valid") }
+            Some(inner) => inner.state(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::footman_impl::guarded::Guarded;
+    use crate::dining_philosophers::footman_impl::semaphore::Semaphore;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
+    use crate::dining_philosophers::table::Table;
+
+    fn guarded(footman: &Arc<Mutex<Semaphore>>) -> Box<dyn StateMachine> {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        Box::new(Guarded::new(Box::new(Thinking::new(table_interaction)), Arc::clone(footman)))
+    }
+
+    #[test]
+    fn state_is_the_wrapped_state() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(1)));
+
+        assert_eq!(guarded(&footman).state(), State::Thinking);
+    }
+
+    #[test]
+    fn reaches_for_forks_once_admitted() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(1)));
+        let mut unit = guarded(&footman);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+        assert_eq!(footman.lock().unwrap().admissions(), 1);
+    }
+
+    #[test]
+    fn keeps_thinking_when_turned_away() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(0)));
+        let mut unit = guarded(&footman);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(footman.lock().unwrap().refusals(), 1);
+    }
+
+    #[test]
+    fn keeps_the_permit_while_holding_forks() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(1)));
+        let mut unit = guarded(&footman);
+
+        unit = unit.transition(); // left thinking
+        unit = unit.transition(); // eating
+
+        assert_eq!(unit.state(), State::Eating);
+        assert!(!footman.lock().unwrap().try_acquire());
+    }
+
+    #[test]
+    fn hands_the_permit_back_when_thinking_again() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(1)));
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Guarded::new(Box::new(Thinking::new(table_interaction)), Arc::clone(&footman)));
+
+        unit = unit.transition(); // left thinking
+        unit = unit.transition(); // the right fork is the same fork, so it is put down again
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert!(footman.lock().unwrap().try_acquire());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let footman = Arc::new(Mutex::new(Semaphore::new(1)));
+        let mut unit = Guarded::new(Box::new(Thinking::new(Table::new(2).get_interactions().pop().unwrap())), footman);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod semaphore;
+pub mod guarded;
+pub mod footman;
@@ -0,0 +1,72 @@
+/// A counting semaphore that never blocks: a caller who finds no permit left is turned away.
+#[derive(Debug, PartialEq)]
+pub struct Semaphore {
+    available: usize,
+    admissions: usize,
+    refusals: usize,
+}
+
+impl Semaphore {
+    pub fn new(permits: usize) -> Semaphore {
+        Semaphore {
+            available: permits,
+            admissions: 0,
+            refusals: 0,
+        }
+    }
+
+    pub fn try_acquire(&mut self) -> bool {
+        if self.available == 0 {
+            self.refusals += 1;
+            return false;
+        }
+        self.available -= 1;
+        self.admissions += 1;
+        true
+    }
+
+    pub fn release(&mut self) {
+        self.available += 1;
+    }
+
+    pub fn admissions(&self) -> usize {
+        self.admissions
+    }
+
+    pub fn refusals(&self) -> usize {
+        self.refusals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::footman_impl::semaphore::Semaphore;
+
+    #[test]
+    fn hands_out_every_permit() {
+        let mut unit = Semaphore::new(2);
+
+        assert!(unit.try_acquire());
+        assert!(unit.try_acquire());
+        assert_eq!(unit.admissions(), 2);
+    }
+
+    #[test]
+    fn turns_away_when_no_permit_is_left() {
+        let mut unit = Semaphore::new(1);
+        unit.try_acquire();
+
+        assert!(!unit.try_acquire());
+        assert_eq!(unit.refusals(), 1);
+    }
+
+    #[test]
+    fn released_permit_can_be_acquired_again() {
+        let mut unit = Semaphore::new(1);
+        unit.try_acquire();
+
+        unit.release();
+
+        assert!(unit.try_acquire());
+    }
+}
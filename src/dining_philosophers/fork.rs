@@ -1,2 +1,107 @@
+use std::error;
+use std::fmt;
+
+/// A fork, which knows its number on the table and who is holding it.
 #[derive(Debug, PartialEq)]
-pub struct Fork;
+pub struct Fork {
+    id: usize,
+    holder: Option<usize>,
+}
+
+impl Fork {
+    pub(crate) fn new(id: usize) -> Fork {
+        Fork {
+            id,
+            holder: None,
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The philosopher who picked the fork up, or `None` while nobody has.
+    pub fn holder(&self) -> Option<usize> {
+        self.holder
+    }
+
+    pub(crate) fn pick_up(&mut self, philosopher: usize) {
+        self.holder = Some(philosopher);
+    }
+
+    pub fn put_down(&mut self) {
+        self.holder = None;
+    }
+}
+
+/// Why the table refused a fork that was put back.
+#[derive(Debug, PartialEq)]
+pub enum ForkError {
+    /// The table has no slot with that number.
+    NoSuchSlot { fork: usize, slot: usize },
+    /// The fork belongs in another slot.
+    WrongSlot { fork: usize, slot: usize },
+    /// The slot already holds its fork.
+    AlreadyReturned { slot: usize },
+    /// The fork is being put back by someone other than the philosopher holding it.
+    NotHolder { fork: usize, holder: Option<usize>, philosopher: usize },
+    /// More or fewer forks are being put back than there are slots named for them.
+    Count { forks: usize, slots: usize },
+}
+
+impl fmt::Display for ForkError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ForkError::NoSuchSlot { fork, slot } => write!(f, "fork {} put back in slot {}, which does not exist", fork, slot),
+            ForkError::WrongSlot { fork, slot } => write!(f, "fork {} put back in slot {}", fork, slot),
+            ForkError::AlreadyReturned { slot } => write!(f, "slot {} already holds its fork", slot),
+            ForkError::NotHolder { fork, holder: Some(holder), philosopher } => write!(f, "philosopher {} put back fork {}, held by philosopher {}", philosopher, fork, holder),
+            ForkError::NotHolder { fork, holder: None, philosopher } => write!(f, "philosopher {} put back fork {}, held by nobody", philosopher, fork),
+            ForkError::Count { forks, slots } => write!(f, "{} forks put back in {} slots", forks, slots),
+        }
+    }
+}
+
+impl error::Error for ForkError {}
+
+/// The fork, or forks, the table refused to take back from the philosopher at `position`, who
+/// keeps them after logging why. `None` once they are back on the table.
+pub fn keep_refused<T>(position: usize, returned: Result<(), (ForkError, T)>) -> Option<T> {
+    match returned {
+        Ok(()) => None,
+        Err((e, kept)) => {
+            error!("{}: Kept a fork the table refused: {}", position, e);
+            Some(kept)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::fork::{keep_refused, Fork, ForkError};
+
+    #[test]
+    fn remembers_who_picked_it_up() {
+        let mut unit = Fork::new(3);
+        assert_eq!((unit.id(), unit.holder()), (3, None));
+
+        unit.pick_up(1);
+        assert_eq!(unit.holder(), Some(1));
+
+        unit.put_down();
+        assert_eq!(unit.holder(), None);
+    }
+
+    #[test]
+    fn describes_the_refused_return() {
+        assert_eq!(ForkError::WrongSlot { fork: 0, slot: 1 }.to_string(), "fork 0 put back in slot 1");
+        assert_eq!(ForkError::NotHolder { fork: 2, holder: Some(1), philosopher: 3 }.to_string(), "philosopher 3 put back fork 2, held by philosopher 1");
+        assert_eq!(ForkError::Count { forks: 1, slots: 2 }.to_string(), "1 forks put back in 2 slots");
+    }
+
+    #[test]
+    fn keeps_a_refused_fork() {
+        assert_eq!(keep_refused(0, Ok::<(), (ForkError, Fork)>(())), None);
+        assert_eq!(keep_refused(0, Err((ForkError::AlreadyReturned { slot: 1 }, Fork::new(1)))), Some(Fork::new(1)));
+    }
+}
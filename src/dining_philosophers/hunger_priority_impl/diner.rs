@@ -0,0 +1,139 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::{Neighbour, TableInteraction};
+
+/// A philosopher who keeps count of its own hunger and looks at a neighbour's before taking
+/// the fork they share.
+#[derive(Debug)]
+pub struct Diner {
+    pub table_interaction: TableInteraction,
+    yields: Arc<Mutex<usize>>,
+}
+
+impl PartialEq for Diner {
+    fn eq(&self, other: &Diner) -> bool {
+        self.table_interaction == other.table_interaction
+    }
+}
+
+impl Diner {
+    pub fn new(table_interaction: TableInteraction, yields: Arc<Mutex<usize>>) -> Diner {
+        Diner {
+            table_interaction,
+            yields,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    /// Another step without a meal.
+    pub fn starve(&self) {
+        self.table_interaction.starve();
+    }
+
+    /// Whether the philosopher has gone without a meal since it last ate.
+    pub fn is_hungry(&self) -> bool {
+        self.table_interaction.starvation() > 0
+    }
+
+    /// Whether the left neighbour, who takes the left fork second, has waited longer.
+    pub fn yields_left_fork(&self) -> bool {
+        self.yields_to(self.table_interaction.neighbours().left)
+    }
+
+    /// Whether the right neighbour, who takes the right fork first, has waited longer.
+    pub fn yields_right_fork(&self) -> bool {
+        self.yields_to(self.table_interaction.neighbours().right)
+    }
+
+    /// Every time a neighbour has waited longer counts as a yield.
+    fn yields_to(&self, neighbour: Neighbour) -> bool {
+        let yields = neighbour.starvation > self.table_interaction.starvation();
+        if yields {
+            *self.yields.lock().unwrap() += 1;
+        }
+        yields
+    }
+
+    pub fn take_left(&self) -> Option<Fork> {
+        self.table_interaction.get_left_fork()
+    }
+
+    pub fn take_right(&self) -> Option<Fork> {
+        self.table_interaction.get_right_fork()
+    }
+
+    /// Resets the starvation counter at the start of a meal.
+    pub fn fed(&self) {
+        self.table_interaction.fed();
+    }
+
+    /// Puts the left fork back, or hands it back with the reason the table refused it.
+    pub fn put_down_left(&self, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        self.table_interaction.return_left_fork(fork)
+    }
+
+    pub fn put_down_right(&self, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        self.table_interaction.return_right_fork(fork)
+    }
+}
+
+/// Every seat of a table of the given size, sharing one count of yields.
+#[cfg(test)]
+pub fn diners(size: usize) -> (Vec<Diner>, Arc<Mutex<usize>>) {
+    use crate::dining_philosophers::table::Table;
+
+    let yields = Arc::new(Mutex::new(0));
+    let diners = Table::new(size).get_interactions().into_iter()
+        .map(|t| Diner::new(t, Arc::clone(&yields)))
+        .collect();
+    (diners, yields)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::hunger_priority_impl::diner::diners;
+
+    #[test]
+    fn yields_to_a_right_neighbour_who_waited_longer() {
+        let (diners, yields) = diners(3);
+        diners[1].starve();
+
+        assert!(diners[0].yields_right_fork());
+        assert_eq!(*yields.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn yields_to_a_left_neighbour_who_waited_longer() {
+        let (diners, yields) = diners(3);
+        diners[2].starve();
+
+        assert!(diners[0].yields_left_fork());
+        assert!(!diners[0].yields_right_fork());
+        assert_eq!(*yields.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn does_not_yield_on_a_tie() {
+        let (diners, yields) = diners(3);
+        diners[0].starve();
+        diners[1].starve();
+
+        assert!(!diners[0].yields_right_fork());
+        assert_eq!(*yields.lock().unwrap(), 0);
+    }
+
+    #[test]
+    fn a_meal_resets_the_starvation_counter() {
+        let (diners, _) = diners(3);
+        diners[1].starve();
+        diners[0].starve();
+
+        diners[1].fed();
+
+        assert!(!diners[0].yields_right_fork());
+    }
+}
@@ -0,0 +1,87 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::hunger_priority_impl::diner::Diner;
+use crate::dining_philosophers::hunger_priority_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+    diner: Option<Diner>,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, diner: Diner) -> Eating {
+        Eating {
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+            diner: Some(diner),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.diner.take() {
+            None => { panic!("No longer valid") }
+            Some(d) => {
+                debug!("{}: Drop both, to thinking", d.position());
+                let right_fork = self.right_fork.take().and_then(|fork| keep_refused(d.position(), d.put_down_right(fork)));
+                let left_fork = self.left_fork.take().and_then(|fork| keep_refused(d.position(), d.put_down_left(fork)));
+                if left_fork.is_some() || right_fork.is_some() {
+                    return Box::new(Eating { left_fork, right_fork, diner: Some(d) });
+                }
+                Box::new(Thinking::new(d))
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::hunger_priority_impl::diner::Diner;
+    use crate::dining_philosophers::hunger_priority_impl::eating::Eating;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+
+    fn eating() -> (Eating, Diner) {
+        let yields = Arc::new(Mutex::new(0));
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = Diner::new(table_interactions.pop().unwrap(), Arc::clone(&yields));
+        let diner = Diner::new(table_interactions.pop().unwrap(), yields);
+        let left = diner.take_left().unwrap();
+        let right = diner.take_right().unwrap();
+        (Eating::new(left, right, diner), neighbour)
+    }
+
+    #[test]
+    fn state_is_eating() {
+        assert_eq!(eating().0.state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_both_forks_down() {
+        let (unit, neighbour) = eating();
+        let mut unit: Box<dyn StateMachine> = Box::new(unit);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert!(neighbour.table_interaction.get_both_forks().is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = eating().0;
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,90 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::hunger_priority_impl::diner::Diner;
+use crate::dining_philosophers::hunger_priority_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Philosophers count the steps since their last meal on the `Table` and take the left fork
+/// first. Before taking the right fork they read the right neighbour's count, and leave the
+/// fork to a neighbour who has gone without for longer.
+pub struct HungerPriority {
+    yields: Arc<Mutex<usize>>,
+}
+
+impl HungerPriority {
+    pub fn new() -> HungerPriority {
+        HungerPriority {
+            yields: Arc::new(Mutex::new(0)),
+        }
+    }
+}
+
+impl Default for HungerPriority {
+    fn default() -> Self {
+        HungerPriority::new()
+    }
+}
+
+impl Strategy for HungerPriority {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Thinking::new(Diner::new(table_interaction, Arc::clone(&self.yields))))
+    }
+
+    fn counters(&self) -> Counters {
+        vec![("yielded to a hungrier neighbour", *self.yields.lock().unwrap())]
+    }
+
+    /// The neighbours it reads are whoever shares its forks.
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::dining_philosophers::analysis::longest_starvation_one_run;
+    use crate::dining_philosophers::hunger_priority_impl::hunger_priority::HungerPriority;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+    use crate::dining_philosophers::strategy::Strategy;
+
+    #[test]
+    fn everyone_eats() {
+        for size in 2..=16 {
+            let results = run_in_lockstep(&mut HungerPriority::new(), size, 8 * size);
+
+            for (id, history) in &results {
+                assert!(history.contains(&State::Eating), "philosopher {} of {} never ate", id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let results = run_in_lockstep(&mut HungerPriority::new(), 5, 200);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn starves_no_longer_than_the_resource_hierarchy() {
+        let streaks = |strategy: &mut dyn Strategy| {
+            let results: HashMap<usize, Vec<State>> = run_in_lockstep(strategy, 5, 200);
+            longest_starvation_one_run(&results)
+        };
+
+        let mut unit = HungerPriority::new();
+        let priority = streaks(&mut unit);
+        let hierarchy = streaks(&mut ResourceHierarchy::new());
+
+        assert!(priority <= hierarchy, "{} > {}", priority, hierarchy);
+        assert!(unit.counters()[0].1 > 0);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::hunger_priority_impl::diner::Diner;
+use crate::dining_philosophers::hunger_priority_impl::eating::Eating;
+use crate::dining_philosophers::hunger_priority_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Holding the left fork. The right fork is left on the table for a right neighbour who has
+/// waited longer, and when it is in use the left fork is put down again, so no ring of held forks
+/// can last.
+#[derive(Debug, PartialEq)]
+pub struct LeftThinking {
+    left_fork: Option<Fork>,
+    diner: Option<Diner>,
+}
+
+impl LeftThinking {
+    pub fn new(left_fork: Fork, diner: Diner) -> LeftThinking {
+        LeftThinking {
+            left_fork: Some(left_fork),
+            diner: Some(diner),
+        }
+    }
+
+    /// Back to thinking, unless the table refuses the left fork and the philosopher keeps it.
+    fn drop_left(left_fork: Fork, diner: Diner) -> Box<dyn StateMachine + Send> {
+        match keep_refused(diner.position(), diner.put_down_left(left_fork)) {
+            Some(left_fork) => Box::new(LeftThinking::new(left_fork, diner)),
+            None => Box::new(Thinking::new(diner)),
+        }
+    }
+}
+
+impl StateMachine for LeftThinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.diner.take() {
+            None => { panic!("No longer valid") }
+            Some(d) => {
+                d.starve();
+                let left_fork = self.left_fork.take().unwrap();
+                if d.yields_right_fork() {
+                    debug!("{}: Right neighbour is hungrier, drop left, to thinking", d.position());
+                    return LeftThinking::drop_left(left_fork, d);
+                }
+                match d.take_right() {
+                    Some(right_fork) => {
+                        debug!("{}: Got right. Eating!", d.position());
+                        d.fed();
+                        Box::new(Eating::new(left_fork, right_fork, d))
+                    }
+                    None => {
+                        debug!("{}: Right in use, drop left, to thinking", d.position());
+                        LeftThinking::drop_left(left_fork, d)
+                    }
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::LeftThinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::hunger_priority_impl::diner::{diners, Diner};
+    use crate::dining_philosophers::hunger_priority_impl::left_thinking::LeftThinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+    fn left_thinking(diner: Diner) -> Box<dyn StateMachine> {
+        let fork = diner.take_left().unwrap();
+        Box::new(LeftThinking::new(fork, diner))
+    }
+
+    #[test]
+    fn state_is_left_thinking() {
+        let (mut diners, _) = diners(3);
+
+        assert_eq!(left_thinking(diners.remove(0)).state(), State::LeftThinking);
+    }
+
+    #[test]
+    fn eats_when_the_right_fork_is_free() {
+        let (mut diners, _) = diners(3);
+        let mut unit = left_thinking(diners.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn yields_the_right_fork_to_a_hungrier_neighbour() {
+        let (mut diners, yields) = diners(3);
+        let neighbour = diners.remove(1);
+        neighbour.starve();
+        neighbour.starve();
+        let mut unit = left_thinking(diners.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+        assert_eq!(*yields.lock().unwrap(), 1);
+        assert!(neighbour.take_left().is_some());
+        assert!(diners[0].table_interaction.get_right_fork().is_some());
+    }
+
+    #[test]
+    fn puts_the_left_fork_down_when_the_right_is_in_use() {
+        let (mut diners, _) = diners(3);
+        let neighbour = diners.remove(1);
+        let _fork = neighbour.take_left();
+        let mut unit = left_thinking(diners.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+        assert!(diners[0].table_interaction.get_right_fork().is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let (mut diners, _) = diners(3);
+        let diner = diners.remove(0);
+        let fork = diner.take_left().unwrap();
+        let mut unit = LeftThinking::new(fork, diner);
+
+        unit.transition();
+        unit.transition();
+    }
+}
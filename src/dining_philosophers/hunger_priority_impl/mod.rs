@@ -0,0 +1,5 @@
+pub mod diner;
+pub mod thinking;
+pub mod left_thinking;
+pub mod eating;
+pub mod hunger_priority;
@@ -0,0 +1,108 @@
+use crate::dining_philosophers::hunger_priority_impl::diner::Diner;
+use crate::dining_philosophers::hunger_priority_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+/// Thinking, or hungry and reaching for the left fork unless the left neighbour, who needs it
+/// as its second fork, has waited longer.
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    diner: Option<Diner>,
+}
+
+impl Thinking {
+    pub fn new(diner: Diner) -> Thinking {
+        Thinking {
+            diner: Some(diner),
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.diner.take() {
+            None => { panic!("No longer valid") }
+            Some(d) => {
+                d.starve();
+                if d.yields_left_fork() {
+                    debug!("{}: Left neighbour is hungrier, still thinking", d.position());
+                    return Box::new(Thinking::new(d));
+                }
+                match d.take_left() {
+                    Some(fork) => {
+                        debug!("{}: Got left, to left thinking", d.position());
+                        Box::new(LeftThinking::new(fork, d))
+                    }
+                    None => {
+                        debug!("{}: No left, still thinking", d.position());
+                        Box::new(Thinking::new(d))
+                    }
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.diner {
+            Some(ref d) if d.is_hungry() => State::Hungry,
+            _ => State::Thinking,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::hunger_priority_impl::diner::diners;
+    use crate::dining_philosophers::hunger_priority_impl::thinking::Thinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+    #[test]
+    fn state_is_thinking() {
+        assert_eq!(Thinking::new(diners(2).0.remove(0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn takes_the_left_fork_and_starves_a_step() {
+        let (mut diners, _) = diners(3);
+        let neighbour = diners.remove(2);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(diners.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+        assert_eq!(neighbour.table_interaction.neighbours().right.starvation, 1);
+    }
+
+    #[test]
+    fn stays_hungry_without_the_left_fork() {
+        let (mut diners, _) = diners(3);
+        let _fork = diners[2].table_interaction.get_right_fork();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(diners.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    fn leaves_the_left_fork_to_a_hungrier_neighbour() {
+        let (mut diners, _) = diners(3);
+        let neighbour = diners.remove(2);
+        neighbour.starve();
+        neighbour.starve();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(diners.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+        assert!(neighbour.take_right().is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(diners(2).0.remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
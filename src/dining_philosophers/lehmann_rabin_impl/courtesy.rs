@@ -0,0 +1,93 @@
+/// What the philosophers of one table know about each other's hunger.
+#[derive(Debug, PartialEq)]
+pub struct Courtesy {
+    /// The tick at which each philosopher last became hungry, while it still is.
+    hungry_since: Vec<Option<usize>>,
+    meals: Vec<usize>,
+}
+
+impl Courtesy {
+    pub fn new(number_of_philosophers: usize) -> Courtesy {
+        Courtesy {
+            hungry_since: vec![None; number_of_philosophers],
+            meals: vec![0; number_of_philosophers],
+        }
+    }
+
+    /// Marks the philosopher hungry from the given tick, unless it already is.
+    pub fn hungry(&mut self, position: usize, now: usize) {
+        self.hungry_since[position].get_or_insert(now);
+    }
+
+    pub fn ate(&mut self, position: usize) {
+        self.hungry_since[position] = None;
+        self.meals[position] += 1;
+    }
+
+    /// Whether the philosopher should leave a shared fork to the neighbour, who has been hungry for longer.
+    pub fn gives_way(&self, position: usize, neighbour: usize) -> bool {
+        match (self.hungry_since[position], self.hungry_since[neighbour]) {
+            (Some(ours), Some(theirs)) => theirs < ours,
+            _ => false,
+        }
+    }
+
+    pub fn meals(&self) -> &[usize] {
+        &self.meals
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+
+    #[test]
+    fn gives_way_to_a_neighbour_hungry_for_longer() {
+        let mut unit = Courtesy::new(3);
+        unit.hungry(1, 2);
+        unit.hungry(0, 5);
+
+        assert!(unit.gives_way(0, 1));
+        assert!(!unit.gives_way(1, 0));
+    }
+
+    #[test]
+    fn does_not_give_way_to_a_neighbour_who_is_not_hungry() {
+        let mut unit = Courtesy::new(3);
+        unit.hungry(0, 5);
+
+        assert!(!unit.gives_way(0, 1));
+    }
+
+    #[test]
+    fn does_not_give_way_on_a_tie() {
+        let mut unit = Courtesy::new(3);
+        unit.hungry(0, 4);
+        unit.hungry(1, 4);
+
+        assert!(!unit.gives_way(0, 1));
+        assert!(!unit.gives_way(1, 0));
+    }
+
+    #[test]
+    fn staying_hungry_keeps_the_first_tick() {
+        let mut unit = Courtesy::new(2);
+        unit.hungry(0, 1);
+        unit.hungry(1, 3);
+        unit.hungry(0, 7);
+
+        assert!(unit.gives_way(1, 0));
+    }
+
+    #[test]
+    fn a_meal_ends_the_hunger_and_is_counted() {
+        let mut unit = Courtesy::new(2);
+        unit.hungry(0, 1);
+        unit.hungry(1, 3);
+
+        unit.ate(0);
+
+        assert!(!unit.gives_way(1, 0));
+        assert_eq!(unit.meals(), &[1, 0]);
+    }
+}
@@ -0,0 +1,93 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+use crate::dining_philosophers::lehmann_rabin_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    seat: Option<Seat>,
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+}
+
+impl Eating {
+    pub fn new(seat: Seat, left_fork: Fork, right_fork: Fork) -> Eating {
+        Eating {
+            seat: Some(seat),
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match (self.seat.take(), self.left_fork.take(), self.right_fork.take()) {
+            (Some(mut seat), Some(left_fork), Some(right_fork)) => {
+                debug!("{}: Drop both, to thinking", seat.position());
+                seat.tick();
+                if let Some((left_fork, right_fork)) = keep_refused(seat.position(), seat.put_down_both(left_fork, right_fork)) {
+                    return Box::new(Eating::new(seat, left_fork, right_fork));
+                }
+                seat.ate();
+                Box::new(Thinking::new(seat))
+            }
+            _ => { panic!("No longer valid") }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+    use crate::dining_philosophers::lehmann_rabin_impl::eating::Eating;
+    use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+    use crate::dining_philosophers::table::Table;
+
+    fn eating(courtesy: &Arc<Mutex<Courtesy>>) -> (Eating, Arc<Mutex<Table>>) {
+        let seat = Seat::new(Table::new(2).get_interactions().remove(0), Arc::clone(courtesy), SeededRng::new(0));
+        let left = seat.take(Side::Left).unwrap();
+        let right = seat.take(Side::Right).unwrap();
+        let table = Arc::clone(&seat.table_interaction.table);
+        (Eating::new(seat, left, right), table)
+    }
+
+    #[test]
+    fn state_is_eating() {
+        let courtesy = Arc::new(Mutex::new(Courtesy::new(2)));
+
+        assert_eq!(eating(&courtesy).0.state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_both_forks_down_and_counts_the_meal() {
+        let courtesy = Arc::new(Mutex::new(Courtesy::new(2)));
+        let (unit, table) = eating(&courtesy);
+        let mut unit: Box<dyn StateMachine> = Box::new(unit);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(courtesy.lock().unwrap().meals(), &[1, 0]);
+        assert_eq!(*table.lock().unwrap(), Table::new(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let courtesy = Arc::new(Mutex::new(Courtesy::new(2)));
+        let mut unit = eating(&courtesy).0;
+
+        unit.transition();
+        unit.transition();
+    }
+}
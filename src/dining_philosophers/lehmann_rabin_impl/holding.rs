@@ -0,0 +1,127 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::lehmann_rabin_impl::eating::Eating;
+use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+use crate::dining_philosophers::lehmann_rabin_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+/// Holding the fork the coin picked and reaching for the other one once.
+#[derive(Debug, PartialEq)]
+pub struct Holding {
+    seat: Option<Seat>,
+    side: Side,
+    fork: Option<Fork>,
+}
+
+impl Holding {
+    pub fn new(seat: Seat, side: Side, fork: Fork) -> Holding {
+        Holding {
+            seat: Some(seat),
+            side,
+            fork: Some(fork),
+        }
+    }
+}
+
+impl StateMachine for Holding {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match (self.seat.take(), self.fork.take()) {
+            (Some(mut seat), Some(fork)) => {
+                seat.tick();
+                match seat.take(self.side.other()) {
+                    Some(other) => {
+                        debug!("{}: Got {:?}. Eating!", seat.position(), self.side.other());
+                        let (left, right) = match self.side {
+                            Side::Left => (fork, other),
+                            Side::Right => (other, fork),
+                        };
+                        Box::new(Eating::new(seat, left, right))
+                    }
+                    None => {
+                        debug!("{}: No {:?}, put {:?} down", seat.position(), self.side.other(), self.side);
+                        match keep_refused(seat.position(), seat.put_down(self.side, fork)) {
+                            Some(fork) => Box::new(Holding::new(seat, self.side, fork)),
+                            None => Box::new(Thinking::new(seat)),
+                        }
+                    }
+                }
+            }
+            _ => { panic!("No longer valid") }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.side {
+            Side::Left => State::LeftThinking,
+            Side::Right => State::RightThinking,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+    use crate::dining_philosophers::lehmann_rabin_impl::holding::Holding;
+    use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+
+    fn seats(size: usize) -> Vec<Seat> {
+        let courtesy = Arc::new(Mutex::new(Courtesy::new(size)));
+        Table::new(size).get_interactions().into_iter()
+            .map(|t| Seat::new(t, Arc::clone(&courtesy), SeededRng::new(0)))
+            .collect()
+    }
+
+    #[test]
+    fn reports_the_fork_in_hand() {
+        let mut seats = seats(3);
+        let left = seats[0].take(Side::Left).unwrap();
+        let right = seats[1].take(Side::Right).unwrap();
+
+        assert_eq!(Holding::new(seats.remove(0), Side::Left, left).state(), State::LeftThinking);
+        assert_eq!(Holding::new(seats.remove(0), Side::Right, right).state(), State::RightThinking);
+    }
+
+    #[test]
+    fn eats_when_the_other_fork_is_free() {
+        let seat = seats(3).remove(0);
+        let fork = seat.take(Side::Right).unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Holding::new(seat, Side::Right, fork));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_the_fork_down_when_the_other_is_taken() {
+        let mut seats = seats(3);
+        let neighbour = seats.remove(2);
+        let seat = seats.remove(0);
+        let fork = seat.take(Side::Right).unwrap();
+        let _taken = neighbour.take(Side::Right);
+        let table = Arc::clone(&seat.table_interaction.table);
+        let mut unit: Box<dyn StateMachine> = Box::new(Holding::new(seat, Side::Right, fork));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert!(TableInteraction { position: 1, table }.get_left_fork().is_some());
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let seat = seats(3).remove(0);
+        let fork = seat.take(Side::Left).unwrap();
+        let mut unit = Holding::new(seat, Side::Left, fork);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+use crate::dining_philosophers::lehmann_rabin_impl::thinking::Thinking;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Lehmann and Rabin's courteous philosophers.
+///
+/// A hungry philosopher flips a coin to pick its first fork and waits for it, then takes the
+/// other fork if it is free or puts the first one back and flips again. A philosopher never
+/// takes a fork its neighbour has been hungry for longer. Each seat flips its own coin, seeded
+/// from `seed` and its position.
+pub struct LehmannRabin {
+    courtesy: Arc<Mutex<Courtesy>>,
+    seed: u64,
+}
+
+impl LehmannRabin {
+    pub fn new(number_of_philosophers: usize, seed: u64) -> LehmannRabin {
+        LehmannRabin {
+            courtesy: Arc::new(Mutex::new(Courtesy::new(number_of_philosophers))),
+            seed,
+        }
+    }
+
+    /// The meals each philosopher has finished, by position.
+    pub fn meals(&self) -> Vec<usize> {
+        self.courtesy.lock().unwrap().meals().to_vec()
+    }
+}
+
+impl Strategy for LehmannRabin {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let coin = SeededRng::new(self.seed.wrapping_add(table_interaction.position as u64));
+        Box::new(Thinking::new(Seat::new(table_interaction, Arc::clone(&self.courtesy), coin)))
+    }
+
+    fn counters(&self) -> Counters {
+        let meals = self.meals();
+        vec![
+            ("fewest meals", meals.iter().cloned().min().unwrap_or(0)),
+            ("most meals", meals.iter().cloned().max().unwrap_or(0)),
+            ("starved", meals.iter().filter(|m| **m == 0).count()),
+        ]
+    }
+
+    /// The neighbour it is courteous to is whoever shares the fork.
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::lehmann_rabin_impl::lehmann_rabin::LehmannRabin;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+    use crate::dining_philosophers::strategy::Strategy;
+
+    #[test]
+    fn everyone_eats() {
+        for size in 2..=16 {
+            for seed in 0..5 {
+                let mut unit = LehmannRabin::new(size, seed);
+                run_in_lockstep(&mut unit, size, 20 * size);
+
+                assert!(unit.meals().iter().all(|m| *m > 0), "seed {}: {:?}", seed, unit.meals());
+            }
+        }
+    }
+
+    #[test]
+    fn nobody_starves_in_a_long_run() {
+        let mut unit = LehmannRabin::new(5, 1);
+        let results = run_in_lockstep(&mut unit, 5, 2000);
+
+        for (id, history) in &results {
+            assert!(history[1000..].contains(&State::Eating), "philosopher {} stopped eating", id);
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let results = run_in_lockstep(&mut LehmannRabin::new(5, 2), 5, 200);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn counts_the_meals_of_every_philosopher() {
+        let mut unit = LehmannRabin::new(5, 3);
+        let results = run_in_lockstep(&mut unit, 5, 100);
+
+        for (id, history) in &results {
+            let finished = history.windows(2).filter(|w| w[0] == State::Eating && w[1] != State::Eating).count();
+            assert_eq!(unit.meals()[*id], finished);
+        }
+        assert_eq!(unit.counters()[2], ("starved", 0));
+    }
+
+    #[test]
+    fn is_reproducible_from_seed() {
+        let run = || run_in_lockstep(&mut LehmannRabin::new(5, 7), 5, 100);
+
+        assert_eq!(run(), run());
+    }
+}
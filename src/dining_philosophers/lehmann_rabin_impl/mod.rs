@@ -0,0 +1,6 @@
+pub mod courtesy;
+pub mod seat;
+pub mod thinking;
+pub mod holding;
+pub mod eating;
+pub mod lehmann_rabin;
@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+
+use rand::Rng;
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Everything a courteous philosopher carries from state to state: its place at the table,
+/// its coin and its own clock.
+#[derive(Debug)]
+pub struct Seat {
+    pub table_interaction: TableInteraction,
+    courtesy: Arc<Mutex<Courtesy>>,
+    coin: SeededRng,
+    now: usize,
+}
+
+impl PartialEq for Seat {
+    fn eq(&self, other: &Seat) -> bool {
+        (&self.table_interaction, &self.coin, self.now) == (&other.table_interaction, &other.coin, other.now)
+    }
+}
+
+impl Seat {
+    pub fn new(table_interaction: TableInteraction, courtesy: Arc<Mutex<Courtesy>>, coin: SeededRng) -> Seat {
+        Seat {
+            table_interaction,
+            courtesy,
+            coin,
+            now: 0,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    pub fn tick(&mut self) {
+        self.now += 1;
+    }
+
+    pub fn flip(&mut self) -> Side {
+        if self.coin.gen_bool(0.5) { Side::Left } else { Side::Right }
+    }
+
+    pub fn hungry(&self) {
+        self.courtesy.lock().unwrap().hungry(self.position(), self.now);
+    }
+
+    pub fn ate(&self) {
+        self.courtesy.lock().unwrap().ate(self.position());
+    }
+
+    /// The neighbour who shares the fork on the given side.
+    fn neighbour(&self, side: Side) -> usize {
+        let neighbours = self.table_interaction.neighbours();
+        match side {
+            Side::Left => neighbours.left.position,
+            Side::Right => neighbours.right.position,
+        }
+    }
+
+    /// Takes the fork on the given side, unless it is in use or the neighbour sharing it has
+    /// been hungry for longer.
+    pub fn take(&self, side: Side) -> Option<Fork> {
+        let neighbour = self.neighbour(side);
+        if self.courtesy.lock().unwrap().gives_way(self.position(), neighbour) {
+            return None;
+        }
+        match side {
+            Side::Left => self.table_interaction.get_left_fork(),
+            Side::Right => self.table_interaction.get_right_fork(),
+        }
+    }
+
+    /// Puts the fork back, or hands it back with the reason the table refused it.
+    pub fn put_down(&self, side: Side, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        match side {
+            Side::Left => self.table_interaction.return_left_fork(fork),
+            Side::Right => self.table_interaction.return_right_fork(fork),
+        }
+    }
+
+    pub fn put_down_both(&self, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        self.table_interaction.return_both_forks(left_fork, right_fork)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+    use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+    use crate::dining_philosophers::table::Table;
+
+    fn seats(size: usize) -> Vec<Seat> {
+        let courtesy = Arc::new(Mutex::new(Courtesy::new(size)));
+        Table::new(size).get_interactions().into_iter()
+            .map(|t| Seat::new(t, Arc::clone(&courtesy), SeededRng::new(0)))
+            .collect()
+    }
+
+    #[test]
+    fn takes_a_free_fork() {
+        let seats = seats(3);
+
+        assert!(seats[0].take(Side::Left).is_some());
+        assert!(seats[0].take(Side::Right).is_some());
+    }
+
+    #[test]
+    fn cannot_take_a_fork_in_use() {
+        let seats = seats(3);
+        let _fork = seats[1].take(Side::Left);
+
+        assert!(seats[0].take(Side::Right).is_none());
+    }
+
+    #[test]
+    fn leaves_the_fork_to_a_neighbour_hungry_for_longer() {
+        let mut seats = seats(3);
+        seats[1].hungry();
+        seats[0].tick();
+        seats[0].hungry();
+
+        assert!(seats[0].take(Side::Right).is_none());
+        assert!(seats[0].take(Side::Left).is_some());
+    }
+
+    #[test]
+    fn returned_fork_can_be_taken_again() {
+        let seats = seats(3);
+        let fork = seats[0].take(Side::Right).unwrap();
+
+        seats[0].put_down(Side::Right, fork).unwrap();
+
+        assert!(seats[1].take(Side::Left).is_some());
+    }
+
+    #[test]
+    fn coin_lands_on_both_sides() {
+        let mut seats = seats(2);
+
+        let sides: Vec<Side> = (0..50).map(|_| seats[0].flip()).collect();
+
+        assert!(sides.contains(&Side::Left));
+        assert!(sides.contains(&Side::Right));
+    }
+}
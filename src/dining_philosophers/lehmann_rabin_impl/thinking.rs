@@ -0,0 +1,108 @@
+use crate::dining_philosophers::lehmann_rabin_impl::holding::Holding;
+use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+/// Hungry with no fork in hand, waiting for the fork the coin picked.
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    seat: Option<Seat>,
+    /// The side the coin picked, kept until that fork is taken.
+    first: Option<Side>,
+}
+
+impl Thinking {
+    pub fn new(seat: Seat) -> Thinking {
+        Thinking {
+            seat: Some(seat),
+            first: None,
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.seat.take() {
+            None => { panic!("No longer valid") }
+            Some(mut seat) => {
+                seat.tick();
+                seat.hungry();
+                let first = match self.first {
+                    Some(side) => side,
+                    None => seat.flip(),
+                };
+                match seat.take(first) {
+                    Some(fork) => {
+                        debug!("{}: Got {:?}, reaching for the other", seat.position(), first);
+                        Box::new(Holding::new(seat, first, fork))
+                    }
+                    None => {
+                        debug!("{}: No {:?}, still thinking", seat.position(), first);
+                        Box::new(Thinking { seat: Some(seat), first: Some(first) })
+                    }
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::lehmann_rabin_impl::courtesy::Courtesy;
+    use crate::dining_philosophers::lehmann_rabin_impl::seat::Seat;
+    use crate::dining_philosophers::lehmann_rabin_impl::thinking::Thinking;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+
+    fn seat(size: usize, position: usize) -> Seat {
+        let table_interaction = Table::new(size).get_interactions().remove(position);
+        Seat::new(table_interaction, Arc::new(Mutex::new(Courtesy::new(size))), SeededRng::new(0))
+    }
+
+    #[test]
+    fn state_is_thinking() {
+        assert_eq!(Thinking::new(seat(2, 0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn takes_the_fork_the_coin_picked() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seat(2, 0)));
+
+        unit = unit.transition();
+
+        assert!(unit.state() == State::LeftThinking || unit.state() == State::RightThinking);
+    }
+
+    #[test]
+    fn waits_for_the_picked_fork_without_flipping_again() {
+        let seat = seat(1, 0);
+        let fork = seat.table_interaction.get_left_fork().unwrap();
+        let table = Arc::clone(&seat.table_interaction.table);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seat));
+
+        for _ in 0..5 {
+            unit = unit.transition();
+            assert_eq!(unit.state(), State::Thinking);
+        }
+        TableInteraction { position: 0, table }.return_left_fork(fork).unwrap();
+        unit = unit.transition();
+
+        assert!(unit.state() != State::Thinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(seat(2, 0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
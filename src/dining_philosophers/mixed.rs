@@ -0,0 +1,185 @@
+use crate::dining_philosophers::analysis::{Counters, Seating};
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Philosophers of different strategies at one table, each strategy seated at a run of
+/// consecutive positions. They share the forks of the `Table`, so only strategies that take their
+/// forks from it can be mixed.
+pub struct Mixed {
+    groups: Vec<Group>,
+    rotation: usize,
+}
+
+struct Group {
+    name: &'static str,
+    strategy: Box<dyn Strategy>,
+    seats: usize,
+}
+
+impl Mixed {
+    pub fn new() -> Mixed {
+        Mixed {
+            groups: Vec::new(),
+            rotation: 0,
+        }
+    }
+
+    /// Seats the next `seats` philosophers with the strategy, which should be built for the size
+    /// of the whole table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strategy does not share the forks of the `Table`.
+    pub fn seating(mut self, name: &'static str, strategy: Box<dyn Strategy>, seats: usize) -> Mixed {
+        assert!(strategy.shares_table(), "{} keeps forks of its own and cannot be mixed", name);
+        self.groups.push(Group { name, strategy, seats });
+        self
+    }
+
+    /// Moves every strategy the given number of seats to the right, round the table.
+    pub fn rotated(mut self, seats: usize) -> Mixed {
+        self.rotation = seats;
+        self
+    }
+
+    /// The number of seats taken.
+    pub fn size(&self) -> usize {
+        self.groups.iter().map(|group| group.seats).sum()
+    }
+
+    /// The name of the strategy at every seat.
+    pub fn seats(&self) -> Seating {
+        let mut seats: Seating = self.groups.iter()
+            .flat_map(|group| vec![group.name; group.seats])
+            .collect();
+        if !seats.is_empty() {
+            let rotation = self.rotation % seats.len();
+            seats.rotate_right(rotation);
+        }
+        seats
+    }
+}
+
+impl Default for Mixed {
+    fn default() -> Self {
+        Mixed::new()
+    }
+}
+
+impl Strategy for Mixed {
+    /// # Panics
+    ///
+    /// Panics if no strategy was seated at the position.
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let size = self.size();
+        let position = match table_interaction.position < size {
+            true => (table_interaction.position + size - self.rotation % size) % size,
+            false => table_interaction.position,
+        };
+        let mut first_seat = 0;
+        for group in self.groups.iter_mut() {
+            if position < first_seat + group.seats {
+                return group.strategy.seat(table_interaction);
+            }
+            first_seat += group.seats;
+        }
+        panic!("No strategy seated at {}", table_interaction.position)
+    }
+
+    /// The counters of every strategy at the table, in seating order.
+    fn counters(&self) -> Counters {
+        self.groups.iter()
+            .flat_map(|group| group.strategy.counters())
+            .collect()
+    }
+
+    /// Only a topology that fits every strategy at the table.
+    fn fits(&self, topology: &Topology) -> bool {
+        self.groups.iter().all(|group| group.strategy.fits(topology))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::always_thinking::AlwaysThinking;
+    use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+    use crate::dining_philosophers::mixed::Mixed;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::waiter_impl::arbitrator::GrantOrder;
+    use crate::dining_philosophers::waiter_impl::waiter::Waiter;
+
+    fn mixed() -> Mixed {
+        Mixed::new()
+            .seating("waiter", Box::new(Waiter::new(5, GrantOrder::Fifo)), 2)
+            .seating("resource-hierarchy", Box::new(ResourceHierarchy::new()), 2)
+            .seating("always-thinking", Box::new(AlwaysThinking {}), 1)
+    }
+
+    #[test]
+    fn names_the_strategy_at_every_seat() {
+        let unit = mixed();
+
+        assert_eq!(unit.size(), 5);
+        assert_eq!(unit.seats(), vec!["waiter", "waiter", "resource-hierarchy", "resource-hierarchy", "always-thinking"]);
+    }
+
+    #[test]
+    fn seats_every_strategy_at_its_own_positions() {
+        let results = run_in_lockstep(&mut mixed(), 5, 20);
+
+        for position in 0..4 {
+            assert!(results[&position].contains(&State::Eating), "philosopher {} never ate", position);
+        }
+        assert!(!results[&4].contains(&State::Eating));
+    }
+
+    #[test]
+    fn neighbours_of_different_strategies_never_eat_together() {
+        let results = run_in_lockstep(&mut mixed(), 5, 100);
+
+        for position in 0..5 {
+            let right = &results[&((position + 1) % 5)];
+            for (state, right_state) in results[&position].iter().zip(right) {
+                assert!(!(*state == State::Eating && *right_state == State::Eating));
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_the_counters_of_every_strategy() {
+        let mut unit = mixed();
+
+        run_in_lockstep(&mut unit, 5, 20);
+
+        let names: Vec<&str> = unit.counters().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["grants", "refusals"]);
+    }
+
+    #[test]
+    fn rotation_moves_every_strategy_round_the_table() {
+        let mut unit = mixed().rotated(6);
+
+        let results = run_in_lockstep(&mut unit, 5, 20);
+
+        assert_eq!(unit.seats(), vec!["always-thinking", "waiter", "waiter", "resource-hierarchy", "resource-hierarchy"]);
+        assert!(!results[&0].contains(&State::Eating));
+        assert!(results[&4].contains(&State::Eating));
+    }
+
+    #[test]
+    #[should_panic(expected = "chandy-misra keeps forks of its own")]
+    fn cannot_seat_a_strategy_with_forks_of_its_own() {
+        Mixed::new().seating("chandy-misra", Box::new(ChandyMisra::new(5)), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "No strategy seated at 5")]
+    fn cannot_seat_more_philosophers_than_planned() {
+        run_in_lockstep(&mut mixed(), 6, 1);
+    }
+}
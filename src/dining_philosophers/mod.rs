@@ -1,5 +1,28 @@
 pub mod resource_hierarchy_impl;
+pub mod chandy_misra_impl;
+pub mod waiter_impl;
+pub mod footman_impl;
+pub mod lehmann_rabin_impl;
+pub mod tanenbaum_impl;
+pub mod preemption_impl;
+pub mod token_ring_impl;
+pub mod hunger_priority_impl;
+pub mod policy_table_impl;
+pub mod q_learning_impl;
+pub mod typestate_impl;
+pub mod adversary_impl;
+pub mod drinking_impl;
+pub mod optimiser;
+pub mod tournament;
+pub mod timed_impl;
 pub mod table;
+pub mod topology;
 pub mod fork;
 pub mod philosopher;
 pub mod analysis;
+pub mod random;
+pub mod strategy;
+pub mod always_thinking;
+pub mod mixed;
+pub mod registry;
+pub mod simulation;
@@ -0,0 +1,163 @@
+use rand::Rng;
+
+use crate::dining_philosophers::optimiser::fitness::Fitness;
+use crate::dining_philosophers::optimiser::genome::Genome;
+use crate::dining_philosophers::random::SeededRng;
+
+/// How the population evolves. All randomness is drawn from `seed`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Settings {
+    pub population: usize,
+    pub generations: usize,
+    /// The fittest genomes carried over unchanged into the next generation.
+    pub elite: usize,
+    /// The genomes drawn for each tournament that picks a parent.
+    pub tournament: usize,
+    pub mutation_rate: f64,
+    pub mutation_size: f64,
+    pub seed: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            population: 20,
+            generations: 15,
+            elite: 2,
+            tournament: 3,
+            mutation_rate: 0.2,
+            mutation_size: 0.2,
+            seed: 0,
+        }
+    }
+}
+
+/// The best and mean fitness of one generation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Generation {
+    pub best: f32,
+    pub mean: f32,
+}
+
+/// The fittest genome found and the score curve that led to it.
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub best: Genome,
+    pub fitness: f32,
+    pub curve: Vec<Generation>,
+}
+
+/// Evolves a random population of genomes against the fitness.
+///
+/// # Panics
+///
+/// Panics if the population is empty, the elite outnumbers it or a tournament draws nobody.
+pub fn evolve(settings: &Settings, fitness: &Fitness) -> Outcome {
+    assert!(settings.population > 0 && settings.elite <= settings.population && settings.tournament > 0);
+    let mut rng = SeededRng::new(settings.seed);
+    let mut population: Vec<Genome> = (0..settings.population).map(|_| Genome::random(&mut rng)).collect();
+    let mut curve = Vec::with_capacity(settings.generations);
+    let mut ranked = rank(&population, fitness);
+
+    for generation in 0..settings.generations {
+        let best = ranked[0].1;
+        let mean = ranked.iter().map(|(_, f)| f).sum::<f32>() / ranked.len() as f32;
+        info!("Generation {}: best {}, mean {}", generation, best, mean);
+        curve.push(Generation { best, mean });
+        if generation + 1 == settings.generations {
+            break;
+        }
+
+        population = ranked.iter().take(settings.elite).map(|(genome, _)| *genome).collect();
+        while population.len() < settings.population {
+            let mother = select(&ranked, settings.tournament, &mut rng);
+            let father = select(&ranked, settings.tournament, &mut rng);
+            let child = mother.crossover(father, &mut rng).mutate(settings.mutation_rate, settings.mutation_size, &mut rng);
+            population.push(child);
+        }
+        ranked = rank(&population, fitness);
+    }
+
+    Outcome {
+        best: ranked[0].0,
+        fitness: ranked[0].1,
+        curve,
+    }
+}
+
+/// The genomes with their fitness, fittest first. Equally fit genomes keep their order.
+fn rank(population: &[Genome], fitness: &Fitness) -> Vec<(Genome, f32)> {
+    let mut ranked: Vec<(Genome, f32)> = population.iter().map(|genome| (*genome, fitness.evaluate(genome))).collect();
+    ranked.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap());
+    ranked
+}
+
+/// The fittest of a few genomes drawn at random.
+fn select<'a>(ranked: &'a [(Genome, f32)], tournament: usize, rng: &mut SeededRng) -> &'a Genome {
+    let winner = (0..tournament).map(|_| rng.gen_range(0, ranked.len())).min().unwrap();
+    &ranked[winner].0
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::optimiser::evolution::{evolve, Settings};
+    use crate::dining_philosophers::optimiser::fitness::Fitness;
+
+    fn small() -> (Settings, Fitness) {
+        let settings = Settings { population: 8, generations: 4, ..Settings::default() };
+        let fitness = Fitness { rounds: 60, runs: 2, ..Fitness::default() };
+        (settings, fitness)
+    }
+
+    #[test]
+    fn same_seed_same_search() {
+        let (settings, fitness) = small();
+
+        assert_eq!(evolve(&settings, &fitness), evolve(&settings, &fitness));
+    }
+
+    #[test]
+    fn another_seed_searches_elsewhere() {
+        let (settings, fitness) = small();
+        let other = Settings { seed: 1, ..settings.clone() };
+
+        assert_ne!(evolve(&settings, &fitness).curve, evolve(&other, &fitness).curve);
+    }
+
+    #[test]
+    fn records_a_score_for_every_generation() {
+        let (settings, fitness) = small();
+
+        let outcome = evolve(&settings, &fitness);
+
+        assert_eq!(outcome.curve.len(), 4);
+        assert_eq!(outcome.curve.last().unwrap().best, outcome.fitness);
+        assert!(outcome.curve.iter().all(|g| g.mean <= g.best));
+    }
+
+    #[test]
+    fn elitism_never_loses_the_best_genome() {
+        let (settings, fitness) = small();
+
+        let curve = evolve(&settings, &fitness).curve;
+
+        assert!(curve.windows(2).all(|pair| pair[1].best >= pair[0].best), "{:?}", curve);
+    }
+
+    #[test]
+    fn best_genome_has_the_reported_fitness() {
+        let (settings, fitness) = small();
+
+        let outcome = evolve(&settings, &fitness);
+
+        assert_eq!(fitness.evaluate(&outcome.best), outcome.fitness);
+    }
+
+    #[test]
+    #[should_panic]
+    fn elite_cannot_outnumber_the_population() {
+        let (settings, fitness) = small();
+
+        evolve(&Settings { elite: 9, ..settings }, &fitness);
+    }
+}
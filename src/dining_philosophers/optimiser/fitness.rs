@@ -0,0 +1,78 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::{compute_average_fairness, compute_average_score, RunResults};
+use crate::dining_philosophers::optimiser::genome::Genome;
+use crate::dining_philosophers::policy_table_impl::scripted::Scripted;
+use crate::dining_philosophers::simulation::run_in_lockstep;
+
+/// Scores a genome by the average analysis score of its policy over several lockstep runs,
+/// less a penalty for every point of fairness below 1.
+///
+/// Run `i` is seeded with `seed + i`, so a genome always gets the same fitness and every
+/// genome faces the same draws.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Fitness {
+    pub philosophers: usize,
+    pub rounds: usize,
+    pub runs: usize,
+    pub fairness_penalty: f32,
+    pub seed: u64,
+}
+
+impl Default for Fitness {
+    fn default() -> Self {
+        Fitness {
+            philosophers: 5,
+            rounds: 200,
+            runs: 5,
+            fairness_penalty: 1.0,
+            seed: 0,
+        }
+    }
+}
+
+impl Fitness {
+    pub fn evaluate(&self, genome: &Genome) -> f32 {
+        let policy = Arc::new(genome.policy_table());
+        let results: Vec<Arc<Mutex<RunResults>>> = (0..self.runs)
+            .map(|run| {
+                let mut strategy = Scripted::new(Arc::clone(&policy), self.seed.wrapping_add(run as u64));
+                Arc::new(Mutex::new(run_in_lockstep(&mut strategy, self.philosophers, self.rounds)))
+            })
+            .collect();
+        compute_average_score(&results) - self.fairness_penalty * (1.0 - compute_average_fairness(&results))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::optimiser::fitness::Fitness;
+    use crate::dining_philosophers::optimiser::genome::{Genome, GENES};
+
+    fn fitness() -> Fitness {
+        Fitness { rounds: 50, runs: 2, ..Fitness::default() }
+    }
+
+    #[test]
+    fn same_genome_same_fitness() {
+        let genome = Genome::from_genes([0.5; GENES]);
+
+        assert_eq!(fitness().evaluate(&genome), fitness().evaluate(&genome));
+    }
+
+    #[test]
+    fn philosophers_who_never_start_eating_score_zero() {
+        let genome = Genome::from_genes([0.5, 1.0, 0.5, 0.5, 0.5]);
+
+        assert_eq!(fitness().evaluate(&genome), 0.0);
+    }
+
+    #[test]
+    fn unfairness_is_penalised() {
+        let genome = Genome::from_genes([1.0, 0.0, 0.0, 1.0, 0.5]);
+        let lenient = Fitness { fairness_penalty: 0.0, ..fitness() };
+        let strict = Fitness { fairness_penalty: 10.0, ..fitness() };
+
+        assert!(strict.evaluate(&genome) < lenient.evaluate(&genome));
+    }
+}
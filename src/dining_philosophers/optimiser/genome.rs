@@ -0,0 +1,159 @@
+use rand::Rng;
+
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+use crate::dining_philosophers::policy_table_impl::policy_table::{PolicyTable, Row};
+use crate::dining_philosophers::random::SeededRng;
+
+pub const GENES: usize = 5;
+
+/// A policy described by a handful of probabilities, each between 0 and 1, which the optimiser
+/// evolves and turns into a `PolicyTable`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Genome {
+    /// The chance of reaching for the left fork first when both are on the table.
+    pub left_first: f64,
+    /// The chance of sitting out a step while thinking, even with forks on the table.
+    pub backoff: f64,
+    /// The chance of keeping hold of one fork while the other is in use, rather than giving way.
+    pub patience: f64,
+    /// The chance of putting both forks down at once after a meal.
+    pub release_both: f64,
+    /// The chance of putting the left fork down first, when not putting both down at once.
+    pub release_left: f64,
+}
+
+impl Genome {
+    pub fn random(rng: &mut SeededRng) -> Genome {
+        let mut genes = [0.0; GENES];
+        for gene in genes.iter_mut() {
+            *gene = rng.gen::<f64>();
+        }
+        Genome::from_genes(genes)
+    }
+
+    pub fn genes(&self) -> [f64; GENES] {
+        [self.left_first, self.backoff, self.patience, self.release_both, self.release_left]
+    }
+
+    pub fn from_genes(genes: [f64; GENES]) -> Genome {
+        Genome {
+            left_first: genes[0],
+            backoff: genes[1],
+            patience: genes[2],
+            release_both: genes[3],
+            release_left: genes[4],
+        }
+    }
+
+    /// Takes every gene from one parent or the other, with even chances.
+    pub fn crossover(&self, other: &Genome, rng: &mut SeededRng) -> Genome {
+        let mut genes = self.genes();
+        for (gene, theirs) in genes.iter_mut().zip(other.genes().iter()) {
+            if rng.gen_bool(0.5) {
+                *gene = *theirs;
+            }
+        }
+        Genome::from_genes(genes)
+    }
+
+    /// Moves each gene, with the given chance, by up to `size` either way, staying between 0 and 1.
+    /// A size of zero or less leaves every gene where it is.
+    pub fn mutate(&self, rate: f64, size: f64, rng: &mut SeededRng) -> Genome {
+        let mut genes = self.genes();
+        if size <= 0.0 {
+            return Genome::from_genes(genes);
+        }
+        for gene in genes.iter_mut() {
+            if rng.gen_bool(rate) {
+                *gene = (*gene + rng.gen_range(-size, size)).clamp(0.0, 1.0);
+            }
+        }
+        Genome::from_genes(genes)
+    }
+
+    pub fn policy_table(&self) -> PolicyTable {
+        let row = |state: State, forks: Forks, choices: Vec<(Action, f64)>| Row { state, forks, choices };
+        let go = 1.0 - self.backoff;
+        let give_way = 1.0 - self.patience;
+        let one_at_a_time = 1.0 - self.release_both;
+        PolicyTable::from_rows(vec![
+            row(State::Thinking, Forks::Both, vec![(Action::Wait, self.backoff), (Action::TakeLeft, go * self.left_first), (Action::TakeRight, go * (1.0 - self.left_first))]),
+            row(State::Thinking, Forks::Left, vec![(Action::Wait, self.backoff), (Action::TakeLeft, go)]),
+            row(State::Thinking, Forks::Right, vec![(Action::Wait, self.backoff), (Action::TakeRight, go)]),
+            row(State::Thinking, Forks::Neither, vec![(Action::Wait, 1.0)]),
+            row(State::LeftThinking, Forks::Right, vec![(Action::TakeRight, 1.0)]),
+            row(State::LeftThinking, Forks::Neither, vec![(Action::Wait, self.patience), (Action::PutLeft, give_way)]),
+            row(State::RightThinking, Forks::Left, vec![(Action::TakeLeft, 1.0)]),
+            row(State::RightThinking, Forks::Neither, vec![(Action::Wait, self.patience), (Action::PutRight, give_way)]),
+            row(State::Eating, Forks::Neither, vec![(Action::PutBoth, self.release_both), (Action::PutLeft, one_at_a_time * self.release_left), (Action::PutRight, one_at_a_time * (1.0 - self.release_left))]),
+        ]).expect("Every genome describes a valid policy table")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::optimiser::genome::{Genome, GENES};
+    use crate::dining_philosophers::random::SeededRng;
+
+    fn genome(gene: f64) -> Genome {
+        Genome::from_genes([gene; GENES])
+    }
+
+    #[test]
+    fn genes_round_trip() {
+        let unit = Genome::from_genes([0.1, 0.2, 0.3, 0.4, 0.5]);
+
+        assert_eq!(unit.genes(), [0.1, 0.2, 0.3, 0.4, 0.5]);
+        assert_eq!(unit.patience, 0.3);
+    }
+
+    #[test]
+    fn random_genomes_are_reproducible() {
+        assert_eq!(Genome::random(&mut SeededRng::new(1)), Genome::random(&mut SeededRng::new(1)));
+        assert_ne!(Genome::random(&mut SeededRng::new(1)), Genome::random(&mut SeededRng::new(2)));
+    }
+
+    #[test]
+    fn crossover_takes_every_gene_from_a_parent() {
+        let mut rng = SeededRng::new(0);
+
+        let child = genome(0.0).crossover(&genome(1.0), &mut rng);
+
+        assert!(child.genes().iter().all(|gene| *gene == 0.0 || *gene == 1.0));
+        assert!(child.genes().contains(&0.0) && child.genes().contains(&1.0));
+    }
+
+    #[test]
+    fn mutation_stays_between_zero_and_one() {
+        let mut rng = SeededRng::new(0);
+
+        for _ in 0..100 {
+            let low = genome(0.0).mutate(1.0, 0.5, &mut rng);
+            let high = genome(1.0).mutate(1.0, 0.5, &mut rng);
+            assert!(low.genes().iter().chain(high.genes().iter()).all(|gene| (0.0..=1.0).contains(gene)));
+        }
+    }
+
+    #[test]
+    fn no_mutation_at_a_rate_of_zero() {
+        assert_eq!(genome(0.5).mutate(0.0, 0.5, &mut SeededRng::new(0)), genome(0.5));
+    }
+
+    #[test]
+    fn no_mutation_of_size_zero() {
+        assert_eq!(genome(0.5).mutate(1.0, 0.0, &mut SeededRng::new(0)), genome(0.5));
+    }
+
+    #[test]
+    fn every_genome_describes_a_valid_policy_table() {
+        let mut rng = SeededRng::new(0);
+
+        for _ in 0..100 {
+            Genome::random(&mut rng).policy_table();
+        }
+        genome(0.0).policy_table();
+        genome(1.0).policy_table();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod genome;
+pub mod fitness;
+pub mod evolution;
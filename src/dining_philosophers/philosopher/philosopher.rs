@@ -4,12 +4,12 @@ use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine
 
 pub struct Philosopher {
     id: usize,
-    sm: Box<StateMachine + Send>,
+    sm: Box<dyn StateMachine + Send>,
     history: Vec<State>,
 }
 
 impl Philosopher {
-    pub fn new(id: usize, sm: Box<StateMachine + Send>) -> Self {
+    pub fn new(id: usize, sm: Box<dyn StateMachine + Send>) -> Self {
         let mut philosopher = Philosopher {
             id,
             sm,
@@ -34,11 +34,11 @@ impl Philosopher {
         self.sm.state()
     }
 
-    fn history(&self) -> &Vec<State> {
-        return &self.history;
+    pub fn history(&self) -> &Vec<State> {
+        &self.history
     }
 
-    fn id(&self) -> usize {
+    pub fn id(&self) -> usize {
         self.id
     }
 }
@@ -94,7 +94,7 @@ mod tests {
     struct MockStateMachine {}
 
     impl StateMachine for MockStateMachine {
-        fn transition(&mut self) -> Box<StateMachine + Send> {
+        fn transition(&mut self) -> Box<dyn StateMachine + Send> {
             Box::new(MockStateMachine{})
         }
 
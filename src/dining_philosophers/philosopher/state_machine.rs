@@ -1,12 +1,20 @@
 #[derive(Debug, PartialEq, Clone)]
 pub enum State {
     Thinking,
+    /// Holds no fork, but has asked for both and is waiting to be let eat.
+    Hungry,
     LeftThinking,
     RightThinking,
     Eating,
+    /// A drinking philosopher who needs no bottle.
+    Tranquil,
+    /// A drinking philosopher waiting for the bottles of its next session.
+    Thirsty,
+    /// A drinking philosopher holding every bottle of its session.
+    Drinking,
 }
 
 pub trait StateMachine {
-    fn transition(&mut self) -> Box<StateMachine + Send>;
+    fn transition(&mut self) -> Box<dyn StateMachine + Send>;
     fn state(&self) -> State;
 }
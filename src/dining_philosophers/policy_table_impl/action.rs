@@ -0,0 +1,102 @@
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+
+/// What a philosopher driven by a policy table does in one step.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Action {
+    Wait,
+    TakeLeft,
+    TakeRight,
+    TakeBoth,
+    PutLeft,
+    PutRight,
+    PutBoth,
+}
+
+impl Action {
+    pub const ALL: [Action; 7] = [Action::Wait, Action::TakeLeft, Action::TakeRight, Action::TakeBoth, Action::PutLeft, Action::PutRight, Action::PutBoth];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Wait => "wait",
+            Action::TakeLeft => "take-left",
+            Action::TakeRight => "take-right",
+            Action::TakeBoth => "take-both",
+            Action::PutLeft => "put-left",
+            Action::PutRight => "put-right",
+            Action::PutBoth => "put-both",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Action> {
+        Action::ALL.iter().find(|action| action.name() == name).cloned()
+    }
+
+    /// The state the action leads to, or `None` if it is impossible from the given state.
+    pub fn next(&self, state: &State) -> Option<State> {
+        match (self, state) {
+            (Action::Wait, State::Hungry) => None,
+            (Action::Wait, _) => Some(state.clone()),
+            (Action::TakeLeft, State::Thinking) => Some(State::LeftThinking),
+            (Action::TakeLeft, State::RightThinking) => Some(State::Eating),
+            (Action::TakeRight, State::Thinking) => Some(State::RightThinking),
+            (Action::TakeRight, State::LeftThinking) => Some(State::Eating),
+            (Action::TakeBoth, State::Thinking) => Some(State::Eating),
+            (Action::PutLeft, State::LeftThinking) => Some(State::Thinking),
+            (Action::PutLeft, State::Eating) => Some(State::RightThinking),
+            (Action::PutRight, State::RightThinking) => Some(State::Thinking),
+            (Action::PutRight, State::Eating) => Some(State::LeftThinking),
+            (Action::PutBoth, State::Eating) => Some(State::Thinking),
+            _ => None,
+        }
+    }
+
+    /// Whether the forks the action takes are on the table.
+    pub fn finds(&self, forks: Forks) -> bool {
+        match self {
+            Action::TakeLeft => forks.has_left(),
+            Action::TakeRight => forks.has_right(),
+            Action::TakeBoth => forks == Forks::Both,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+
+    #[test]
+    fn parses_its_own_names() {
+        for action in Action::ALL.iter() {
+            assert_eq!(Action::parse(action.name()), Some(*action));
+        }
+        assert_eq!(Action::parse("eat"), None);
+    }
+
+    #[test]
+    fn follows_the_state_diagram() {
+        assert_eq!(Action::TakeLeft.next(&State::Thinking), Some(State::LeftThinking));
+        assert_eq!(Action::TakeRight.next(&State::LeftThinking), Some(State::Eating));
+        assert_eq!(Action::PutRight.next(&State::Eating), Some(State::LeftThinking));
+        assert_eq!(Action::Wait.next(&State::Eating), Some(State::Eating));
+    }
+
+    #[test]
+    fn cannot_take_a_held_fork_or_put_down_a_missing_one() {
+        assert_eq!(Action::TakeLeft.next(&State::LeftThinking), None);
+        assert_eq!(Action::TakeBoth.next(&State::RightThinking), None);
+        assert_eq!(Action::PutLeft.next(&State::Thinking), None);
+        assert_eq!(Action::PutBoth.next(&State::LeftThinking), None);
+    }
+
+    #[test]
+    fn takes_only_forks_on_the_table() {
+        assert!(Action::TakeLeft.finds(Forks::Left));
+        assert!(!Action::TakeLeft.finds(Forks::Right));
+        assert!(!Action::TakeBoth.finds(Forks::Left));
+        assert!(Action::Wait.finds(Forks::Neither));
+    }
+}
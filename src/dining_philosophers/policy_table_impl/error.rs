@@ -0,0 +1,56 @@
+use std::error;
+use std::fmt;
+
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+
+/// Why a policy table was rejected.
+#[derive(Debug, PartialEq)]
+pub enum PolicyTableError {
+    /// The file could not be read.
+    Io(String),
+    /// A line could not be parsed.
+    Syntax { line: usize, message: String },
+    /// Two rows for the same state and forks.
+    Duplicate { state: State, forks: Forks },
+    /// A row for forks the philosopher can never find in that state.
+    Unreachable { state: State, forks: Forks },
+    /// An action that cannot be taken in that state with those forks.
+    ImpossibleAction { state: State, forks: Forks, action: Action },
+    /// Probabilities that do not add up to one.
+    Probabilities { state: State, forks: Forks, total: f64 },
+    /// No row for a state and forks the philosopher can find itself in.
+    Missing { state: State, forks: Forks },
+}
+
+impl fmt::Display for PolicyTableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PolicyTableError::Io(message) => write!(f, "cannot read policy table: {}", message),
+            PolicyTableError::Syntax { line, message } => write!(f, "line {}: {}", line, message),
+            PolicyTableError::Duplicate { state, forks } => write!(f, "{:?} finding {} appears twice", state, forks.name()),
+            PolicyTableError::Unreachable { state, forks } => write!(f, "{:?} never finds {}", state, forks.name()),
+            PolicyTableError::ImpossibleAction { state, forks, action } => write!(f, "{:?} finding {} cannot {}", state, forks.name(), action.name()),
+            PolicyTableError::Probabilities { state, forks, total } => write!(f, "{:?} finding {} has probabilities adding up to {}", state, forks.name(), total),
+            PolicyTableError::Missing { state, forks } => write!(f, "no row for {:?} finding {}", state, forks.name()),
+        }
+    }
+}
+
+impl error::Error for PolicyTableError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::error::PolicyTableError;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+
+    #[test]
+    fn describes_the_offending_row() {
+        let unit = PolicyTableError::ImpossibleAction { state: State::Eating, forks: Forks::Neither, action: Action::TakeLeft };
+
+        assert_eq!(unit.to_string(), "Eating finding neither cannot take-left");
+    }
+}
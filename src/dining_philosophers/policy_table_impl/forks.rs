@@ -0,0 +1,94 @@
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Which of a philosopher's two forks are on the table when it acts.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Forks {
+    Both,
+    Left,
+    Right,
+    Neither,
+}
+
+impl Forks {
+    pub const ALL: [Forks; 4] = [Forks::Both, Forks::Left, Forks::Right, Forks::Neither];
+
+    pub fn observe(table_interaction: &TableInteraction) -> Forks {
+        match (table_interaction.left_fork_on_table(), table_interaction.right_fork_on_table()) {
+            (true, true) => Forks::Both,
+            (true, false) => Forks::Left,
+            (false, true) => Forks::Right,
+            (false, false) => Forks::Neither,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Forks::Both => "both",
+            Forks::Left => "left",
+            Forks::Right => "right",
+            Forks::Neither => "neither",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Forks> {
+        Forks::ALL.iter().find(|forks| forks.name() == name).cloned()
+    }
+
+    pub fn has_left(&self) -> bool {
+        *self == Forks::Both || *self == Forks::Left
+    }
+
+    pub fn has_right(&self) -> bool {
+        *self == Forks::Both || *self == Forks::Right
+    }
+
+    /// Whether a philosopher in the given state can find these forks on the table. It never
+    /// finds a fork it is holding.
+    pub fn possible_in(&self, state: &State) -> bool {
+        match state {
+            State::Thinking => true,
+            State::LeftThinking => !self.has_left(),
+            State::RightThinking => !self.has_right(),
+            State::Eating => *self == Forks::Neither,
+            State::Hungry | State::Tranquil | State::Thirsty | State::Drinking => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+    use crate::dining_philosophers::table::Table;
+
+    #[test]
+    fn observes_the_forks_on_the_table() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let right = table_interactions.pop().unwrap();
+        let unit = table_interactions.pop().unwrap();
+
+        assert_eq!(Forks::observe(&unit), Forks::Both);
+        let _fork = right.get_left_fork();
+        assert_eq!(Forks::observe(&unit), Forks::Left);
+        let _fork = unit.get_left_fork();
+        assert_eq!(Forks::observe(&unit), Forks::Neither);
+    }
+
+    #[test]
+    fn parses_its_own_names() {
+        for forks in Forks::ALL.iter() {
+            assert_eq!(Forks::parse(forks.name()), Some(*forks));
+        }
+        assert_eq!(Forks::parse("some"), None);
+    }
+
+    #[test]
+    fn never_finds_a_fork_it_holds() {
+        assert!(Forks::Right.possible_in(&State::LeftThinking));
+        assert!(!Forks::Both.possible_in(&State::LeftThinking));
+        assert!(!Forks::Right.possible_in(&State::RightThinking));
+        assert!(!Forks::Left.possible_in(&State::Eating));
+        assert!(Forks::ALL.iter().all(|forks| forks.possible_in(&State::Thinking)));
+    }
+}
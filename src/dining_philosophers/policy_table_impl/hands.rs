@@ -0,0 +1,109 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// The forks a philosopher is holding, which it takes and puts down one action at a time.
+#[derive(Debug, PartialEq, Default)]
+pub struct Hands {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+}
+
+impl Hands {
+    /// Carries out the action, returning whether it went through. Taking a fork that is no
+    /// longer on the table does not, and neither does putting down a fork the table refuses,
+    /// which stays in hand.
+    ///
+    /// # Panics
+    ///
+    /// Panics when putting down a fork that is not held.
+    pub fn act(&mut self, action: Action, t: &TableInteraction) -> bool {
+        match action {
+            Action::Wait => true,
+            Action::TakeLeft => {
+                self.left_fork = t.get_left_fork();
+                self.left_fork.is_some()
+            }
+            Action::TakeRight => {
+                self.right_fork = t.get_right_fork();
+                self.right_fork.is_some()
+            }
+            Action::TakeBoth => match t.get_both_forks() {
+                Some((left_fork, right_fork)) => {
+                    self.left_fork = Some(left_fork);
+                    self.right_fork = Some(right_fork);
+                    true
+                }
+                None => false,
+            },
+            Action::PutLeft => {
+                self.left_fork = keep_refused(t.position, t.return_left_fork(self.left_fork.take().unwrap()));
+                self.left_fork.is_none()
+            }
+            Action::PutRight => {
+                self.right_fork = keep_refused(t.position, t.return_right_fork(self.right_fork.take().unwrap()));
+                self.right_fork.is_none()
+            }
+            Action::PutBoth => {
+                self.right_fork = keep_refused(t.position, t.return_right_fork(self.right_fork.take().unwrap()));
+                self.left_fork = keep_refused(t.position, t.return_left_fork(self.left_fork.take().unwrap()));
+                self.left_fork.is_none() && self.right_fork.is_none()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::fork::Fork;
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::hands::Hands;
+    use crate::dining_philosophers::table::Table;
+
+    #[test]
+    fn takes_and_puts_down_forks() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = table_interactions.pop().unwrap();
+        let t = table_interactions.pop().unwrap();
+        let mut unit = Hands::default();
+
+        assert!(unit.act(Action::TakeBoth, &t));
+        assert_eq!(neighbour.get_left_fork(), None);
+        assert!(unit.act(Action::PutRight, &t));
+        assert!(unit.act(Action::PutLeft, &t));
+        assert_eq!(unit, Hands::default());
+        assert!(neighbour.get_both_forks().is_some());
+    }
+
+    #[test]
+    fn fails_to_take_a_fork_in_use() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = table_interactions.pop().unwrap();
+        let t = table_interactions.pop().unwrap();
+        let _fork = neighbour.get_left_fork();
+        let mut unit = Hands::default();
+
+        assert!(!unit.act(Action::TakeRight, &t));
+        assert!(!unit.act(Action::TakeBoth, &t));
+        assert!(unit.act(Action::TakeLeft, &t));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_put_down_a_fork_it_does_not_hold() {
+        let t = Table::new(2).get_interactions().remove(0);
+
+        Hands::default().act(Action::PutLeft, &t);
+    }
+
+    #[test]
+    fn keeps_a_fork_the_table_refuses() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let t = table_interactions.remove(0);
+        let _fork = t.get_left_fork();
+        let mut unit = Hands { left_fork: Some(Fork::new(0)), right_fork: None };
+
+        assert!(!unit.act(Action::PutLeft, &t));
+        assert_eq!(unit.left_fork, Some(Fork::new(0)));
+    }
+}
@@ -0,0 +1,133 @@
+use std::mem;
+use std::sync::Arc;
+
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+use crate::dining_philosophers::policy_table_impl::hands::Hands;
+use crate::dining_philosophers::policy_table_impl::policy_table::PolicyTable;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// One state machine for every state, which looks up its next action in a policy table.
+///
+/// A fork that was on the table when the philosopher looked may be gone by the time it
+/// reaches for it; it then stays where it is.
+#[derive(Debug, PartialEq)]
+pub struct Interpreter {
+    state: State,
+    hands: Hands,
+    table_interaction: Option<TableInteraction>,
+    policy: Arc<PolicyTable>,
+    rng: SeededRng,
+}
+
+impl Interpreter {
+    pub fn new(table_interaction: TableInteraction, policy: Arc<PolicyTable>, rng: SeededRng) -> Interpreter {
+        Interpreter {
+            state: State::Thinking,
+            hands: Hands::default(),
+            table_interaction: Some(table_interaction),
+            policy,
+            rng,
+        }
+    }
+}
+
+impl StateMachine for Interpreter {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.table_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(t) => {
+                let forks = Forks::observe(&t);
+                let action = self.policy.choose(&self.state, forks, &mut self.rng);
+                let state = match self.hands.act(action, &t) {
+                    true => action.next(&self.state).unwrap(),
+                    false => self.state.clone(),
+                };
+                debug!("{}: {:?} found {} forks, {} to {:?}", t.position, self.state, forks.name(), action.name(), state);
+                Box::new(Interpreter {
+                    state,
+                    hands: mem::take(&mut self.hands),
+                    table_interaction: Some(t),
+                    policy: Arc::clone(&self.policy),
+                    rng: self.rng.clone(),
+                })
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        self.state.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::policy_table_impl::interpreter::Interpreter;
+    use crate::dining_philosophers::policy_table_impl::policy_table::PolicyTable;
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+
+    fn give_way() -> Arc<PolicyTable> {
+        Arc::new(include_str!("../../../policies/give-way.policy").parse().unwrap())
+    }
+
+    fn interpreter(table_interaction: TableInteraction) -> Box<dyn StateMachine> {
+        Box::new(Interpreter::new(table_interaction, give_way(), SeededRng::new(0)))
+    }
+
+    #[test]
+    fn starts_thinking() {
+        assert_eq!(interpreter(Table::new(2).get_interactions().remove(0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn follows_the_table_to_eating_and_back() {
+        let mut unit = interpreter(Table::new(3).get_interactions().remove(0));
+
+        let states: Vec<State> = (0..4).map(|_| {
+            unit = unit.transition();
+            unit.state()
+        }).collect();
+
+        assert_eq!(states, vec![State::LeftThinking, State::Eating, State::Thinking, State::LeftThinking]);
+    }
+
+    #[test]
+    fn gives_way_when_the_right_fork_is_taken() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let neighbour = table_interactions.remove(1);
+        let mut unit = interpreter(table_interactions.remove(0));
+        unit = unit.transition();
+        let _fork = neighbour.get_left_fork();
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert!(neighbour.get_right_fork().is_some());
+    }
+
+    #[test]
+    fn stays_put_when_it_cannot_take_a_fork_it_found() {
+        let text = include_str!("../../../policies/give-way.policy").replace("thinking        both     take-left:1", "thinking both take-both:1");
+        let policy: Arc<PolicyTable> = Arc::new(text.parse().unwrap());
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Interpreter::new(table_interaction, policy, SeededRng::new(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Interpreter::new(Table::new(2).get_interactions().remove(0), give_way(), SeededRng::new(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
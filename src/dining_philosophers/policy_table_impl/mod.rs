@@ -0,0 +1,7 @@
+pub mod forks;
+pub mod action;
+pub mod error;
+pub mod policy_table;
+pub mod hands;
+pub mod interpreter;
+pub mod scripted;
@@ -0,0 +1,315 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use rand::Rng;
+
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::error::PolicyTableError;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+use crate::dining_philosophers::random::SeededRng;
+
+/// The states a policy table covers, with their names in the text form.
+pub const STATES: [(&str, State); 4] = [
+    ("thinking", State::Thinking),
+    ("left-thinking", State::LeftThinking),
+    ("right-thinking", State::RightThinking),
+    ("eating", State::Eating),
+];
+
+pub fn state_name(state: &State) -> Option<&'static str> {
+    STATES.iter().find(|(_, s)| s == state).map(|(name, _)| *name)
+}
+
+pub fn parse_state(name: &str) -> Option<State> {
+    STATES.iter().find(|(n, _)| *n == name).map(|(_, state)| state.clone())
+}
+
+/// How far the probabilities of a row may add up from one.
+const TOLERANCE: f64 = 1e-6;
+
+/// The chances of every next action for one state and the forks found on the table.
+#[derive(Debug, PartialEq)]
+pub struct Row {
+    pub state: State,
+    pub forks: Forks,
+    pub choices: Vec<(Action, f64)>,
+}
+
+/// A strategy written as data: for every state and the forks on the table, a probability
+/// distribution over the next action.
+///
+/// The text form has one row per line, naming the state, the forks on the table and the
+/// actions with their probabilities. Anything after a `#` is a comment:
+///
+/// ```text
+/// # state        forks    actions
+/// thinking       both     take-left:0.5 take-right:0.5
+/// left-thinking  neither  put-left:0.2 wait:0.8
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct PolicyTable {
+    rows: Vec<Row>,
+}
+
+impl PolicyTable {
+    /// Reads, parses and validates a policy table file.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<PolicyTable, PolicyTableError> {
+        fs::read_to_string(path)
+            .map_err(|e| PolicyTableError::Io(e.to_string()))?
+            .parse()
+    }
+
+    /// Builds and validates a policy table from its rows.
+    pub fn from_rows(rows: Vec<Row>) -> Result<PolicyTable, PolicyTableError> {
+        let table = PolicyTable { rows };
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Parses the rows of a policy table without validating them.
+    pub fn parse(text: &str) -> Result<PolicyTable, PolicyTableError> {
+        let mut rows = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if !line.is_empty() {
+                rows.push(parse_row(line).map_err(|message| PolicyTableError::Syntax { line: index + 1, message })?);
+            }
+        }
+        Ok(PolicyTable { rows })
+    }
+
+    /// Rejects tables that could send a philosopher through an impossible transition, or leave
+    /// it without a next action.
+    pub fn validate(&self) -> Result<(), PolicyTableError> {
+        for (index, row) in self.rows.iter().enumerate() {
+            let (state, forks) = (row.state.clone(), row.forks);
+            if self.rows[..index].iter().any(|r| r.state == row.state && r.forks == row.forks) {
+                return Err(PolicyTableError::Duplicate { state, forks });
+            }
+            if !forks.possible_in(&state) {
+                return Err(PolicyTableError::Unreachable { state, forks });
+            }
+            if let Some(&(action, _)) = row.choices.iter().find(|(a, _)| a.next(&state).is_none() || !a.finds(forks)) {
+                return Err(PolicyTableError::ImpossibleAction { state, forks, action });
+            }
+            let total: f64 = row.choices.iter().map(|(_, p)| p).sum();
+            if (total - 1.0).abs() > TOLERANCE {
+                return Err(PolicyTableError::Probabilities { state, forks, total });
+            }
+        }
+        for (_, state) in STATES.iter() {
+            for forks in Forks::ALL.iter().filter(|forks| forks.possible_in(state)) {
+                if self.row(state, *forks).is_none() {
+                    return Err(PolicyTableError::Missing { state: state.clone(), forks: *forks });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    fn row(&self, state: &State, forks: Forks) -> Option<&Row> {
+        self.rows.iter().find(|row| row.state == *state && row.forks == forks)
+    }
+
+    /// Draws the next action for a philosopher in the given state who found the given forks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table has no row for them, which a validated table always has.
+    pub fn choose(&self, state: &State, forks: Forks, rng: &mut SeededRng) -> Action {
+        let row = self.row(state, forks).expect("The policy table has no row for this state");
+        let mut draw: f64 = rng.gen::<f64>();
+        for &(action, probability) in &row.choices {
+            if draw < probability {
+                return action;
+            }
+            draw -= probability;
+        }
+        row.choices.iter().rev().find(|(_, p)| *p > 0.0).unwrap().0
+    }
+}
+
+impl FromStr for PolicyTable {
+    type Err = PolicyTableError;
+
+    /// Parses and validates a policy table.
+    fn from_str(text: &str) -> Result<PolicyTable, PolicyTableError> {
+        let table = PolicyTable::parse(text)?;
+        table.validate()?;
+        Ok(table)
+    }
+}
+
+/// Writes the table in the form it is parsed from.
+impl fmt::Display for PolicyTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in &self.rows {
+            write!(f, "{:<15} {:<8}", state_name(&row.state).unwrap(), row.forks.name())?;
+            for (action, probability) in &row.choices {
+                write!(f, " {}:{}", action.name(), probability)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_row(line: &str) -> Result<Row, String> {
+    let mut words = line.split_whitespace();
+    let state_name = words.next().unwrap();
+    let state = parse_state(state_name).ok_or_else(|| format!("unknown state {}", state_name))?;
+    let forks_name = words.next().ok_or("missing forks")?;
+    let forks = Forks::parse(forks_name).ok_or_else(|| format!("unknown forks {}", forks_name))?;
+    let choices = words.map(parse_choice).collect::<Result<Vec<_>, _>>()?;
+    if choices.is_empty() {
+        return Err("missing actions".to_string());
+    }
+    Ok(Row { state, forks, choices })
+}
+
+fn parse_choice(word: &str) -> Result<(Action, f64), String> {
+    let mut parts = word.splitn(2, ':');
+    let action_name = parts.next().unwrap();
+    let action = Action::parse(action_name).ok_or_else(|| format!("unknown action {}", action_name))?;
+    let probability: f64 = parts.next()
+        .ok_or_else(|| format!("missing probability for {}", action_name))?
+        .parse()
+        .map_err(|_| format!("bad probability for {}", action_name))?;
+    if !(0.0..=1.0).contains(&probability) {
+        return Err(format!("probability for {} is not between 0 and 1", action_name));
+    }
+    Ok((action, probability))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::error::PolicyTableError;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+    use crate::dining_philosophers::policy_table_impl::policy_table::{PolicyTable, Row};
+    use crate::dining_philosophers::random::SeededRng;
+
+    const GIVE_WAY: &str = include_str!("../../../policies/give-way.policy");
+
+    fn replacing(row: &str, with: &str) -> String {
+        GIVE_WAY.lines()
+            .map(|line| if line.starts_with(row) { with } else { line })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn accepts_the_example_table() {
+        let unit: PolicyTable = GIVE_WAY.parse().unwrap();
+
+        assert_eq!(unit.rows().len(), 9);
+        assert_eq!(unit.rows()[0].choices, vec![(Action::TakeLeft, 1.0)]);
+    }
+
+    #[test]
+    fn reports_the_line_of_a_syntax_error() {
+        let text = "# comment\nthinking both take-left:1\nthinking some wait:1";
+
+        assert_eq!(PolicyTable::parse(text), Err(PolicyTableError::Syntax { line: 3, message: "unknown forks some".to_string() }));
+    }
+
+    #[test]
+    fn rejects_a_probability_outside_zero_and_one() {
+        assert!(matches!(PolicyTable::parse("thinking both take-left:1.5"), Err(PolicyTableError::Syntax { line: 1, .. })));
+        assert!(matches!(PolicyTable::parse("thinking both take-left"), Err(PolicyTableError::Syntax { line: 1, .. })));
+    }
+
+    #[test]
+    fn rejects_an_impossible_transition() {
+        let text = replacing("eating", "eating neither put-both:0.5 take-left:0.5");
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::ImpossibleAction { state: State::Eating, forks: Forks::Neither, action: Action::TakeLeft }));
+    }
+
+    #[test]
+    fn rejects_taking_a_fork_that_is_not_on_the_table() {
+        let text = replacing("thinking        right", "thinking right take-left:1");
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::ImpossibleAction { state: State::Thinking, forks: Forks::Right, action: Action::TakeLeft }));
+    }
+
+    #[test]
+    fn rejects_forks_that_cannot_be_found() {
+        let text = format!("{}\nleft-thinking both wait:1", GIVE_WAY);
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::Unreachable { state: State::LeftThinking, forks: Forks::Both }));
+    }
+
+    #[test]
+    fn rejects_probabilities_not_adding_up_to_one() {
+        let text = replacing("eating", "eating neither put-both:0.5 wait:0.25");
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::Probabilities { state: State::Eating, forks: Forks::Neither, total: 0.75 }));
+    }
+
+    #[test]
+    fn rejects_a_duplicate_row() {
+        let text = format!("{}\neating neither wait:1", GIVE_WAY);
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::Duplicate { state: State::Eating, forks: Forks::Neither }));
+    }
+
+    #[test]
+    fn rejects_a_missing_row() {
+        let text = replacing("eating", "");
+
+        assert_eq!(text.parse::<PolicyTable>(), Err(PolicyTableError::Missing { state: State::Eating, forks: Forks::Neither }));
+    }
+
+    #[test]
+    fn writes_a_table_it_can_read_back() {
+        let unit: PolicyTable = GIVE_WAY.parse().unwrap();
+
+        let written = unit.to_string();
+
+        assert!(written.starts_with("thinking        both     take-left:1\n"));
+        assert_eq!(written.parse::<PolicyTable>(), Ok(unit));
+    }
+
+    #[test]
+    fn from_rows_validates_them() {
+        let rows = vec![Row { state: State::Eating, forks: Forks::Neither, choices: vec![(Action::PutBoth, 1.0)] }];
+
+        assert_eq!(PolicyTable::from_rows(rows), Err(PolicyTableError::Missing { state: State::Thinking, forks: Forks::Both }));
+    }
+
+    #[test]
+    fn load_reports_a_missing_file() {
+        assert!(matches!(PolicyTable::load("no/such/file.policy"), Err(PolicyTableError::Io(_))));
+    }
+
+    #[test]
+    fn chooses_actions_in_proportion() {
+        let text = replacing("eating", "eating neither put-both:0.25 wait:0.75");
+        let unit: PolicyTable = text.parse().unwrap();
+        let mut rng = SeededRng::new(0);
+
+        let waits = (0..1000)
+            .filter(|_| unit.choose(&State::Eating, Forks::Neither, &mut rng) == Action::Wait)
+            .count();
+
+        assert!(waits > 700 && waits < 800, "{}", waits);
+    }
+
+    #[test]
+    fn never_chooses_an_action_without_a_chance() {
+        let text = replacing("eating", "eating neither wait:0 put-both:1");
+        let unit: PolicyTable = text.parse().unwrap();
+        let mut rng = SeededRng::new(0);
+
+        assert!((0..100).all(|_| unit.choose(&State::Eating, Forks::Neither, &mut rng) == Action::PutBoth));
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::policy_table_impl::interpreter::Interpreter;
+use crate::dining_philosophers::policy_table_impl::policy_table::PolicyTable;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Seats philosophers who follow a policy table.
+///
+/// Each seat draws its actions from its own generator, seeded from `seed` and its position.
+pub struct Scripted {
+    policy: Arc<PolicyTable>,
+    seed: u64,
+}
+
+impl Scripted {
+    pub fn new(policy: Arc<PolicyTable>, seed: u64) -> Scripted {
+        Scripted {
+            policy,
+            seed,
+        }
+    }
+}
+
+impl Strategy for Scripted {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let rng = SeededRng::new(self.seed.wrapping_add(table_interaction.position as u64));
+        Box::new(Interpreter::new(table_interaction, Arc::clone(&self.policy), rng))
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::policy_table::PolicyTable;
+    use crate::dining_philosophers::policy_table_impl::scripted::Scripted;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+
+    const COIN: &str = "
+        thinking        both     take-left:0.5 take-right:0.5
+        thinking        left     take-left:0.5 wait:0.5
+        thinking        right    take-right:0.5 wait:0.5
+        thinking        neither  wait:1
+        left-thinking   right    take-right:1
+        left-thinking   neither  put-left:0.5 wait:0.5
+        right-thinking  left     take-left:1
+        right-thinking  neither  put-right:0.5 wait:0.5
+        eating          neither  put-both:0.5 put-left:0.25 put-right:0.25
+    ";
+
+    fn coin() -> Arc<PolicyTable> {
+        Arc::new(COIN.parse().unwrap())
+    }
+
+    #[test]
+    fn everyone_eats() {
+        for size in 2..=16 {
+            let results = run_in_lockstep(&mut Scripted::new(coin(), 0), size, 10 * size);
+
+            for (id, history) in &results {
+                assert!(history.contains(&State::Eating), "philosopher {} of {} never ate", id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let results = run_in_lockstep(&mut Scripted::new(coin(), 0), 5, 200);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn same_seed_gives_the_same_run() {
+        let run = |seed| run_in_lockstep(&mut Scripted::new(coin(), seed), 5, 50);
+
+        assert_eq!(run(3), run(3));
+        assert_ne!(run(3), run(4));
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::preemption_impl::ledger::Ledger;
+use crate::dining_philosophers::preemption_impl::scheme::{Conflict, Scheme};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// A philosopher's place at a table run under a timestamp scheme.
+#[derive(Debug)]
+pub struct Contender {
+    pub table_interaction: TableInteraction,
+    pub scheme: Scheme,
+    ledger: Arc<Mutex<Ledger>>,
+}
+
+impl PartialEq for Contender {
+    fn eq(&self, other: &Contender) -> bool {
+        (&self.table_interaction, self.scheme) == (&other.table_interaction, other.scheme)
+    }
+}
+
+impl Contender {
+    pub fn new(table_interaction: TableInteraction, scheme: Scheme, ledger: Arc<Mutex<Ledger>>) -> Contender {
+        Contender {
+            table_interaction,
+            scheme,
+            ledger,
+        }
+    }
+
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    /// The neighbour who shares the fork on the given side.
+    fn neighbour(&self, side: Side) -> usize {
+        let neighbours = self.table_interaction.neighbours();
+        match side {
+            Side::Left => neighbours.left.position,
+            Side::Right => neighbours.right.position,
+        }
+    }
+
+    pub fn hungry(&self) {
+        self.ledger.lock().unwrap().hungry(self.position());
+    }
+
+    /// Whether the philosopher has become hungry and not eaten since.
+    pub fn is_hungry(&self) -> bool {
+        self.ledger.lock().unwrap().timestamp(self.position()).is_some()
+    }
+
+    pub fn take(&self, side: Side) -> Option<Fork> {
+        match side {
+            Side::Left => self.table_interaction.get_left_fork(),
+            Side::Right => self.table_interaction.get_right_fork(),
+        }
+    }
+
+    /// Puts the fork back, or hands it back with the reason the table refused it.
+    pub fn put_down(&self, side: Side, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        match side {
+            Side::Left => self.table_interaction.return_left_fork(fork),
+            Side::Right => self.table_interaction.return_right_fork(fork),
+        }
+    }
+
+    /// Whether an older neighbour has wounded this philosopher and claimed the fork on the given side.
+    pub fn claimed(&self, side: Side) -> bool {
+        match side {
+            Side::Left => self.table_interaction.left_fork_claimed(),
+            Side::Right => self.table_interaction.right_fork_claimed(),
+        }
+    }
+
+    /// Settles the conflict with the neighbour holding the fork on the given side, claiming the
+    /// fork if the scheme lets this philosopher wound the neighbour.
+    pub fn contend(&self, side: Side) -> Conflict {
+        let neighbour = self.neighbour(side);
+        let conflict = {
+            let mut ledger = self.ledger.lock().unwrap();
+            let ours = ledger.hungry(self.position());
+            self.scheme.resolve(ours, ledger.timestamp(neighbour), ledger.is_eating(neighbour))
+        };
+        if conflict == Conflict::Wound {
+            let claimed = match side {
+                Side::Left => self.table_interaction.preempt_left_fork(),
+                Side::Right => self.table_interaction.preempt_right_fork(),
+            };
+            if claimed {
+                self.ledger.lock().unwrap().preempted();
+            }
+        }
+        conflict
+    }
+
+    pub fn abort(&self) {
+        self.ledger.lock().unwrap().aborted();
+    }
+
+    pub fn start_eating(&self) {
+        self.ledger.lock().unwrap().start_eating(self.position());
+    }
+
+    pub fn ate(&self) {
+        self.ledger.lock().unwrap().ate(self.position());
+    }
+}
+
+/// Every seat of a table of the given size, sharing one ledger.
+#[cfg(test)]
+pub fn contenders(size: usize, scheme: Scheme) -> (Vec<Contender>, Arc<Mutex<Ledger>>) {
+    use crate::dining_philosophers::table::Table;
+
+    let ledger = Arc::new(Mutex::new(Ledger::new(size)));
+    let contenders = Table::new(size).get_interactions().into_iter()
+        .map(|t| Contender::new(t, scheme, Arc::clone(&ledger)))
+        .collect();
+    (contenders, ledger)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::preemption_impl::contender::contenders;
+    use crate::dining_philosophers::preemption_impl::scheme::{Conflict, Scheme};
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+    #[test]
+    fn older_philosopher_wounds_a_younger_holder() {
+        let (contenders, ledger) = contenders(3, Scheme::WoundWait);
+        contenders[0].hungry();
+        contenders[1].hungry();
+        let _fork = contenders[1].take(Side::Left);
+
+        assert_eq!(contenders[0].contend(Side::Right), Conflict::Wound);
+        assert!(contenders[1].claimed(Side::Left));
+        assert_eq!(ledger.lock().unwrap().preemptions(), 1);
+    }
+
+    #[test]
+    fn younger_philosopher_waits_under_wound_wait() {
+        let (contenders, ledger) = contenders(3, Scheme::WoundWait);
+        contenders[1].hungry();
+        contenders[0].hungry();
+        let _fork = contenders[1].take(Side::Left);
+
+        assert_eq!(contenders[0].contend(Side::Right), Conflict::Wait);
+        assert!(!contenders[1].claimed(Side::Left));
+        assert_eq!(ledger.lock().unwrap().preemptions(), 0);
+    }
+
+    #[test]
+    fn younger_philosopher_dies_under_wait_die() {
+        let (contenders, ledger) = contenders(3, Scheme::WaitDie);
+        contenders[1].hungry();
+        contenders[0].hungry();
+        let _fork = contenders[1].take(Side::Left);
+
+        assert_eq!(contenders[0].contend(Side::Right), Conflict::Die);
+        contenders[0].abort();
+        assert_eq!(ledger.lock().unwrap().aborts(), 1);
+    }
+
+    #[test]
+    fn eating_holder_is_not_wounded() {
+        let (contenders, _) = contenders(3, Scheme::WoundWait);
+        contenders[0].hungry();
+        contenders[1].hungry();
+        let _fork = contenders[1].take(Side::Left);
+        contenders[1].start_eating();
+
+        assert_eq!(contenders[0].contend(Side::Right), Conflict::Wait);
+    }
+}
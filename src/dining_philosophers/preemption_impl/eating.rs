@@ -0,0 +1,95 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::preemption_impl::contender::Contender;
+use crate::dining_philosophers::preemption_impl::thinking::Thinking;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+    contender: Option<Contender>,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, contender: Contender) -> Eating {
+        Eating {
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+            contender: Some(contender),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.contender.take() {
+            None => { panic!("No longer valid") }
+            Some(c) => {
+                debug!("{}: Drop both, to thinking", c.position());
+                let right_fork = self.right_fork.take().and_then(|fork| keep_refused(c.position(), c.put_down(Side::Right, fork)));
+                let left_fork = self.left_fork.take().and_then(|fork| keep_refused(c.position(), c.put_down(Side::Left, fork)));
+                if left_fork.is_some() || right_fork.is_some() {
+                    return Box::new(Eating { left_fork, right_fork, contender: Some(c) });
+                }
+                c.ate();
+                Box::new(Thinking::new(c))
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::preemption_impl::contender::Contender;
+    use crate::dining_philosophers::preemption_impl::eating::Eating;
+    use crate::dining_philosophers::preemption_impl::ledger::Ledger;
+    use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+    use crate::dining_philosophers::table::Table;
+
+    fn eating(ledger: &Arc<Mutex<Ledger>>) -> Eating {
+        let contender = Contender::new(Table::new(2).get_interactions().remove(0), Scheme::WoundWait, Arc::clone(ledger));
+        contender.hungry();
+        contender.start_eating();
+        let left = contender.take(Side::Left).unwrap();
+        let right = contender.take(Side::Right).unwrap();
+        Eating::new(left, right, contender)
+    }
+
+    #[test]
+    fn state_is_eating() {
+        let ledger = Arc::new(Mutex::new(Ledger::new(2)));
+
+        assert_eq!(eating(&ledger).state(), State::Eating);
+    }
+
+    #[test]
+    fn finishing_clears_the_timestamp() {
+        let ledger = Arc::new(Mutex::new(Ledger::new(2)));
+        let mut unit: Box<dyn StateMachine> = Box::new(eating(&ledger));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(ledger.lock().unwrap().timestamp(0), None);
+        assert!(!ledger.lock().unwrap().is_eating(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let ledger = Arc::new(Mutex::new(Ledger::new(2)));
+        let mut unit = eating(&ledger);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,103 @@
+/// The timestamps of one table, with the preemptions and aborts they caused.
+#[derive(Debug, PartialEq)]
+pub struct Ledger {
+    /// When each philosopher became hungry, kept until it has eaten.
+    timestamps: Vec<Option<u64>>,
+    eating: Vec<bool>,
+    clock: u64,
+    preemptions: usize,
+    aborts: usize,
+}
+
+impl Ledger {
+    pub fn new(number_of_philosophers: usize) -> Ledger {
+        Ledger {
+            timestamps: vec![None; number_of_philosophers],
+            eating: vec![false; number_of_philosophers],
+            clock: 0,
+            preemptions: 0,
+            aborts: 0,
+        }
+    }
+
+    /// The philosopher's timestamp, handing out the next one if it was not hungry yet.
+    pub fn hungry(&mut self, position: usize) -> u64 {
+        let clock = &mut self.clock;
+        *self.timestamps[position].get_or_insert_with(|| {
+            *clock += 1;
+            *clock
+        })
+    }
+
+    pub fn timestamp(&self, position: usize) -> Option<u64> {
+        self.timestamps[position]
+    }
+
+    pub fn is_eating(&self, position: usize) -> bool {
+        self.eating[position]
+    }
+
+    pub fn start_eating(&mut self, position: usize) {
+        self.eating[position] = true;
+    }
+
+    pub fn ate(&mut self, position: usize) {
+        self.eating[position] = false;
+        self.timestamps[position] = None;
+    }
+
+    pub fn preempted(&mut self) {
+        self.preemptions += 1;
+    }
+
+    pub fn aborted(&mut self) {
+        self.aborts += 1;
+    }
+
+    pub fn preemptions(&self) -> usize {
+        self.preemptions
+    }
+
+    pub fn aborts(&self) -> usize {
+        self.aborts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::preemption_impl::ledger::Ledger;
+
+    #[test]
+    fn hands_out_increasing_timestamps() {
+        let mut unit = Ledger::new(3);
+
+        assert_eq!(unit.hungry(2), 1);
+        assert_eq!(unit.hungry(0), 2);
+    }
+
+    #[test]
+    fn keeps_the_timestamp_until_the_philosopher_has_eaten() {
+        let mut unit = Ledger::new(2);
+        unit.hungry(0);
+        unit.hungry(1);
+
+        assert_eq!(unit.hungry(0), 1);
+
+        unit.ate(0);
+
+        assert_eq!(unit.timestamp(0), None);
+        assert_eq!(unit.hungry(0), 3);
+    }
+
+    #[test]
+    fn knows_who_is_eating() {
+        let mut unit = Ledger::new(2);
+        unit.start_eating(1);
+
+        assert!(unit.is_eating(1));
+
+        unit.ate(1);
+
+        assert!(!unit.is_eating(1));
+    }
+}
@@ -0,0 +1,160 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::preemption_impl::contender::Contender;
+use crate::dining_philosophers::preemption_impl::eating::Eating;
+use crate::dining_philosophers::preemption_impl::scheme::Conflict;
+use crate::dining_philosophers::preemption_impl::thinking::Thinking;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+/// Holding the left fork and reaching for the right one, which is where conflicts are settled.
+#[derive(Debug, PartialEq)]
+pub struct LeftThinking {
+    left_fork: Option<Fork>,
+    contender: Option<Contender>,
+}
+
+impl LeftThinking {
+    pub fn new(left_fork: Fork, contender: Contender) -> LeftThinking {
+        LeftThinking {
+            left_fork: Some(left_fork),
+            contender: Some(contender),
+        }
+    }
+
+    /// Back to thinking, unless the table refuses the left fork and the philosopher keeps it.
+    fn drop_left(&mut self, contender: Contender) -> Box<dyn StateMachine + Send> {
+        let refused = contender.put_down(Side::Left, self.left_fork.take().unwrap());
+        match keep_refused(contender.position(), refused) {
+            Some(fork) => Box::new(LeftThinking::new(fork, contender)),
+            None => Box::new(Thinking::new(contender)),
+        }
+    }
+}
+
+impl StateMachine for LeftThinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.contender.take() {
+            None => { panic!("No longer valid") }
+            Some(c) => {
+                if c.claimed(Side::Left) {
+                    debug!("{}: Wounded, left fork preempted, back to thinking", c.position());
+                    return self.drop_left(c);
+                }
+                match c.take(Side::Right) {
+                    Some(right_fork) => {
+                        debug!("{}: Got right. Eating!", c.position());
+                        c.start_eating();
+                        Box::new(Eating::new(self.left_fork.take().unwrap(), right_fork, c))
+                    }
+                    None => match c.contend(Side::Right) {
+                        Conflict::Die => {
+                            debug!("{}: Younger, dies and puts left down", c.position());
+                            c.abort();
+                            self.drop_left(c)
+                        }
+                        conflict => {
+                            debug!("{}: Not right, {:?}", c.position(), conflict);
+                            Box::new(LeftThinking::new(self.left_fork.take().unwrap(), c))
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::LeftThinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::preemption_impl::contender::{contenders, Contender};
+    use crate::dining_philosophers::preemption_impl::left_thinking::LeftThinking;
+    use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+    fn left_thinking(contender: Contender) -> Box<dyn StateMachine> {
+        contender.hungry();
+        let fork = contender.take(Side::Left).unwrap();
+        Box::new(LeftThinking::new(fork, contender))
+    }
+
+    #[test]
+    fn eats_when_the_right_fork_is_free() {
+        let (mut contenders, ledger) = contenders(3, Scheme::WoundWait);
+        let mut unit = left_thinking(contenders.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+        assert!(ledger.lock().unwrap().is_eating(0));
+    }
+
+    #[test]
+    fn older_philosopher_waits_for_the_wounded_neighbour() {
+        let (mut contenders, ledger) = contenders(3, Scheme::WoundWait);
+        let mut unit = left_thinking(contenders.remove(0));
+        let mut neighbour = left_thinking(contenders.remove(0));
+
+        unit = unit.transition(); // wounds the neighbour
+        neighbour = neighbour.transition(); // puts the fork down
+        unit = unit.transition();
+
+        assert_eq!(neighbour.state(), State::Hungry);
+        assert_eq!(unit.state(), State::Eating);
+        assert_eq!(ledger.lock().unwrap().preemptions(), 1);
+    }
+
+    #[test]
+    fn wounded_philosopher_keeps_its_timestamp() {
+        let (mut contenders, ledger) = contenders(3, Scheme::WoundWait);
+        let mut unit = left_thinking(contenders.remove(0));
+        let mut neighbour = left_thinking(contenders.remove(0));
+
+        unit.transition();
+        neighbour = neighbour.transition();
+
+        assert_eq!(neighbour.state(), State::Hungry);
+        assert_eq!(ledger.lock().unwrap().timestamp(1), Some(2));
+    }
+
+    #[test]
+    fn younger_philosopher_dies_under_wait_die() {
+        let (mut contenders, ledger) = contenders(3, Scheme::WaitDie);
+        let mut neighbour = left_thinking(contenders.remove(1));
+        let mut unit = left_thinking(contenders.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+        assert_eq!(ledger.lock().unwrap().aborts(), 1);
+        assert_eq!(ledger.lock().unwrap().timestamp(0), Some(2));
+        neighbour = neighbour.transition();
+        assert_eq!(neighbour.state(), State::Eating);
+    }
+
+    #[test]
+    fn older_philosopher_waits_under_wait_die() {
+        let (mut contenders, _) = contenders(3, Scheme::WaitDie);
+        let mut unit = left_thinking(contenders.remove(0));
+        let _neighbour = left_thinking(contenders.remove(0));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let (mut contenders, _) = contenders(3, Scheme::WaitDie);
+        let contender = contenders.remove(0);
+        let fork = contender.take(Side::Left).unwrap();
+        let mut unit = LeftThinking::new(fork, contender);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,7 @@
+pub mod scheme;
+pub mod ledger;
+pub mod contender;
+pub mod thinking;
+pub mod left_thinking;
+pub mod eating;
+pub mod preemption;
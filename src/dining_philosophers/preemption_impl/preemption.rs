@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::preemption_impl::contender::Contender;
+use crate::dining_philosophers::preemption_impl::ledger::Ledger;
+use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+use crate::dining_philosophers::preemption_impl::thinking::Thinking;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Deadlock prevention borrowed from databases: every philosopher takes its left fork first,
+/// and a conflict over the right fork is settled by the timestamps of the two neighbours.
+pub struct Preemption {
+    scheme: Scheme,
+    ledger: Arc<Mutex<Ledger>>,
+}
+
+impl Preemption {
+    pub fn new(number_of_philosophers: usize, scheme: Scheme) -> Preemption {
+        Preemption {
+            scheme,
+            ledger: Arc::new(Mutex::new(Ledger::new(number_of_philosophers))),
+        }
+    }
+}
+
+impl Strategy for Preemption {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Thinking::new(Contender::new(table_interaction, self.scheme, Arc::clone(&self.ledger))))
+    }
+
+    fn counters(&self) -> Counters {
+        let ledger = self.ledger.lock().unwrap();
+        vec![("preemptions", ledger.preemptions()), ("aborts", ledger.aborts())]
+    }
+
+    /// The neighbour it contends with is whoever shares the fork.
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::preemption_impl::preemption::Preemption;
+    use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+    use crate::dining_philosophers::strategy::Strategy;
+
+    #[test]
+    fn everyone_eats_under_either_scheme() {
+        for &scheme in &[Scheme::WoundWait, Scheme::WaitDie] {
+            for size in 2..=16 {
+                let results = run_in_lockstep(&mut Preemption::new(size, scheme), size, 8 * size);
+
+                for (id, history) in &results {
+                    assert!(history.contains(&State::Eating), "{:?}: philosopher {} of {} never ate", scheme, id, size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        for &scheme in &[Scheme::WoundWait, Scheme::WaitDie] {
+            let results = run_in_lockstep(&mut Preemption::new(5, scheme), 5, 200);
+
+            assert_neighbours_never_eat_together(&results);
+        }
+    }
+
+    #[test]
+    fn wound_wait_preempts_but_never_aborts() {
+        let mut unit = Preemption::new(5, Scheme::WoundWait);
+        run_in_lockstep(&mut unit, 5, 100);
+
+        let counters = unit.counters();
+        assert!(counters[0].1 > 0);
+        assert_eq!(counters[1], ("aborts", 0));
+    }
+
+    #[test]
+    fn wait_die_aborts_but_never_preempts() {
+        let mut unit = Preemption::new(5, Scheme::WaitDie);
+        run_in_lockstep(&mut unit, 5, 100);
+
+        let counters = unit.counters();
+        assert_eq!(counters[0], ("preemptions", 0));
+        assert!(counters[1].1 > 0);
+    }
+}
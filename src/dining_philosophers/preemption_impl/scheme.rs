@@ -0,0 +1,67 @@
+/// The database schemes for settling who waits when a fork is in use. The philosopher with the
+/// smaller timestamp became hungry first and is the older one.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Scheme {
+    /// An older requester wounds a younger holder, taking the fork; a younger requester waits.
+    WoundWait,
+    /// An older requester waits; a younger requester dies, putting down what it holds.
+    WaitDie,
+}
+
+/// What a requester does about a fork its neighbour holds.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Conflict {
+    Wound,
+    Wait,
+    Die,
+}
+
+impl Scheme {
+    /// Settles a conflict over a fork. Only a holder still waiting for its other fork can be
+    /// wounded; a holder without a timestamp is treated as the older one.
+    pub fn resolve(self, requester: u64, holder: Option<u64>, holder_eating: bool) -> Conflict {
+        let older = holder.is_some_and(|holder| requester < holder);
+        match self {
+            Scheme::WoundWait if older && !holder_eating => Conflict::Wound,
+            Scheme::WoundWait => Conflict::Wait,
+            Scheme::WaitDie if older => Conflict::Wait,
+            Scheme::WaitDie => Conflict::Die,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::preemption_impl::scheme::{Conflict, Scheme};
+
+    #[test]
+    fn wound_wait_wounds_a_younger_holder() {
+        assert_eq!(Scheme::WoundWait.resolve(1, Some(2), false), Conflict::Wound);
+    }
+
+    #[test]
+    fn wound_wait_does_not_wound_an_eating_holder() {
+        assert_eq!(Scheme::WoundWait.resolve(1, Some(2), true), Conflict::Wait);
+    }
+
+    #[test]
+    fn wound_wait_makes_a_younger_requester_wait() {
+        assert_eq!(Scheme::WoundWait.resolve(3, Some(2), false), Conflict::Wait);
+    }
+
+    #[test]
+    fn wait_die_makes_an_older_requester_wait() {
+        assert_eq!(Scheme::WaitDie.resolve(1, Some(2), false), Conflict::Wait);
+    }
+
+    #[test]
+    fn wait_die_kills_a_younger_requester() {
+        assert_eq!(Scheme::WaitDie.resolve(3, Some(2), false), Conflict::Die);
+    }
+
+    #[test]
+    fn holder_without_a_timestamp_counts_as_older() {
+        assert_eq!(Scheme::WoundWait.resolve(3, None, false), Conflict::Wait);
+        assert_eq!(Scheme::WaitDie.resolve(3, None, false), Conflict::Die);
+    }
+}
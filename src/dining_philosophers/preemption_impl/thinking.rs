@@ -0,0 +1,93 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::preemption_impl::contender::Contender;
+use crate::dining_philosophers::preemption_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+/// Thinking, or hungry with no fork in hand and reaching for the left fork first.
+///
+/// The left fork is only ever held by the left neighbour as its second fork, while it eats,
+/// so there is nobody to wound or die for here: the philosopher just waits.
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    contender: Option<Contender>,
+}
+
+impl Thinking {
+    pub fn new(contender: Contender) -> Thinking {
+        Thinking {
+            contender: Some(contender),
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.contender.take() {
+            None => { panic!("No longer valid") }
+            Some(c) => {
+                c.hungry();
+                match c.take(Side::Left) {
+                    Some(fork) => {
+                        debug!("{}: Got left, to left thinking", c.position());
+                        Box::new(LeftThinking::new(fork, c))
+                    }
+                    None => {
+                        debug!("{}: No left, still thinking", c.position());
+                        Box::new(Thinking::new(c))
+                    }
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        match self.contender {
+            Some(ref c) if c.is_hungry() => State::Hungry,
+            _ => State::Thinking,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::preemption_impl::contender::contenders;
+    use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+    use crate::dining_philosophers::preemption_impl::thinking::Thinking;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+    #[test]
+    fn state_is_thinking() {
+        assert_eq!(Thinking::new(contenders(2, Scheme::WoundWait).0.remove(0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn takes_the_left_fork() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(contenders(3, Scheme::WoundWait).0.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+
+    #[test]
+    fn waits_for_the_left_fork() {
+        let (mut contenders, _) = contenders(3, Scheme::WoundWait);
+        let neighbour = contenders.remove(2);
+        let _fork = neighbour.take(Side::Right);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(contenders.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(contenders(2, Scheme::WoundWait).0.remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
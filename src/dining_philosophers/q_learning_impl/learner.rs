@@ -0,0 +1,242 @@
+use std::mem;
+use std::sync::{Arc, Mutex};
+
+use rand::Rng;
+
+use crate::dining_philosophers::analysis::score_history;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+use crate::dining_philosophers::policy_table_impl::hands::Hands;
+use crate::dining_philosophers::q_learning_impl::q_table::QTable;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::table::TableInteraction;
+
+/// How a learner updates its Q-table.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Learning {
+    /// How far each update moves a value towards its target.
+    pub rate: f64,
+    /// How much the value of the next state counts against the reward.
+    pub discount: f64,
+    /// The chance of trying a random action instead of the best one.
+    pub exploration: f64,
+}
+
+impl Default for Learning {
+    fn default() -> Self {
+        Learning {
+            rate: 0.1,
+            discount: 0.9,
+            exploration: 0.1,
+        }
+    }
+}
+
+/// Whether a learner still updates its Q-table, or follows it as it is.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Mode {
+    Training(Learning),
+    Frozen,
+}
+
+/// The last action taken, waiting for the next step to value it.
+#[derive(Debug, PartialEq)]
+struct Step {
+    state: State,
+    forks: Forks,
+    sated: bool,
+    action: Action,
+    reward: f64,
+}
+
+/// A philosopher who picks whether to grab, hold or release forks from a Q-table.
+///
+/// Its reward for an action is how much the action changed its own term of the analysis score,
+/// which is best when it eats for half its history. So it also looks up whether it is sated,
+/// having eaten for at least half its history so far.
+#[derive(Debug)]
+pub struct Learner {
+    state: State,
+    hands: Hands,
+    table_interaction: Option<TableInteraction>,
+    q_table: Arc<Mutex<QTable>>,
+    mode: Mode,
+    rng: SeededRng,
+    history: Vec<State>,
+    last: Option<Step>,
+}
+
+impl PartialEq for Learner {
+    fn eq(&self, other: &Learner) -> bool {
+        (&self.state, &self.table_interaction, &self.mode, &self.rng, &self.history) == (&other.state, &other.table_interaction, &other.mode, &other.rng, &other.history)
+    }
+}
+
+impl Learner {
+    pub fn new(table_interaction: TableInteraction, q_table: Arc<Mutex<QTable>>, mode: Mode, rng: SeededRng) -> Learner {
+        Learner {
+            state: State::Thinking,
+            hands: Hands::default(),
+            table_interaction: Some(table_interaction),
+            q_table,
+            mode,
+            rng,
+            history: vec![State::Thinking],
+            last: None,
+        }
+    }
+
+    /// Values the last action by its reward and the best value of where it led, then picks
+    /// the next action.
+    fn choose(&mut self, forks: Forks, sated: bool) -> Action {
+        let mut q_table = self.q_table.lock().unwrap();
+        match self.mode {
+            Mode::Frozen => q_table.best(&self.state, forks, sated).0,
+            Mode::Training(learning) => {
+                if let Some(last) = self.last.take() {
+                    let next = q_table.best(&self.state, forks, sated).1;
+                    let value = q_table.value(&last.state, last.forks, last.sated, last.action);
+                    let target = last.reward + learning.discount * next;
+                    q_table.set(&last.state, last.forks, last.sated, last.action, value + learning.rate * (target - value));
+                }
+                match self.rng.gen_bool(learning.exploration) {
+                    true => {
+                        let actions = QTable::actions(&self.state, forks);
+                        actions[self.rng.gen_range(0, actions.len())]
+                    }
+                    false => q_table.best(&self.state, forks, sated).0,
+                }
+            }
+        }
+    }
+}
+
+impl StateMachine for Learner {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.table_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(t) => {
+                let forks = Forks::observe(&t);
+                let sated = 2 * self.history.iter().filter(|s| **s == State::Eating).count() >= self.history.len();
+                let action = self.choose(forks, sated);
+                let state = match self.hands.act(action, &t) {
+                    true => action.next(&self.state).unwrap(),
+                    false => self.state.clone(),
+                };
+                debug!("{}: {:?} found {} forks, {} to {:?}", t.position, self.state, forks.name(), action.name(), state);
+
+                let before = score_history(&self.history) as f64;
+                self.history.push(state.clone());
+                let reward = score_history(&self.history) as f64 - before;
+                Box::new(Learner {
+                    state: state.clone(),
+                    hands: mem::take(&mut self.hands),
+                    table_interaction: Some(t),
+                    q_table: Arc::clone(&self.q_table),
+                    mode: self.mode,
+                    rng: self.rng.clone(),
+                    history: mem::take(&mut self.history),
+                    last: Some(Step { state: self.state.clone(), forks, sated, action, reward }),
+                })
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        self.state.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+    use crate::dining_philosophers::q_learning_impl::learner::{Learner, Learning, Mode};
+    use crate::dining_philosophers::q_learning_impl::q_table::QTable;
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::table::Table;
+
+    fn learner(q_table: &Arc<Mutex<QTable>>, mode: Mode) -> Box<dyn StateMachine> {
+        Box::new(Learner::new(Table::new(3).get_interactions().remove(0), Arc::clone(q_table), mode, SeededRng::new(0)))
+    }
+
+    fn greedy() -> Mode {
+        Mode::Training(Learning { exploration: 0.0, ..Learning::default() })
+    }
+
+    #[test]
+    fn starts_thinking() {
+        assert_eq!(learner(&Arc::new(Mutex::new(QTable::new())), Mode::Frozen).state(), State::Thinking);
+    }
+
+    #[test]
+    fn follows_the_best_action() {
+        let mut q_table = QTable::new();
+        q_table.set(&State::Thinking, Forks::Both, false, Action::TakeBoth, 1.0);
+        let q_table = Arc::new(Mutex::new(q_table));
+        let mut unit = learner(&q_table, Mode::Frozen);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn frozen_learner_leaves_the_table_alone() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        let mut unit = learner(&q_table, Mode::Frozen);
+
+        for _ in 0..10 {
+            unit = unit.transition();
+        }
+
+        assert_eq!(*q_table.lock().unwrap(), QTable::new());
+    }
+
+    #[test]
+    fn values_an_action_by_its_reward_on_the_next_step() {
+        let mut q_table = QTable::new();
+        q_table.set(&State::Thinking, Forks::Both, false, Action::TakeBoth, 0.5);
+        let q_table = Arc::new(Mutex::new(q_table));
+        let mut unit = learner(&q_table, greedy());
+
+        unit = unit.transition();
+        assert_eq!(q_table.lock().unwrap().value(&State::Thinking, Forks::Both, false, Action::TakeBoth), 0.5);
+        unit.transition();
+
+        // Eating for one step of two lifts the score from 0 to 1; the next state is valued at 0.
+        let value = q_table.lock().unwrap().value(&State::Thinking, Forks::Both, false, Action::TakeBoth);
+        assert!((value - (0.5 + 0.1 * (1.0 - 0.5))).abs() < 1e-9, "{}", value);
+    }
+
+    #[test]
+    fn explores_every_action() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        let mode = Mode::Training(Learning { exploration: 1.0, rate: 0.0, ..Learning::default() });
+        let mut states = Vec::new();
+        let mut unit = learner(&q_table, mode);
+
+        for _ in 0..50 {
+            unit = unit.transition();
+            states.push(unit.state());
+        }
+
+        assert!(states.contains(&State::LeftThinking));
+        assert!(states.contains(&State::RightThinking));
+        assert!(states.contains(&State::Eating));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        let mut unit = Learner::new(Table::new(2).get_interactions().remove(0), q_table, Mode::Frozen, SeededRng::new(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,3 @@
+pub mod q_table;
+pub mod learner;
+pub mod q_learning;
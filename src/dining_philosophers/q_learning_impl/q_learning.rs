@@ -0,0 +1,155 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::score_one_run;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::q_learning_impl::learner::{Learner, Learning, Mode};
+use crate::dining_philosophers::q_learning_impl::q_table::QTable;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::simulation::run_in_lockstep;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Seats learners who share one Q-table, which outlives the table and can be saved.
+///
+/// Each seat draws from its own generator, seeded from `seed` and its position.
+pub struct QLearning {
+    q_table: Arc<Mutex<QTable>>,
+    mode: Mode,
+    seed: u64,
+}
+
+impl QLearning {
+    pub fn training(q_table: Arc<Mutex<QTable>>, learning: Learning, seed: u64) -> QLearning {
+        QLearning {
+            q_table,
+            mode: Mode::Training(learning),
+            seed,
+        }
+    }
+
+    /// Learners who always take the best action and never update the table.
+    pub fn frozen(q_table: Arc<Mutex<QTable>>, seed: u64) -> QLearning {
+        QLearning {
+            q_table,
+            mode: Mode::Frozen,
+            seed,
+        }
+    }
+}
+
+impl Strategy for QLearning {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let rng = SeededRng::new(self.seed.wrapping_add(table_interaction.position as u64));
+        Box::new(Learner::new(table_interaction, Arc::clone(&self.q_table), self.mode, rng))
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+/// A training session of several lockstep runs. Run `i` is seeded with `seed + i`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Training {
+    pub learning: Learning,
+    pub philosophers: usize,
+    pub rounds: usize,
+    pub episodes: usize,
+    pub seed: u64,
+}
+
+impl Default for Training {
+    fn default() -> Self {
+        Training {
+            learning: Learning::default(),
+            philosophers: 5,
+            rounds: 200,
+            episodes: 500,
+            seed: 0,
+        }
+    }
+}
+
+impl Training {
+    /// Trains the Q-table, returning the score of every run.
+    pub fn train(&self, q_table: &Arc<Mutex<QTable>>) -> Vec<f32> {
+        (0..self.episodes)
+            .map(|episode| {
+                let mut strategy = QLearning::training(Arc::clone(q_table), self.learning, self.seed.wrapping_add(episode as u64));
+                score_one_run(&run_in_lockstep(&mut strategy, self.philosophers, self.rounds))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::analysis::score_one_run;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::q_learning_impl::q_learning::{QLearning, Training};
+    use crate::dining_philosophers::q_learning_impl::q_table::QTable;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+
+    fn training() -> Training {
+        Training { rounds: 100, episodes: 40, ..Training::default() }
+    }
+
+    #[test]
+    fn untrained_learners_never_eat() {
+        let results = run_in_lockstep(&mut QLearning::frozen(Arc::new(Mutex::new(QTable::new())), 0), 5, 50);
+
+        assert!(results.values().all(|history| !history.contains(&State::Eating)));
+    }
+
+    #[test]
+    fn trained_learners_eat() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        training().train(&q_table);
+
+        let results = run_in_lockstep(&mut QLearning::frozen(q_table, 0), 5, 100);
+
+        assert!(score_one_run(&results) > 0.1, "{}", score_one_run(&results));
+    }
+
+    #[test]
+    fn neighbours_never_eat_together_while_training() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        let results = run_in_lockstep(&mut QLearning::training(q_table, Default::default(), 0), 5, 200);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn same_seed_trains_the_same_table() {
+        let first = Arc::new(Mutex::new(QTable::new()));
+        let second = Arc::new(Mutex::new(QTable::new()));
+
+        assert_eq!(training().train(&first), training().train(&second));
+        assert_eq!(*first.lock().unwrap(), *second.lock().unwrap());
+    }
+
+    #[test]
+    fn training_goes_on_from_a_saved_table() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        training().train(&q_table);
+        let saved: QTable = q_table.lock().unwrap().to_string().parse().unwrap();
+
+        let scores = Training { episodes: 1, ..training() }.train(&Arc::new(Mutex::new(saved)));
+
+        assert!(scores[0] > 0.0);
+    }
+
+    #[test]
+    fn frozen_runs_leave_the_table_alone() {
+        let q_table = Arc::new(Mutex::new(QTable::new()));
+        training().train(&q_table);
+        let before = q_table.lock().unwrap().clone();
+
+        run_in_lockstep(&mut QLearning::frozen(Arc::clone(&q_table), 0), 5, 100);
+
+        assert_eq!(*q_table.lock().unwrap(), before);
+    }
+}
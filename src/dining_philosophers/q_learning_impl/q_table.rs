@@ -0,0 +1,188 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::policy_table_impl::action::Action;
+use crate::dining_philosophers::policy_table_impl::forks::Forks;
+use crate::dining_philosophers::policy_table_impl::policy_table::{parse_state, STATES};
+
+/// The learned value of every action a philosopher can take in each state, given the forks it
+/// finds on the table and whether it is sated, having eaten for at least half its history.
+/// Actions it cannot take are never valued.
+#[derive(Debug, PartialEq, Clone)]
+pub struct QTable {
+    values: Vec<f64>,
+}
+
+impl Default for QTable {
+    fn default() -> Self {
+        QTable::new()
+    }
+}
+
+impl QTable {
+    /// A table that values every action at zero.
+    pub fn new() -> QTable {
+        QTable {
+            values: vec![0.0; STATES.len() * Forks::ALL.len() * APPETITES.len() * Action::ALL.len()],
+        }
+    }
+
+    /// Reads a table saved with `save`.
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<QTable> {
+        fs::read_to_string(path)?
+            .parse()
+            .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// The actions a philosopher in the given state who found the given forks can take.
+    pub fn actions(state: &State, forks: Forks) -> Vec<Action> {
+        Action::ALL.iter()
+            .filter(|action| action.next(state).is_some() && action.finds(forks))
+            .cloned()
+            .collect()
+    }
+
+    pub fn value(&self, state: &State, forks: Forks, sated: bool, action: Action) -> f64 {
+        self.values[index(state, forks, sated, action)]
+    }
+
+    pub fn set(&mut self, state: &State, forks: Forks, sated: bool, action: Action, value: f64) {
+        self.values[index(state, forks, sated, action)] = value;
+    }
+
+    /// The most valuable action, the earliest of equally valued ones, and its value.
+    pub fn best(&self, state: &State, forks: Forks, sated: bool) -> (Action, f64) {
+        QTable::actions(state, forks).into_iter()
+            .map(|action| (action, self.value(state, forks, sated, action)))
+            .fold(None, |best: Option<(Action, f64)>, candidate| match best {
+                Some(b) if b.1 >= candidate.1 => Some(b),
+                _ => Some(candidate),
+            })
+            .expect("Every state has an action")
+    }
+}
+
+/// The names of being hungry and sated in the text form.
+const APPETITES: [(&str, bool); 2] = [("hungry", false), ("sated", true)];
+
+fn index(state: &State, forks: Forks, sated: bool, action: Action) -> usize {
+    let state = STATES.iter().position(|(_, s)| s == state).expect("The Q-table does not cover this state");
+    let forks = Forks::ALL.iter().position(|f| *f == forks).unwrap();
+    let action = Action::ALL.iter().position(|a| *a == action).unwrap();
+    ((state * Forks::ALL.len() + forks) * APPETITES.len() + sated as usize) * Action::ALL.len() + action
+}
+
+/// One line for every action a philosopher can take: the state, the forks, whether it is hungry
+/// or sated, the action and its value.
+impl fmt::Display for QTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (name, state) in STATES.iter() {
+            for forks in Forks::ALL.iter().filter(|forks| forks.possible_in(state)) {
+                for (appetite, sated) in APPETITES.iter() {
+                    for action in QTable::actions(state, *forks) {
+                        writeln!(f, "{:<15} {:<8} {:<7} {:<11} {}", name, forks.name(), appetite, action.name(), self.value(state, *forks, *sated, action))?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for QTable {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<QTable, String> {
+        let mut table = QTable::new();
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let entry = match words.as_slice() {
+                [state, forks, appetite, action, value] => parse_state(state).and_then(|state| Some((
+                    state,
+                    Forks::parse(forks)?,
+                    APPETITES.iter().find(|(name, _)| name == appetite)?.1,
+                    Action::parse(action)?,
+                    value.parse::<f64>().ok()?,
+                ))),
+                _ => None,
+            };
+            match entry {
+                Some((state, forks, sated, action, value)) if QTable::actions(&state, forks).contains(&action) => table.set(&state, forks, sated, action, value),
+                _ => return Err(format!("line {}: expected a state, forks, hungry or sated, a possible action and a value", index + 1)),
+            }
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::policy_table_impl::action::Action;
+    use crate::dining_philosophers::policy_table_impl::forks::Forks;
+    use crate::dining_philosophers::q_learning_impl::q_table::QTable;
+
+    #[test]
+    fn new_table_values_everything_at_zero() {
+        let unit = QTable::new();
+
+        assert_eq!(unit.value(&State::Eating, Forks::Neither, true, Action::PutBoth), 0.0);
+        assert_eq!(unit.best(&State::Eating, Forks::Neither, false), (Action::Wait, 0.0));
+    }
+
+    #[test]
+    fn lists_only_possible_actions() {
+        assert_eq!(QTable::actions(&State::Thinking, Forks::Left), vec![Action::Wait, Action::TakeLeft]);
+        assert_eq!(QTable::actions(&State::LeftThinking, Forks::Neither), vec![Action::Wait, Action::PutLeft]);
+    }
+
+    #[test]
+    fn best_action_is_the_most_valuable() {
+        let mut unit = QTable::new();
+        unit.set(&State::Thinking, Forks::Both, false, Action::TakeRight, 0.5);
+        unit.set(&State::Thinking, Forks::Both, false, Action::TakeBoth, 0.25);
+
+        assert_eq!(unit.best(&State::Thinking, Forks::Both, false), (Action::TakeRight, 0.5));
+        assert_eq!(unit.best(&State::Thinking, Forks::Both, true), (Action::Wait, 0.0));
+    }
+
+    #[test]
+    fn reads_back_what_it_writes() {
+        let mut unit = QTable::new();
+        unit.set(&State::RightThinking, Forks::Left, false, Action::TakeLeft, 0.125);
+        unit.set(&State::Eating, Forks::Neither, true, Action::Wait, -0.5);
+
+        assert_eq!(unit.to_string().parse::<QTable>(), Ok(unit));
+    }
+
+    #[test]
+    fn rejects_an_impossible_action() {
+        assert_eq!("eating neither sated take-left 1".parse::<QTable>(), Err("line 1: expected a state, forks, hungry or sated, a possible action and a value".to_string()));
+        assert!("eating neither full put-both 1".parse::<QTable>().is_err());
+        assert!("eating neither".parse::<QTable>().is_err());
+    }
+
+    #[test]
+    fn saves_and_loads_a_file() {
+        let path = env::temp_dir().join(format!("q-table-{}.txt", std::process::id()));
+        let mut unit = QTable::new();
+        unit.set(&State::Thinking, Forks::Neither, false, Action::Wait, 0.75);
+
+        unit.save(&path).unwrap();
+        let loaded = QTable::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.unwrap(), unit);
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt;
+
+use rand::{Error, RngCore, SeedableRng};
+use rand::rngs::StdRng;
+
+/// A reproducible random number generator.
+///
+/// Two generators compare equal when they were created from the same seed, so the
+/// states that carry one can still be compared in tests.
+#[derive(Clone)]
+pub struct SeededRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl SeededRng {
+    pub fn new(seed: u64) -> SeededRng {
+        SeededRng {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl fmt::Debug for SeededRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SeededRng({})", self.seed)
+    }
+}
+
+impl PartialEq for SeededRng {
+    fn eq(&self, other: &SeededRng) -> bool {
+        self.seed == other.seed
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use crate::dining_philosophers::random::SeededRng;
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut one = SeededRng::new(42);
+        let mut two = SeededRng::new(42);
+
+        let first: Vec<u32> = (0..10).map(|_| one.gen()).collect();
+        let second: Vec<u32> = (0..10).map(|_| two.gen()).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn equal_when_seeds_match() {
+        assert_eq!(SeededRng::new(1), SeededRng::new(1));
+        assert_ne!(SeededRng::new(1), SeededRng::new(2));
+    }
+}
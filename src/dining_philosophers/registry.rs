@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use crate::dining_philosophers::always_thinking::AlwaysThinking;
+use crate::dining_philosophers::chandy_misra_impl::chandy_misra::ChandyMisra;
+use crate::dining_philosophers::footman_impl::footman::Footman;
+use crate::dining_philosophers::hunger_priority_impl::hunger_priority::HungerPriority;
+use crate::dining_philosophers::lehmann_rabin_impl::lehmann_rabin::LehmannRabin;
+use crate::dining_philosophers::preemption_impl::preemption::Preemption;
+use crate::dining_philosophers::preemption_impl::scheme::Scheme;
+use crate::dining_philosophers::resource_hierarchy_impl::exponential_backoff::ExponentialBackoff;
+use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+use crate::dining_philosophers::simulation::NewStrategy;
+use crate::dining_philosophers::tanenbaum_impl::tanenbaum::Tanenbaum;
+use crate::dining_philosophers::timed_impl::duration::Duration;
+use crate::dining_philosophers::timed_impl::timing::Timing;
+use crate::dining_philosophers::token_ring_impl::ring::Passing;
+use crate::dining_philosophers::token_ring_impl::token_ring::TokenRing;
+use crate::dining_philosophers::typestate_impl::typestate::Typestate;
+use crate::dining_philosophers::waiter_impl::arbitrator::GrantOrder;
+use crate::dining_philosophers::waiter_impl::waiter::Waiter;
+
+/// The strategies that can be selected by name, in the order they were registered, with the
+/// fewest philosophers each one can be built for.
+pub struct Registry {
+    strategies: Vec<(&'static str, usize, NewStrategy)>,
+}
+
+impl Registry {
+    pub fn empty() -> Registry {
+        Registry {
+            strategies: Vec::new(),
+        }
+    }
+
+    /// Every strategy of this crate.
+    pub fn new() -> Registry {
+        let mut registry = Registry::empty();
+        registry.register("resource-hierarchy", Arc::new(|_| Box::new(ResourceHierarchy::new())));
+        registry.register("odd-even", Arc::new(|_| Box::new(ResourceHierarchy::odd_even())));
+        registry.register("backoff", Arc::new(|_| Box::new(ExponentialBackoff::new(1, 16, 0))));
+        registry.register_with_fewest("chandy-misra", 2, Arc::new(|size| Box::new(ChandyMisra::new(size))));
+        registry.register("waiter", Arc::new(|size| Box::new(Waiter::new(size, GrantOrder::Fifo))));
+        registry.register("waiter-hunger", Arc::new(|size| Box::new(Waiter::new(size, GrantOrder::Hunger))));
+        registry.register_with_fewest("token-ring", 2, Arc::new(|size| Box::new(TokenRing::new(size))));
+        registry.register_with_fewest("token-ring-strict", 2, Arc::new(|size| Box::new(TokenRing::with_passing(size, Passing::Nobody))));
+        registry.register("hunger-priority", Arc::new(|_| Box::new(HungerPriority::new())));
+        registry.register("lehmann-rabin", Arc::new(|size| Box::new(LehmannRabin::new(size, 0))));
+        registry.register_with_fewest("tanenbaum", 2, Arc::new(|size| Box::new(Tanenbaum::new(size))));
+        registry.register("wound-wait", Arc::new(|size| Box::new(Preemption::new(size, Scheme::WoundWait))));
+        registry.register("wait-die", Arc::new(|size| Box::new(Preemption::new(size, Scheme::WaitDie))));
+        registry.register("footman", Arc::new(|size| Box::new(Footman::new(size))));
+        registry.register("long-meals", Arc::new(|_| Box::new(Timing::new(Box::new(ResourceHierarchy::new()), Duration::geometric(0.5), Duration::exponential(5_f64), 0))));
+        registry.register("typestate", Arc::new(|_| Box::new(Typestate::new())));
+        registry.register("always-thinking", Arc::new(|_| Box::new(AlwaysThinking {})));
+        registry
+    }
+
+    /// Adds a strategy for any number of philosophers, replacing any registered under the same name.
+    pub fn register(&mut self, name: &'static str, new_strategy: NewStrategy) {
+        self.register_with_fewest(name, 1, new_strategy);
+    }
+
+    /// Adds a strategy that can only be built for `fewest` philosophers or more.
+    pub fn register_with_fewest(&mut self, name: &'static str, fewest: usize, new_strategy: NewStrategy) {
+        match self.strategies.iter_mut().find(|(n, _, _)| *n == name) {
+            Some(entry) => *entry = (name, fewest, new_strategy),
+            None => self.strategies.push((name, fewest, new_strategy)),
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<NewStrategy> {
+        self.strategies.iter()
+            .find(|(n, _, _)| *n == name)
+            .map(|(_, _, new_strategy)| Arc::clone(new_strategy))
+    }
+
+    /// Whether the named strategy can be built for a table of the given size.
+    pub fn builds_for(&self, name: &str, size: usize) -> bool {
+        self.strategies.iter().any(|(n, fewest, _)| *n == name && size >= *fewest)
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        self.strategies.iter().map(|(name, _, _)| *name).collect()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        Registry::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::always_thinking::AlwaysThinking;
+    use crate::dining_philosophers::analysis::score_one_run;
+    use crate::dining_philosophers::registry::Registry;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+
+    #[test]
+    fn registers_every_strategy() {
+        let unit = Registry::new();
+
+        assert_eq!(unit.names(), vec!["resource-hierarchy", "odd-even", "backoff", "chandy-misra", "waiter", "waiter-hunger", "token-ring", "token-ring-strict", "hunger-priority", "lehmann-rabin", "tanenbaum", "wound-wait", "wait-die", "footman", "long-meals", "typestate", "always-thinking"]);
+    }
+
+    #[test]
+    fn unknown_name_is_not_found() {
+        assert!(Registry::new().get("no-such-strategy").is_none());
+    }
+
+    #[test]
+    fn every_registered_strategy_seats_a_table() {
+        let unit = Registry::new();
+
+        for name in unit.names() {
+            let mut strategy = unit.get(name).unwrap()(5);
+            let results = run_in_lockstep(strategy.as_mut(), 5, 20);
+
+            assert_eq!(results.len(), 5, "{} did not seat every philosopher", name);
+        }
+    }
+
+    #[test]
+    fn every_strategy_but_the_baseline_eats() {
+        let unit = Registry::new();
+
+        for name in unit.names() {
+            let mut strategy = unit.get(name).unwrap()(5);
+            let results = run_in_lockstep(strategy.as_mut(), 5, 20);
+
+            assert_eq!(score_one_run(&results) > 0.0, name != "always-thinking", "{}", name);
+        }
+    }
+
+    #[test]
+    fn every_strategy_builds_for_the_fewest_philosophers_it_claims() {
+        let unit = Registry::new();
+
+        for name in unit.names() {
+            let fewest = (1..).find(|size| unit.builds_for(name, *size)).unwrap();
+            let mut strategy = unit.get(name).unwrap()(fewest);
+            run_in_lockstep(strategy.as_mut(), fewest, 5);
+        }
+        assert!(!unit.builds_for("chandy-misra", 1));
+        assert!(!unit.builds_for("no-such-strategy", 5));
+    }
+
+    #[test]
+    fn registering_a_name_again_replaces_the_strategy() {
+        let mut unit = Registry::empty();
+        unit.register("baseline", Arc::new(|_| Box::new(AlwaysThinking {})));
+        unit.register("baseline", Arc::new(|_| Box::new(AlwaysThinking {})));
+
+        assert_eq!(unit.names(), vec!["baseline"]);
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::{Arc, Mutex};
+
+use rand::Rng;
+
+use crate::dining_philosophers::random::SeededRng;
+
+/// The failed attempts before each meal of a table, in the order the meals were eaten.
+#[derive(Debug, PartialEq, Default)]
+pub struct Retries {
+    per_meal: Vec<usize>,
+}
+
+impl Retries {
+    pub fn record(&mut self, retries: usize) {
+        self.per_meal.push(retries);
+    }
+
+    pub fn per_meal(&self) -> &[usize] {
+        &self.per_meal
+    }
+
+    pub fn meals(&self) -> usize {
+        self.per_meal.len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.per_meal.iter().sum()
+    }
+
+    pub fn most(&self) -> usize {
+        self.per_meal.iter().cloned().max().unwrap_or(0)
+    }
+}
+
+/// Capped, jittered exponential backoff after failing to get the forks.
+///
+/// After the k-th failure since its last meal a philosopher sits out a random number of ticks
+/// between 0 and `min(cap, base * 2^(k-1))`. A meal resets the count.
+#[derive(Debug)]
+pub struct Backoff {
+    base: usize,
+    cap: usize,
+    failures: usize,
+    remaining: usize,
+    rng: Box<SeededRng>,
+    retries: Arc<Mutex<Retries>>,
+}
+
+impl PartialEq for Backoff {
+    fn eq(&self, other: &Backoff) -> bool {
+        (self.base, self.cap, self.failures, self.remaining, &self.rng) == (other.base, other.cap, other.failures, other.remaining, &other.rng)
+    }
+}
+
+impl Backoff {
+    pub fn new(base: usize, cap: usize, seed: u64, retries: Arc<Mutex<Retries>>) -> Backoff {
+        Backoff {
+            base,
+            cap,
+            failures: 0,
+            remaining: 0,
+            rng: Box::new(SeededRng::new(seed)),
+            retries,
+        }
+    }
+
+    /// The most ticks the next failure can cost.
+    pub fn window(&self) -> usize {
+        let doublings = 2_usize.saturating_pow(self.failures.saturating_sub(1) as u32);
+        self.base.saturating_mul(doublings).min(self.cap)
+    }
+
+    pub fn failures(&self) -> usize {
+        self.failures
+    }
+
+    pub fn failed(&mut self) {
+        self.failures += 1;
+        self.remaining = self.rng.gen_range(0, self.window() + 1);
+    }
+
+    pub fn ate(&mut self) {
+        self.retries.lock().unwrap().record(self.failures);
+        self.failures = 0;
+        self.remaining = 0;
+    }
+
+    /// Whether to sit this tick out, counting it off the wait.
+    pub fn sitting_out(&mut self) -> bool {
+        match self.remaining {
+            0 => false,
+            _ => {
+                self.remaining -= 1;
+                true
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::resource_hierarchy_impl::backoff::{Backoff, Retries};
+
+    fn backoff(base: usize, cap: usize) -> Backoff {
+        Backoff::new(base, cap, 5, Arc::new(Mutex::new(Retries::default())))
+    }
+
+    fn sat_out(unit: &mut Backoff) -> usize {
+        let mut ticks = 0;
+        while unit.sitting_out() {
+            ticks += 1;
+        }
+        ticks
+    }
+
+    #[test]
+    fn does_not_sit_out_before_a_failure() {
+        assert!(!backoff(2, 16).sitting_out());
+    }
+
+    #[test]
+    fn window_doubles_with_every_failure() {
+        let mut unit = backoff(2, 100);
+        let mut windows = Vec::new();
+        for _ in 0..4 {
+            unit.failed();
+            windows.push(unit.window());
+        }
+
+        assert_eq!(windows, vec![2, 4, 8, 16]);
+    }
+
+    #[test]
+    fn window_is_capped() {
+        let mut unit = backoff(2, 10);
+        for _ in 0..100 {
+            unit.failed();
+        }
+
+        assert_eq!(unit.window(), 10);
+    }
+
+    #[test]
+    fn sits_out_at_most_the_window() {
+        let mut unit = backoff(3, 12);
+        for _ in 0..50 {
+            unit.failed();
+            let window = unit.window();
+
+            assert!(sat_out(&mut unit) <= window);
+        }
+    }
+
+    #[test]
+    fn wait_is_jittered() {
+        let mut unit = backoff(8, 8);
+        let waits: Vec<usize> = (0..20).map(|_| {
+            unit.failed();
+            sat_out(&mut unit)
+        }).collect();
+
+        assert!(waits.iter().any(|w| *w != waits[0]));
+    }
+
+    #[test]
+    fn meal_resets_the_failures_and_records_them() {
+        let retries = Arc::new(Mutex::new(Retries::default()));
+        let mut unit = Backoff::new(2, 16, 5, Arc::clone(&retries));
+        unit.failed();
+        unit.failed();
+
+        unit.ate();
+
+        assert_eq!(unit.failures(), 0);
+        assert!(!unit.sitting_out());
+        assert_eq!(retries.lock().unwrap().per_meal(), &[2]);
+    }
+
+    #[test]
+    fn retries_summarise_every_meal() {
+        let mut unit = Retries::default();
+        unit.record(0);
+        unit.record(3);
+        unit.record(1);
+
+        assert_eq!((unit.meals(), unit.total(), unit.most()), (3, 4, 3));
+    }
+}
@@ -1,42 +1,82 @@
-use crate::dining_philosophers::fork::Fork;
+use crate::dining_philosophers::fork::{keep_refused, Fork};
 use crate::dining_philosophers::resource_hierarchy_impl::left_thinking::LeftThinking;
 use crate::dining_philosophers::resource_hierarchy_impl::right_thinking::RightThinking;
 use crate::dining_philosophers::table::TableInteraction;
 use crate::dining_philosophers::philosopher::state_machine::{StateMachine, State};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
 
 #[derive(Debug, PartialEq)]
 pub struct Eating {
     left_fork: Option<Fork>,
     right_fork: Option<Fork>,
     table_interaction: Option<TableInteraction>,
+    policy: Option<Policy>,
 }
 
 impl Eating {
     pub fn new(left_fork: Fork, right_fork: Fork, table_interaction: TableInteraction) -> Eating {
+        Eating::with_policy(left_fork, right_fork, table_interaction, Policy::default())
+    }
+
+    pub fn with_policy(left_fork: Fork, right_fork: Fork, table_interaction: TableInteraction, policy: Policy) -> Eating {
         Eating {
             left_fork: Some(left_fork),
             right_fork: Some(right_fork),
             table_interaction: Some(table_interaction),
+            policy: Some(policy),
         }
     }
 
-    fn drop_left(&mut self, table_interaction: TableInteraction) -> RightThinking {
-        table_interaction.return_left_fork(self.left_fork.take().unwrap());
-        RightThinking::new(self.right_fork.take().unwrap(), table_interaction)
+    fn drop_left(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let left_fork = keep_refused(table_interaction.position, table_interaction.return_left_fork(self.left_fork.take().unwrap()));
+        let right_fork = self.right_fork.take();
+        self.holding(left_fork, right_fork, table_interaction)
+    }
+    fn drop_right(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let right_fork = keep_refused(table_interaction.position, table_interaction.return_right_fork(self.right_fork.take().unwrap()));
+        let left_fork = self.left_fork.take();
+        self.holding(left_fork, right_fork, table_interaction)
     }
-    fn drop_right(&mut self, table_interaction: TableInteraction) -> LeftThinking {
-        table_interaction.return_right_fork(self.right_fork.take().unwrap());
-        LeftThinking::new(self.left_fork.take().unwrap(), table_interaction)
+    fn drop_both(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let right_fork = keep_refused(table_interaction.position, table_interaction.return_right_fork(self.right_fork.take().unwrap()));
+        let left_fork = keep_refused(table_interaction.position, table_interaction.return_left_fork(self.left_fork.take().unwrap()));
+        self.holding(left_fork, right_fork, table_interaction)
+    }
+    /// The state of a philosopher still holding the given forks, which includes any the table refused.
+    fn holding(&mut self, left_fork: Option<Fork>, right_fork: Option<Fork>, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let policy = self.policy.take().unwrap();
+        match (left_fork, right_fork) {
+            (Some(left_fork), Some(right_fork)) => Box::new(Eating::with_policy(left_fork, right_fork, table_interaction, policy)),
+            (Some(left_fork), None) => Box::new(LeftThinking::with_policy(left_fork, table_interaction, policy)),
+            (None, Some(right_fork)) => Box::new(RightThinking::with_policy(right_fork, table_interaction, policy)),
+            (None, None) => Box::new(Thinking::with_policy(table_interaction, policy)),
+        }
     }
 }
 
 impl StateMachine for Eating {
-    fn transition(&mut self) -> Box<StateMachine + Send> {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
         match self.table_interaction.take() {
             None => { panic!("No longer valid") }
             Some(t) => {
-                debug!("{}: Drop left, to right thinking", t.position);
-                Box::new(self.drop_left(t))
+                let policy = self.policy.as_mut().unwrap();
+                policy.ate();
+                match policy.release.first_fork() {
+                    Some(Side::Left) => {
+                        debug!("{}: Drop left, to right thinking", t.position);
+                        self.drop_left(t)
+                    }
+                    Some(Side::Right) => {
+                        debug!("{}: Drop right, to left thinking", t.position);
+                        self.drop_right(t)
+                    }
+                    None => {
+                        debug!("{}: Drop both, to thinking", t.position);
+                        self.drop_both(t)
+                    }
+                }
             }
         }
     }
@@ -51,38 +91,68 @@ mod tests {
     use std::sync::{Arc, Mutex};
 
     use crate::dining_philosophers::fork::Fork;
-    use crate::dining_philosophers::resource_hierarchy_impl::left_thinking::LeftThinking;
-    use crate::dining_philosophers::resource_hierarchy_impl::right_thinking::RightThinking;
     use crate::dining_philosophers::table::{Table, TableInteraction};
     use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
     use crate::dining_philosophers::resource_hierarchy_impl::eating::Eating;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+    use crate::dining_philosophers::resource_hierarchy_impl::release::Release;
+
+    /// A fork as philosopher 0 holds it, for states that never put it back.
+    fn held(id: usize) -> Fork {
+        let mut fork = Fork::new(id);
+        fork.pick_up(0);
+        fork
+    }
 
     #[test]
     fn eating_drop_right_becomes_left_thinking() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = Eating { left_fork: Some(Fork), right_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+        let (left_fork, right_fork) = table_interaction.get_both_forks().unwrap();
+        let mut unit = Eating { left_fork: Some(left_fork), right_fork: Some(right_fork), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
+        let table = Arc::clone(&table_interaction.table);
 
         let unit = unit.drop_right(table_interaction);
 
-        assert_eq!(unit, LeftThinking::new(Fork, TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.state(), State::LeftThinking);
+        let table_interaction = TableInteraction { position: 0, table };
+        assert!(table_interaction.right_fork_on_table() && !table_interaction.left_fork_on_table());
     }
 
     #[test]
     fn eating_drop_left_becomes_right_thinking() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = Eating { left_fork: Some(Fork), right_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+        let (left_fork, right_fork) = table_interaction.get_both_forks().unwrap();
+        let mut unit = Eating { left_fork: Some(left_fork), right_fork: Some(right_fork), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
+        let table = Arc::clone(&table_interaction.table);
 
         let unit = unit.drop_left(table_interaction);
 
-        assert_eq!(unit, RightThinking::new(Fork, TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.state(), State::RightThinking);
+        let table_interaction = TableInteraction { position: 0, table };
+        assert!(table_interaction.left_fork_on_table() && !table_interaction.right_fork_on_table());
+    }
+
+    #[test]
+    fn keeps_eating_with_a_fork_the_table_refuses() {
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+        let (_, right_fork) = table_interaction.get_both_forks().unwrap();
+        let mut unit = Eating { left_fork: Some(held(1)), right_fork: Some(right_fork), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
+        let table_interaction = unit.table_interaction.take().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+
+        let unit = unit.drop_both(table_interaction);
+
+        assert_eq!(unit.state(), State::LeftThinking);
+        assert!(TableInteraction { position: 0, table }.right_fork_on_table());
     }
 
     #[test]
     fn state_is_eating() {
         let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let unit = Eating::new(Fork, Fork, table_interaction);
+        let unit = Eating::new(held(0), held(1), table_interaction);
 
         assert_eq!(unit.state(), State::Eating);
     }
@@ -93,7 +163,7 @@ mod tests {
         let table_interaction = table.get_interactions().pop().unwrap();
         let left_fork = table_interaction.get_left_fork().unwrap();
         let right_fork = table_interaction.get_right_fork().unwrap();
-        let mut unit: Box<StateMachine> = Box::new(Eating::new(left_fork, right_fork, table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::new(left_fork, right_fork, table_interaction));
 
         unit = unit.transition();
 
@@ -107,7 +177,7 @@ mod tests {
         let table_interaction = interactions.pop().unwrap();
         let left_fork = table_interaction.get_left_fork().unwrap();
         let right_fork = table_interaction.get_right_fork().unwrap();
-        let mut unit: Box<StateMachine> = Box::new(Eating::new(left_fork, right_fork, table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::new(left_fork, right_fork, table_interaction));
 
         unit = unit.transition(); // to right thinking
         unit = unit.transition(); // back to eating
@@ -118,10 +188,56 @@ mod tests {
     #[test]
     #[should_panic]
     fn cannot_call_transition_twice_on_same_instance() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = Eating::new(Fork, Fork, table_interaction);
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+        let (left_fork, right_fork) = table_interaction.get_both_forks().unwrap();
+        let mut unit = Eating::new(left_fork, right_fork, table_interaction);
 
         unit.transition();
         unit.transition();
     }
+
+    #[test]
+    fn waiting_philosopher_puts_both_forks_down() {
+        let table = Table::new(2);
+        let table_interaction = table.get_interactions().pop().unwrap();
+        let left_fork = table_interaction.get_left_fork().unwrap();
+        let right_fork = table_interaction.get_right_fork().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::with_policy(left_fork, right_fork, table_interaction, Policy::resource_hierarchy()));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(2));
+    }
+
+    #[test]
+    fn right_first_release_changes_to_left_thinking() {
+        let table = Table::new(2);
+        let table_interaction = table.get_interactions().pop().unwrap();
+        let left_fork = table_interaction.get_left_fork().unwrap();
+        let right_fork = table_interaction.get_right_fork().unwrap();
+        let policy = Policy::new(ForkChoice::AlwaysLeft).releasing(Release::RightFirst);
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::with_policy(left_fork, right_fork, table_interaction, policy));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+
+    #[test]
+    fn both_at_once_release_changes_to_thinking() {
+        let table = Table::new(2);
+        let table_interaction = table.get_interactions().pop().unwrap();
+        let left_fork = table_interaction.get_left_fork().unwrap();
+        let right_fork = table_interaction.get_right_fork().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+        let policy = Policy::new(ForkChoice::AlwaysLeft).releasing(Release::BothAtOnce);
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::with_policy(left_fork, right_fork, table_interaction, policy));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(2));
+    }
 }
@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::resource_hierarchy_impl::backoff::{Backoff, Retries};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Philosophers who give way, but back off exponentially before trying again.
+///
+/// Each seat jitters its waits with its own generator, seeded from `seed` and its position.
+pub struct ExponentialBackoff {
+    base: usize,
+    cap: usize,
+    seed: u64,
+    retries: Arc<Mutex<Retries>>,
+}
+
+impl ExponentialBackoff {
+    pub fn new(base: usize, cap: usize, seed: u64) -> ExponentialBackoff {
+        ExponentialBackoff {
+            base,
+            cap,
+            seed,
+            retries: Arc::new(Mutex::new(Retries::default())),
+        }
+    }
+}
+
+impl Strategy for ExponentialBackoff {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let seed = self.seed.wrapping_add(table_interaction.position as u64);
+        let backoff = Backoff::new(self.base, self.cap, seed, Arc::clone(&self.retries));
+        Box::new(Thinking::with_policy(table_interaction, Policy::new(ForkChoice::AlwaysLeft).with_backoff(backoff)))
+    }
+
+    fn counters(&self) -> Counters {
+        let retries = self.retries.lock().unwrap();
+        vec![("meals", retries.meals()), ("retries", retries.total()), ("most retries before a meal", retries.most())]
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::RunResults;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::exponential_backoff::ExponentialBackoff;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::strategy::Strategy;
+
+    /// The forks picked up and put straight back down without a meal.
+    fn dropped_forks(results: &RunResults) -> usize {
+        results.values()
+            .map(|history| history.windows(2)
+                .filter(|w| w[0] != State::Thinking && w[0] != State::Eating && w[1] == State::Thinking)
+                .count())
+            .sum()
+    }
+
+    #[test]
+    fn everyone_eats() {
+        for size in 2..=16 {
+            let results = run_in_lockstep(&mut ExponentialBackoff::new(1, 16, 0), size, 20 * size);
+
+            for (id, history) in &results {
+                assert!(history.contains(&State::Eating), "philosopher {} of {} never ate", id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn churns_less_than_giving_way_at_once() {
+        let mut giving_way = ResourceHierarchy::with_policy(|_| Policy::new(ForkChoice::AlwaysLeft));
+        let mut backing_off = ExponentialBackoff::new(1, 16, 0);
+
+        let churn = dropped_forks(&run_in_lockstep(&mut giving_way, 5, 200));
+        let backed_off = dropped_forks(&run_in_lockstep(&mut backing_off, 5, 200));
+
+        assert!(backed_off < churn, "{} dropped forks with backoff, {} without", backed_off, churn);
+    }
+
+    #[test]
+    fn counts_retries_for_every_meal() {
+        let mut unit = ExponentialBackoff::new(1, 16, 0);
+        let results = run_in_lockstep(&mut unit, 5, 100);
+
+        let counters = unit.counters();
+        let meals_finished: usize = results.values()
+            .map(|history| history.windows(2).filter(|w| w[0] == State::Eating && w[1] != State::Eating).count())
+            .sum();
+
+        assert_eq!(counters[0], ("meals", meals_finished));
+        assert_eq!(counters.iter().map(|(name, _)| *name).collect::<Vec<_>>(), vec!["meals", "retries", "most retries before a meal"]);
+    }
+
+    #[test]
+    fn is_reproducible_from_seed() {
+        let run = || run_in_lockstep(&mut ExponentialBackoff::new(2, 32, 4), 5, 100);
+
+        assert_eq!(run(), run());
+    }
+}
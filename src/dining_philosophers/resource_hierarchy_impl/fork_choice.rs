@@ -0,0 +1,179 @@
+use rand::Rng;
+
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::table::TableInteraction;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub fn other(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// Decides which fork a thinking philosopher reaches for first.
+#[derive(Debug, PartialEq, Default)]
+pub enum ForkChoice {
+    #[default]
+    AlwaysLeft,
+    AlwaysRight,
+    /// Holds the side that will be tried next.
+    Alternating(Side),
+    Random(Box<SeededRng>),
+    /// Dijkstra's resource hierarchy: the lower-numbered fork is always taken first.
+    LowestNumbered,
+    /// Even seats take the left fork first and odd seats the right.
+    ///
+    /// At an odd table size the last seat keeps its parity, so it and seat 0 both reach left
+    /// first. The last seat then competes with seat N-2 for their shared fork N-1 as its first
+    /// fork, so the ring of first forks is still broken.
+    OddEven,
+}
+
+impl ForkChoice {
+    pub fn alternating() -> ForkChoice {
+        ForkChoice::Alternating(Side::Left)
+    }
+
+    pub fn random(seed: u64) -> ForkChoice {
+        ForkChoice::Random(Box::new(SeededRng::new(seed)))
+    }
+
+    pub fn first_fork(&mut self, table_interaction: &TableInteraction) -> Side {
+        match self {
+            ForkChoice::AlwaysLeft => Side::Left,
+            ForkChoice::AlwaysRight => Side::Right,
+            ForkChoice::Alternating(next) => {
+                let side = *next;
+                *next = side.other();
+                side
+            }
+            ForkChoice::Random(rng) => {
+                if rng.gen_bool(0.5) { Side::Left } else { Side::Right }
+            }
+            ForkChoice::LowestNumbered => {
+                if table_interaction.left_fork_number() <= table_interaction.right_fork_number() { Side::Left } else { Side::Right }
+            }
+            ForkChoice::OddEven => {
+                if table_interaction.position.is_multiple_of(2) { Side::Left } else { Side::Right }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::{ForkChoice, Side};
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+
+    fn seat(size: usize, position: usize) -> TableInteraction {
+        Table::new(size).get_interactions().remove(position)
+    }
+
+    #[test]
+    fn always_left_picks_left() {
+        let t = seat(2, 0);
+        let mut unit = ForkChoice::AlwaysLeft;
+
+        assert_eq!(unit.first_fork(&t), Side::Left);
+        assert_eq!(unit.first_fork(&t), Side::Left);
+    }
+
+    #[test]
+    fn always_right_picks_right() {
+        let t = seat(2, 0);
+        let mut unit = ForkChoice::AlwaysRight;
+
+        assert_eq!(unit.first_fork(&t), Side::Right);
+        assert_eq!(unit.first_fork(&t), Side::Right);
+    }
+
+    #[test]
+    fn alternating_switches_sides() {
+        let t = seat(2, 0);
+        let mut unit = ForkChoice::alternating();
+
+        assert_eq!(unit.first_fork(&t), Side::Left);
+        assert_eq!(unit.first_fork(&t), Side::Right);
+        assert_eq!(unit.first_fork(&t), Side::Left);
+    }
+
+    #[test]
+    fn random_is_reproducible_from_seed() {
+        let t = seat(2, 0);
+        let mut one = ForkChoice::random(7);
+        let mut two = ForkChoice::random(7);
+
+        let first: Vec<Side> = (0..20).map(|_| one.first_fork(&t)).collect();
+        let second: Vec<Side> = (0..20).map(|_| two.first_fork(&t)).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn random_picks_both_sides() {
+        let t = seat(2, 0);
+        let mut unit = ForkChoice::random(7);
+
+        let sides: Vec<Side> = (0..50).map(|_| unit.first_fork(&t)).collect();
+
+        assert!(sides.contains(&Side::Left));
+        assert!(sides.contains(&Side::Right));
+    }
+
+    #[test]
+    fn lowest_numbered_picks_left_before_the_end_of_the_table() {
+        let t = seat(5, 3);
+        let mut unit = ForkChoice::LowestNumbered;
+
+        assert_eq!(unit.first_fork(&t), Side::Left);
+    }
+
+    #[test]
+    fn lowest_numbered_picks_fork_zero_first_at_the_last_seat() {
+        let t = seat(5, 4);
+        let mut unit = ForkChoice::LowestNumbered;
+
+        assert_eq!(unit.first_fork(&t), Side::Right);
+    }
+
+    #[test]
+    fn odd_even_picks_left_at_even_seats_and_right_at_odd_seats() {
+        let mut unit = ForkChoice::OddEven;
+
+        assert_eq!(unit.first_fork(&seat(4, 0)), Side::Left);
+        assert_eq!(unit.first_fork(&seat(4, 1)), Side::Right);
+        assert_eq!(unit.first_fork(&seat(4, 2)), Side::Left);
+        assert_eq!(unit.first_fork(&seat(4, 3)), Side::Right);
+    }
+
+    #[test]
+    fn odd_even_picks_left_at_the_last_seat_of_an_odd_table() {
+        let mut unit = ForkChoice::OddEven;
+
+        assert_eq!(unit.first_fork(&seat(5, 4)), Side::Left);
+    }
+
+    #[test]
+    fn odd_even_first_forks_never_form_a_ring() {
+        for size in 2..=64 {
+            let mut first_forks: Vec<usize> = Table::new(size).get_interactions().iter()
+                .map(|t| match ForkChoice::OddEven.first_fork(t) {
+                    Side::Left => t.left_fork_number(),
+                    Side::Right => t.right_fork_number(),
+                })
+                .collect();
+            first_forks.sort();
+            first_forks.dedup();
+
+            assert!(first_forks.len() < size, "every philosopher of {} can hold a first fork", size);
+        }
+    }
+}
@@ -1,40 +1,59 @@
-use crate::dining_philosophers::fork::Fork;
+use crate::dining_philosophers::fork::{keep_refused, Fork};
 use crate::dining_philosophers::table::TableInteraction;
 use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
 use crate::dining_philosophers::philosopher::state_machine::{StateMachine, State};
 use crate::dining_philosophers::resource_hierarchy_impl::eating::Eating;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::{Contention, Policy};
 
 #[derive(Debug, PartialEq)]
 pub struct LeftThinking {
     left_fork: Option<Fork>,
     table_interaction: Option<TableInteraction>,
+    policy: Option<Policy>,
 }
 
 impl LeftThinking {
     pub fn new(left_fork: Fork, table_interaction: TableInteraction) -> LeftThinking {
+        LeftThinking::with_policy(left_fork, table_interaction, Policy::default())
+    }
+    pub fn with_policy(left_fork: Fork, table_interaction: TableInteraction, policy: Policy) -> LeftThinking {
         LeftThinking {
             left_fork: Some(left_fork),
             table_interaction: Some(table_interaction),
+            policy: Some(policy),
         }
     }
     fn take_right(&mut self, fork: Fork, table_interaction: TableInteraction) -> Eating {
-        Eating::new(self.left_fork.take().unwrap(), fork, table_interaction)
+        Eating::with_policy(self.left_fork.take().unwrap(), fork, table_interaction, self.policy.take().unwrap())
+    }
+    /// Back to thinking, unless the table refuses the left fork and the philosopher keeps it.
+    fn drop_left(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let refused = table_interaction.return_left_fork(self.left_fork.take().unwrap());
+        if let Some(fork) = keep_refused(table_interaction.position, refused) {
+            return Box::new(LeftThinking::with_policy(fork, table_interaction, self.policy.take().unwrap()));
+        }
+        let mut policy = self.policy.take().unwrap();
+        policy.failed();
+        Box::new(Thinking::with_policy(table_interaction, policy))
     }
-    fn drop_left(&mut self, table_interaction: TableInteraction) -> Thinking {
-        table_interaction.return_left_fork(self.left_fork.take().unwrap());
-        Thinking::new(table_interaction)
+    fn keep_waiting(&mut self, table_interaction: TableInteraction) -> LeftThinking {
+        LeftThinking::with_policy(self.left_fork.take().unwrap(), table_interaction, self.policy.take().unwrap())
     }
 }
 
 impl StateMachine for LeftThinking {
-    fn transition(&mut self) -> Box<StateMachine + Send> {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
         match self.table_interaction.take() {
             None => { panic!("No longer valid") }
             Some(t) => {
                 match t.get_right_fork() {
+                    None if self.policy.as_ref().unwrap().contention == Contention::Wait => {
+                        debug!("{}: Not right, waiting", t.position);
+                        Box::new(self.keep_waiting(t))
+                    }
                     None => {
                         debug!("{}: Not right, back to thinking", t.position);
-                        Box::new(self.drop_left(t))
+                        self.drop_left(t)
                     }
                     Some(fork) => {
                         debug!("{}: Got right. Eating!", t.position);
@@ -57,35 +76,56 @@ mod tests {
     use crate::dining_philosophers::fork::Fork;
     use crate::dining_philosophers::resource_hierarchy_impl::left_thinking::LeftThinking;
     use crate::dining_philosophers::table::{Table, TableInteraction};
-    use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
     use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
     use crate::dining_philosophers::resource_hierarchy_impl::eating::Eating;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+
+    /// A fork as philosopher 0 holds it, for states that never put it back.
+    fn held(id: usize) -> Fork {
+        let mut fork = Fork::new(id);
+        fork.pick_up(0);
+        fork
+    }
 
     #[test]
     fn take_right_becomes_eating() {
         let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
 
-        let mut unit = LeftThinking { left_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let mut unit = LeftThinking { left_fork: Some(held(0)), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
 
-        assert_eq!(unit.take_right(Fork, table_interaction), Eating::new(Fork, Fork, TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.take_right(held(1), table_interaction), Eating::new(held(0), held(1), TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
     }
 
     #[test]
     fn drop_left_becomes_thinking() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = LeftThinking { left_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let fork = table_interaction.get_left_fork().unwrap();
+        let mut unit = LeftThinking { left_fork: Some(fork), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
+        let table = Arc::clone(&table_interaction.table);
 
         let unit = unit.drop_left(table_interaction);
 
-        assert_eq!(unit, Thinking::new(TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(1));
+    }
+
+    #[test]
+    fn keeps_a_left_fork_the_table_refuses() {
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let mut unit = LeftThinking { left_fork: Some(held(0)), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
+        let table_interaction = unit.table_interaction.take().unwrap();
+
+        let unit = unit.drop_left(table_interaction);
+
+        assert_eq!(unit.state(), State::LeftThinking);
     }
 
     #[test]
     fn state_is_left_thinking() {
         let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let unit = LeftThinking::new(Fork, table_interaction);
+        let unit = LeftThinking::new(held(0), table_interaction);
 
         assert_eq!(unit.state(), State::LeftThinking);
     }
@@ -95,7 +135,7 @@ mod tests {
         let table = Table::new(2);
         let table_interaction = table.get_interactions().pop().unwrap();
         let fork = table_interaction.get_left_fork().unwrap();
-        let mut unit: Box<StateMachine> = Box::new(LeftThinking::new(fork, table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(LeftThinking::new(fork, table_interaction));
 
         unit = unit.transition();
 
@@ -106,7 +146,7 @@ mod tests {
     fn changes_to_thinking_when_right_fork_is_not_available() {
         let table_interaction = Table::new(1).get_interactions().pop().unwrap();
         let mut fork = table_interaction.get_left_fork();
-        let mut unit: Box<StateMachine> = Box::new(LeftThinking::new(fork.take().unwrap(), table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(LeftThinking::new(fork.take().unwrap(), table_interaction));
 
         unit = unit.transition();
 
@@ -117,7 +157,7 @@ mod tests {
     fn returns_left_fork_when_right_fork_is_not_available() {
         let table_interaction = Table::new(1).get_interactions().pop().unwrap();
         let mut fork = table_interaction.get_left_fork();
-        let mut unit: Box<StateMachine> = Box::new(LeftThinking::new(fork.take().unwrap(), table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(LeftThinking::new(fork.take().unwrap(), table_interaction));
 
         unit = unit.transition();
         unit = unit.transition();
@@ -128,10 +168,24 @@ mod tests {
     #[test]
     #[should_panic]
     fn cannot_call_transition_twice_on_same_instance() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = LeftThinking::new(Fork, table_interaction);
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let fork = table_interaction.get_left_fork().unwrap();
+        let mut unit = LeftThinking::new(fork, table_interaction);
 
         unit.transition();
         unit.transition();
     }
+
+    #[test]
+    fn keeps_left_fork_when_waiting_for_right() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_left_fork().unwrap();
+        let policy = Policy::default().waiting();
+        let mut unit: Box<dyn StateMachine> = Box::new(LeftThinking::with_policy(fork, table_interaction, policy));
+
+        unit = unit.transition();
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
 }
@@ -2,3 +2,10 @@ pub mod eating;
 pub mod thinking;
 pub mod left_thinking;
 pub mod right_thinking;
+pub mod ordered;
+pub mod fork_choice;
+pub mod release;
+pub mod backoff;
+pub mod policy;
+pub mod resource_hierarchy;
+pub mod exponential_backoff;
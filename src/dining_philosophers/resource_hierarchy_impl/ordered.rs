@@ -0,0 +1,133 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Dijkstra's resource hierarchy for a philosopher who needs any number of forks.
+///
+/// The forks are taken one step at a time in ascending order of their numbers, waiting for each
+/// one in turn, and all of them go down after one step of eating. Holding only lower-numbered
+/// forks while waiting for a higher one, no ring of waiting philosophers can form.
+#[derive(Debug)]
+pub struct Ordered {
+    held: Option<Vec<Fork>>,
+    table_interaction: Option<TableInteraction>,
+}
+
+impl Ordered {
+    pub fn new(table_interaction: TableInteraction) -> Ordered {
+        Ordered::holding(Vec::new(), table_interaction)
+    }
+
+    fn holding(held: Vec<Fork>, table_interaction: TableInteraction) -> Ordered {
+        Ordered {
+            held: Some(held),
+            table_interaction: Some(table_interaction),
+        }
+    }
+
+    /// The forks needed, lowest number first.
+    fn order(table_interaction: &TableInteraction) -> Vec<usize> {
+        let mut numbers = table_interaction.fork_numbers();
+        numbers.sort_unstable();
+        numbers
+    }
+}
+
+impl StateMachine for Ordered {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match (self.table_interaction.take(), self.held.take()) {
+            (Some(t), Some(mut held)) => {
+                let order = Ordered::order(&t);
+                if held.len() == order.len() {
+                    debug!("{}: Putting down {} forks, to thinking", t.position, held.len());
+                    return match keep_refused(t.position, t.return_forks(&order, held)) {
+                        Some(held) => Box::new(Ordered::holding(held, t)),
+                        None => Box::new(Ordered::new(t)),
+                    };
+                }
+                let next = order[held.len()];
+                match t.get_forks(&[next]) {
+                    Some(mut fork) => {
+                        held.append(&mut fork);
+                        match held.len() == order.len() {
+                            true => debug!("{}: Got fork {}, the last one. Eating!", t.position, next),
+                            false => debug!("{}: Got fork {}", t.position, next),
+                        }
+                    }
+                    None => debug!("{}: Fork {} in use, waiting", t.position, next),
+                }
+                Box::new(Ordered::holding(held, t))
+            }
+            _ => { panic!("No longer valid") }
+        }
+    }
+
+    /// Eating once every fork is in hand, and reported like a philosopher holding its first fork
+    /// while it holds only some of them.
+    fn state(&self) -> State {
+        match (&self.held, &self.table_interaction) {
+            (Some(held), Some(t)) if held.len() == t.fork_numbers().len() => State::Eating,
+            (Some(held), _) if !held.is_empty() => State::LeftThinking,
+            _ => State::Thinking,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::resource_hierarchy_impl::ordered::Ordered;
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn takes_one_fork_a_step_and_eats_with_all_of_them() {
+        let mut spokes = Table::with_topology(Topology::star(3)).get_interactions();
+        let mut centre: Box<dyn StateMachine> = Box::new(Ordered::new(spokes.remove(0)));
+
+        centre = centre.transition();
+        assert_eq!(centre.state(), State::LeftThinking);
+        assert!(!spokes[0].left_fork_on_table());
+        assert!(spokes[1].left_fork_on_table());
+
+        centre = centre.transition();
+        assert_eq!(centre.state(), State::Eating);
+        assert!(spokes.iter().all(|spoke| !spoke.left_fork_on_table()));
+
+        centre = centre.transition();
+        assert_eq!(centre.state(), State::Thinking);
+        assert!(spokes.iter().all(|spoke| spoke.left_fork_on_table()));
+    }
+
+    #[test]
+    fn waits_for_a_fork_in_use_while_holding_the_lower_ones() {
+        let mut table_interactions = Table::with_topology(Topology::star(3)).get_interactions();
+        let _fork = table_interactions[2].get_all_forks();
+        let mut centre: Box<dyn StateMachine> = Box::new(Ordered::new(table_interactions.remove(0)));
+
+        centre = centre.transition();
+        centre = centre.transition();
+
+        assert_eq!(centre.state(), State::LeftThinking);
+        assert!(!table_interactions[0].left_fork_on_table());
+    }
+
+    #[test]
+    fn eats_with_a_single_fork() {
+        let mut ends = Table::with_topology(Topology::line(2)).get_interactions();
+        let mut unit: Box<dyn StateMachine> = Box::new(Ordered::new(ends.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Ordered::new(Table::new(2).get_interactions().remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,148 @@
+use crate::dining_philosophers::resource_hierarchy_impl::backoff::Backoff;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+use crate::dining_philosophers::resource_hierarchy_impl::release::Release;
+
+/// What a philosopher does when the fork it reaches for is taken.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Contention {
+    /// Try the other fork when thinking, or put the held fork back down.
+    #[default]
+    GiveWay,
+    /// Keep whatever is held and reach for the same fork again on the next step.
+    Wait,
+}
+
+/// The decisions a philosopher makes along the way, handed from state to state.
+#[derive(Debug, PartialEq, Default)]
+pub struct Policy {
+    pub fork_choice: ForkChoice,
+    pub contention: Contention,
+    pub release: Release,
+    pub backoff: Option<Backoff>,
+}
+
+impl Policy {
+    pub fn new(fork_choice: ForkChoice) -> Policy {
+        Policy {
+            fork_choice,
+            contention: Contention::GiveWay,
+            release: Release::LeftFirst,
+            backoff: None,
+        }
+    }
+
+    /// Dijkstra's solution: take the lower-numbered fork first and wait for the other.
+    ///
+    /// Both forks go down after a meal: a waiting philosopher that kept one would starve the
+    /// neighbour waiting on it.
+    pub fn resource_hierarchy() -> Policy {
+        Policy {
+            fork_choice: ForkChoice::LowestNumbered,
+            contention: Contention::Wait,
+            release: Release::BothAtOnce,
+            backoff: None,
+        }
+    }
+
+    /// Even seats take the left fork first, odd seats the right, and both wait for the other.
+    pub fn odd_even() -> Policy {
+        Policy {
+            fork_choice: ForkChoice::OddEven,
+            contention: Contention::Wait,
+            release: Release::BothAtOnce,
+            backoff: None,
+        }
+    }
+
+    pub fn waiting(self) -> Policy {
+        Policy { contention: Contention::Wait, ..self }
+    }
+
+    pub fn releasing(self, release: Release) -> Policy {
+        Policy { release, ..self }
+    }
+
+    pub fn with_backoff(self, backoff: Backoff) -> Policy {
+        Policy { backoff: Some(backoff), ..self }
+    }
+
+    /// Records a failed attempt to get both forks.
+    pub fn failed(&mut self) {
+        if let Some(backoff) = self.backoff.as_mut() {
+            backoff.failed();
+        }
+    }
+
+    pub fn ate(&mut self) {
+        if let Some(backoff) = self.backoff.as_mut() {
+            backoff.ate();
+        }
+    }
+
+    /// Whether a thinking philosopher is still backing off and should not reach for a fork yet.
+    pub fn sitting_out(&mut self) -> bool {
+        self.backoff.as_mut().is_some_and(|backoff| backoff.sitting_out())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::RunResults;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+
+    fn everyone_has_eaten(results: &RunResults) -> bool {
+        results.values().all(|history| history.contains(&State::Eating))
+    }
+
+    #[test]
+    fn resource_hierarchy_never_deadlocks() {
+        for size in 2..=32 {
+            let results = run_in_lockstep(&mut ResourceHierarchy::new(), size, 4 * size);
+
+            assert!(everyone_has_eaten(&results), "deadlock with {} philosophers", size);
+        }
+    }
+
+    #[test]
+    fn resource_hierarchy_keeps_eating_after_everyone_has_eaten_once() {
+        let results = run_in_lockstep(&mut ResourceHierarchy::new(), 5, 200);
+
+        for (id, history) in &results {
+            let meals_at_the_end = history[100..].iter().filter(|s| **s == State::Eating).count();
+            assert!(meals_at_the_end > 0, "philosopher {} stopped eating", id);
+        }
+    }
+
+    #[test]
+    fn odd_even_never_deadlocks() {
+        for size in 2..=64 {
+            let results = run_in_lockstep(&mut ResourceHierarchy::odd_even(), size, 4 * size);
+
+            assert!(everyone_has_eaten(&results), "deadlock with {} philosophers", size);
+        }
+    }
+
+    #[test]
+    fn odd_even_keeps_eating_at_an_odd_table() {
+        let results = run_in_lockstep(&mut ResourceHierarchy::odd_even(), 7, 200);
+
+        for (id, history) in &results {
+            let meals_at_the_end = history[100..].iter().filter(|s| **s == State::Eating).count();
+            assert!(meals_at_the_end > 0, "philosopher {} stopped eating", id);
+        }
+    }
+
+    #[test]
+    fn waiting_for_the_left_fork_first_deadlocks() {
+        let mut strategy = ResourceHierarchy::with_policy(|_| Policy::new(ForkChoice::AlwaysLeft).waiting());
+
+        let results = run_in_lockstep(&mut strategy, 5, 20);
+
+        assert!(!everyone_has_eaten(&results));
+        assert!(results.values().all(|history| history.last() == Some(&State::LeftThinking)));
+    }
+}
@@ -0,0 +1,81 @@
+use rand::Rng;
+
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+
+/// Decides how a philosopher puts its forks down after eating.
+#[derive(Debug, PartialEq, Default)]
+pub enum Release {
+    /// Put the left fork down and keep the right, going on to `RightThinking`.
+    #[default]
+    LeftFirst,
+    /// Put the right fork down and keep the left, going on to `LeftThinking`.
+    RightFirst,
+    /// Put both forks down and go straight back to `Thinking`.
+    BothAtOnce,
+    /// One of the three above, picked afresh after every meal.
+    Random(Box<SeededRng>),
+}
+
+impl Release {
+    pub fn random(seed: u64) -> Release {
+        Release::Random(Box::new(SeededRng::new(seed)))
+    }
+
+    /// The fork to put down first, or `None` to put both down at once.
+    pub fn first_fork(&mut self) -> Option<Side> {
+        match self {
+            Release::LeftFirst => Some(Side::Left),
+            Release::RightFirst => Some(Side::Right),
+            Release::BothAtOnce => None,
+            Release::Random(rng) => match rng.gen_range(0, 3) {
+                0 => Some(Side::Left),
+                1 => Some(Side::Right),
+                _ => None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
+    use crate::dining_philosophers::resource_hierarchy_impl::release::Release;
+
+    #[test]
+    fn left_first_puts_left_down() {
+        assert_eq!(Release::LeftFirst.first_fork(), Some(Side::Left));
+    }
+
+    #[test]
+    fn right_first_puts_right_down() {
+        assert_eq!(Release::RightFirst.first_fork(), Some(Side::Right));
+    }
+
+    #[test]
+    fn both_at_once_puts_both_down() {
+        assert_eq!(Release::BothAtOnce.first_fork(), None);
+    }
+
+    #[test]
+    fn random_is_reproducible_from_seed() {
+        let mut one = Release::random(3);
+        let mut two = Release::random(3);
+
+        let first: Vec<Option<Side>> = (0..20).map(|_| one.first_fork()).collect();
+        let second: Vec<Option<Side>> = (0..20).map(|_| two.first_fork()).collect();
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn random_uses_every_release() {
+        let mut unit = Release::random(3);
+
+        let releases: Vec<Option<Side>> = (0..50).map(|_| unit.first_fork()).collect();
+
+        assert!(releases.contains(&Some(Side::Left)));
+        assert!(releases.contains(&Some(Side::Right)));
+        assert!(releases.contains(&None));
+    }
+}
@@ -0,0 +1,109 @@
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::resource_hierarchy_impl::ordered::Ordered;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// Builds the policy of the philosopher at the given seat.
+pub type NewPolicy = fn(usize) -> Policy;
+
+/// Seats every philosopher in `Thinking` with the policy built for its position.
+pub struct ResourceHierarchy {
+    policy: NewPolicy,
+    /// Whether philosophers who do not need exactly two forks take theirs in `Ordered`.
+    ordered: bool,
+}
+
+impl ResourceHierarchy {
+    /// Dijkstra's solution, which takes the lowest-numbered fork first. It sits at any topology:
+    /// a philosopher who needs more or fewer than two forks takes them all in ascending order.
+    pub fn new() -> ResourceHierarchy {
+        ResourceHierarchy {
+            ordered: true,
+            ..ResourceHierarchy::with_policy(|_| Policy::resource_hierarchy())
+        }
+    }
+
+    /// The asymmetric solution: even seats reach left first and odd seats right.
+    pub fn odd_even() -> ResourceHierarchy {
+        ResourceHierarchy::with_policy(|_| Policy::odd_even())
+    }
+
+    pub fn with_policy(policy: NewPolicy) -> ResourceHierarchy {
+        ResourceHierarchy {
+            policy,
+            ordered: false,
+        }
+    }
+}
+
+impl Default for ResourceHierarchy {
+    fn default() -> ResourceHierarchy {
+        ResourceHierarchy::new()
+    }
+}
+
+impl Strategy for ResourceHierarchy {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        if self.ordered && table_interaction.fork_numbers().len() != 2 {
+            return Box::new(Ordered::new(table_interaction));
+        }
+        let policy = (self.policy)(table_interaction.position);
+        Box::new(Thinking::with_policy(table_interaction, policy))
+    }
+
+    /// Forks are ordered by their number rather than by seat, so no cycle of waiting philosophers
+    /// can form at any table where everyone needs two forks, or at any table at all when every
+    /// fork is taken in that order.
+    fn fits(&self, topology: &Topology) -> bool {
+        self.ordered || topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::score_one_run;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep_on;
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn every_philosopher_eats_at_two_separate_tables() {
+        let topology = Topology::new(6, vec![vec![0, 1], vec![1, 2], vec![2, 0], vec![3, 4], vec![4, 5], vec![5, 3]]);
+
+        let results = run_in_lockstep_on(&mut ResourceHierarchy::new(), &topology, 60);
+
+        assert!(results.values().all(|history| history.contains(&State::Eating)));
+    }
+
+    #[test]
+    fn fits_every_topology_but_other_policies_need_pairs() {
+        assert!(ResourceHierarchy::new().fits(&Topology::star(4)));
+        assert!(!ResourceHierarchy::odd_even().fits(&Topology::star(4)));
+        assert!(ResourceHierarchy::odd_even().fits(&Topology::ring(4)));
+    }
+
+    #[test]
+    fn neighbours_never_eat_together_on_any_topology() {
+        for topology in &[Topology::star(5), Topology::grid(2, 3), Topology::complete(4), Topology::line(4)] {
+            let results = run_in_lockstep_on(&mut ResourceHierarchy::new(), topology, 60);
+
+            for (a, b) in (0..topology.forks()).map(|fork| topology.sharing(fork)).filter(|pair| pair.len() == 2).map(|pair| (pair[0], pair[1])) {
+                let both = results[&a].iter().zip(&results[&b]).any(|(x, y)| *x == State::Eating && *y == State::Eating);
+                assert!(!both, "{:?}: {} and {} ate together", topology, a, b);
+            }
+            assert!(score_one_run(&results) > 0.0);
+        }
+    }
+
+    #[test]
+    fn every_philosopher_eats_round_a_star() {
+        let results = run_in_lockstep_on(&mut ResourceHierarchy::new(), &Topology::star(5), 100);
+
+        assert!(results.values().all(|history| history.contains(&State::Eating)));
+    }
+}
@@ -1,37 +1,55 @@
 use crate::dining_philosophers::resource_hierarchy_impl::eating::Eating;
-use crate::dining_philosophers::fork::Fork;
+use crate::dining_philosophers::fork::{keep_refused, Fork};
 use crate::dining_philosophers::table::TableInteraction;
 use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
 use crate::dining_philosophers::philosopher::state_machine::{StateMachine, State};
+use crate::dining_philosophers::resource_hierarchy_impl::policy::{Contention, Policy};
 
 #[derive(Debug, PartialEq)]
 pub struct RightThinking {
     right_fork: Option<Fork>,
     table_interaction: Option<TableInteraction>,
+    policy: Option<Policy>,
 }
 
 impl RightThinking {
     pub fn new(right_fork: Fork, table_interaction: TableInteraction) -> RightThinking {
-        RightThinking { right_fork: Some(right_fork), table_interaction: Some(table_interaction) }
+        RightThinking::with_policy(right_fork, table_interaction, Policy::default())
+    }
+    pub fn with_policy(right_fork: Fork, table_interaction: TableInteraction, policy: Policy) -> RightThinking {
+        RightThinking { right_fork: Some(right_fork), table_interaction: Some(table_interaction), policy: Some(policy) }
     }
     fn take_left(&mut self, fork: Fork, table_interaction: TableInteraction) -> Eating {
-        Eating::new(fork, self.right_fork.take().unwrap(), table_interaction)
+        Eating::with_policy(fork, self.right_fork.take().unwrap(), table_interaction, self.policy.take().unwrap())
+    }
+    /// Back to thinking, unless the table refuses the right fork and the philosopher keeps it.
+    fn drop_right(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let refused = table_interaction.return_right_fork(self.right_fork.take().unwrap());
+        if let Some(fork) = keep_refused(table_interaction.position, refused) {
+            return Box::new(RightThinking::with_policy(fork, table_interaction, self.policy.take().unwrap()));
+        }
+        let mut policy = self.policy.take().unwrap();
+        policy.failed();
+        Box::new(Thinking::with_policy(table_interaction, policy))
     }
-    fn drop_right(&mut self, table_interaction: TableInteraction) -> Thinking {
-        table_interaction.return_right_fork(self.right_fork.take().unwrap());
-        Thinking::new(table_interaction)
+    fn keep_waiting(&mut self, table_interaction: TableInteraction) -> RightThinking {
+        RightThinking::with_policy(self.right_fork.take().unwrap(), table_interaction, self.policy.take().unwrap())
     }
 }
 
 impl StateMachine for RightThinking {
-    fn transition(&mut self) -> Box<StateMachine + Send> {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
         match self.table_interaction.take() {
             None => { panic!("No longer valid") }
             Some(t) => {
                 match t.get_left_fork() {
+                    None if self.policy.as_ref().unwrap().contention == Contention::Wait => {
+                        debug!("{}: Not left, waiting", t.position);
+                        Box::new(self.keep_waiting(t))
+                    }
                     None => {
                         debug!("{}: Not left, back to thinking", t.position);
-                        Box::new(self.drop_right(t))
+                        self.drop_right(t)
                     }
                     Some(fork) => {
                         debug!("{}: Got left. Eating!", t.position);
@@ -54,34 +72,44 @@ mod tests {
     use crate::dining_philosophers::fork::Fork;
     use crate::dining_philosophers::resource_hierarchy_impl::right_thinking::RightThinking;
     use crate::dining_philosophers::table::{Table, TableInteraction};
-    use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
     use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
     use crate::dining_philosophers::resource_hierarchy_impl::eating::Eating;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+
+    /// A fork as philosopher 0 holds it, for states that never put it back.
+    fn held(id: usize) -> Fork {
+        let mut fork = Fork::new(id);
+        fork.pick_up(0);
+        fork
+    }
 
     #[test]
     fn right_thinking_take_left_becomes_eating() {
         let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = RightThinking { right_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let mut unit = RightThinking { right_fork: Some(held(1)), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
 
-        assert_eq!(unit.take_left(Fork, table_interaction), Eating::new(Fork, Fork, TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.take_left(held(0), table_interaction), Eating::new(held(0), held(1), TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
     }
 
     #[test]
     fn right_thinking_drop_right_becomes_thinking() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = RightThinking { right_fork: Some(Fork), table_interaction: Some(table_interaction) };
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let fork = table_interaction.get_right_fork().unwrap();
+        let mut unit = RightThinking { right_fork: Some(fork), table_interaction: Some(table_interaction), policy: Some(Policy::default()) };
         let table_interaction = unit.table_interaction.take().unwrap();
+        let table = Arc::clone(&table_interaction.table);
 
         let unit = unit.drop_right(table_interaction);
 
-        assert_eq!(unit, Thinking::new(TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) }));
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(1));
     }
 
     #[test]
     fn state_is_right_thinking() {
         let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let unit = RightThinking::new(Fork, table_interaction);
+        let unit = RightThinking::new(held(0), table_interaction);
 
         assert_eq!(unit.state(), State::RightThinking);
     }
@@ -91,7 +119,7 @@ mod tests {
         let table = Table::new(2);
         let table_interaction = table.get_interactions().pop().unwrap();
         let fork = table_interaction.get_right_fork().unwrap();
-        let mut unit: Box<StateMachine> = Box::new(RightThinking::new(fork, table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(RightThinking::new(fork, table_interaction));
 
         unit = unit.transition();
 
@@ -103,7 +131,7 @@ mod tests {
         let table = Table::new(1);
         let table_interaction = table.get_interactions().pop().unwrap();
         let mut fork = table_interaction.get_right_fork();
-        let mut unit: Box<StateMachine> = Box::new(RightThinking::new(fork.take().unwrap(), table_interaction));
+        let mut unit: Box<dyn StateMachine> = Box::new(RightThinking::new(fork.take().unwrap(), table_interaction));
 
         unit = unit.transition();
 
@@ -113,8 +141,8 @@ mod tests {
     #[test]
     fn returns_right_fork_when_left_fork_is_not_available() {
         let table_interaction = Table::new(1).get_interactions().pop().unwrap();
-        let fork = table_interaction.get_right_fork().take().unwrap();
-        let mut unit: Box<StateMachine> = Box::new(RightThinking::new(fork, table_interaction));
+        let fork = table_interaction.get_right_fork().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(RightThinking::new(fork, table_interaction));
 
         unit = unit.transition();
         unit = unit.transition();
@@ -125,10 +153,24 @@ mod tests {
     #[test]
     #[should_panic]
     fn cannot_call_transition_twice_on_same_instance() {
-        let table_interaction = TableInteraction { position: 0, table: Arc::new(Mutex::new(Table::new(1))) };
-        let mut unit = RightThinking::new(Fork, table_interaction);
+        let table_interaction = Table::new(1).get_interactions().remove(0);
+        let fork = table_interaction.get_right_fork().unwrap();
+        let mut unit = RightThinking::new(fork, table_interaction);
 
         unit.transition();
         unit.transition();
     }
+
+    #[test]
+    fn keeps_right_fork_when_waiting_for_left() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_right_fork().unwrap();
+        let policy = Policy::default().waiting();
+        let mut unit: Box<dyn StateMachine> = Box::new(RightThinking::with_policy(fork, table_interaction, policy));
+
+        unit = unit.transition();
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::RightThinking);
+    }
 }
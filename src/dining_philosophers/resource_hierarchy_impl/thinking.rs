@@ -1,23 +1,83 @@
 use crate::dining_philosophers::fork::Fork;
 use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::Side;
 use crate::dining_philosophers::resource_hierarchy_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::resource_hierarchy_impl::policy::{Contention, Policy};
 use crate::dining_philosophers::resource_hierarchy_impl::right_thinking::RightThinking;
 use crate::dining_philosophers::table::TableInteraction;
 
 #[derive(Debug, PartialEq)]
 pub struct Thinking {
-    table_interaction: Option<TableInteraction>
+    table_interaction: Option<TableInteraction>,
+    policy: Option<Policy>,
 }
 
 impl Thinking {
     pub fn new(table_interaction: TableInteraction) -> Thinking {
-        unimplemented!()
+        Thinking::with_policy(table_interaction, Policy::default())
+    }
+
+    pub fn with_policy(table_interaction: TableInteraction, policy: Policy) -> Thinking {
+        Thinking {
+            table_interaction: Some(table_interaction),
+            policy: Some(policy),
+        }
+    }
+
+    fn take_left(&mut self, fork: Fork, table_interaction: TableInteraction) -> LeftThinking {
+        LeftThinking::with_policy(fork, table_interaction, self.policy.take().unwrap())
+    }
+    fn take_right(&mut self, fork: Fork, table_interaction: TableInteraction) -> RightThinking {
+        RightThinking::with_policy(fork, table_interaction, self.policy.take().unwrap())
+    }
+    fn keep_thinking(&mut self, table_interaction: TableInteraction) -> Thinking {
+        Thinking::with_policy(table_interaction, self.policy.take().unwrap())
+    }
+
+    fn try_side(&mut self, side: Side, table_interaction: TableInteraction) -> Result<Box<dyn StateMachine + Send>, TableInteraction> {
+        match side {
+            Side::Left => match table_interaction.get_left_fork() {
+                None => Err(table_interaction),
+                Some(fork) => {
+                    debug!("{}: Got left, to left thinking", table_interaction.position);
+                    Ok(Box::new(self.take_left(fork, table_interaction)))
+                }
+            },
+            Side::Right => match table_interaction.get_right_fork() {
+                None => Err(table_interaction),
+                Some(fork) => {
+                    debug!("{}: Got right, to right thinking", table_interaction.position);
+                    Ok(Box::new(self.take_right(fork, table_interaction)))
+                }
+            },
+        }
     }
 }
 
 impl StateMachine for Thinking {
-    fn transition(&mut self) -> Box<StateMachine + Send> {
-        unimplemented!()
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.table_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(t) => {
+                let policy = self.policy.as_mut().unwrap();
+                if policy.sitting_out() {
+                    debug!("{}: Backing off, still thinking", t.position);
+                    return Box::new(self.keep_thinking(t));
+                }
+                let first = policy.fork_choice.first_fork(&t);
+                let contention = policy.contention;
+                self.try_side(first, t)
+                    .or_else(|t| match contention {
+                        Contention::GiveWay => self.try_side(first.other(), t),
+                        Contention::Wait => Err(t),
+                    })
+                    .unwrap_or_else(|t| {
+                        debug!("{}: No forks, still thinking", t.position);
+                        self.policy.as_mut().unwrap().failed();
+                        Box::new(self.keep_thinking(t))
+                    })
+            }
+        }
     }
 
     fn state(&self) -> State {
@@ -29,10 +89,9 @@ impl StateMachine for Thinking {
 mod tests {
     use std::sync::{Arc, Mutex};
 
-    use crate::dining_philosophers::fork::Fork;
     use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
-    use crate::dining_philosophers::resource_hierarchy_impl::left_thinking::LeftThinking;
-    use crate::dining_philosophers::resource_hierarchy_impl::right_thinking::RightThinking;
+    use crate::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
     use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
     use crate::dining_philosophers::table::{Table, TableInteraction};
 
@@ -46,12 +105,74 @@ mod tests {
 
     #[test]
     fn changes_to_left_when_left_fork_available() {
-        // Write test here
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(table_interaction));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
     }
 
     #[test]
     fn changes_to_thinking_when_left_fork_is_not_available() {
-        // Write test here
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let _fork = table_interaction.get_left_fork();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(table_interaction));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    fn changes_to_right_when_only_right_fork_available() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let table_interaction = table_interactions.pop().unwrap();
+        let neighbour = table_interactions.pop().unwrap();
+        let _fork = neighbour.get_right_fork();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(table_interaction));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::RightThinking);
+    }
+
+    #[test]
+    fn changes_to_right_when_policy_prefers_right() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let policy = Policy::new(ForkChoice::AlwaysRight);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::with_policy(table_interaction, policy));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::RightThinking);
+    }
+
+    #[test]
+    fn alternating_policy_is_carried_between_states() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let policy = Policy::new(ForkChoice::alternating());
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::with_policy(table_interaction, policy));
+
+        unit = unit.transition(); // left thinking
+        unit = unit.transition(); // right fork is the same fork, back to thinking
+        unit = unit.transition(); // right first this time
+
+        assert_eq!(unit.state(), State::RightThinking);
+    }
+
+    #[test]
+    fn stays_thinking_until_a_fork_is_returned() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_left_fork().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(table_interaction));
+
+        unit = unit.transition();
+        TableInteraction { position: 0, table }.return_left_fork(fork).unwrap();
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
     }
 
     #[test]
@@ -63,4 +184,17 @@ mod tests {
         unit.transition();
         unit.transition();
     }
+
+    #[test]
+    fn waiting_philosopher_does_not_reach_for_the_other_fork() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let table_interaction = table_interactions.pop().unwrap();
+        let neighbour = table_interactions.pop().unwrap();
+        let _fork = neighbour.get_left_fork();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::with_policy(table_interaction, Policy::resource_hierarchy()));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
 }
@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Barrier, Mutex};
+
+use crate::dining_philosophers::analysis::{Counters, RunResults};
+use crate::dining_philosophers::philosopher::philosopher::Philosopher;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::{Table, TableInteraction};
+use crate::dining_philosophers::topology::Topology;
+use crate::thread_pool::thread_pool::ThreadPool;
+
+/// The results of several runs, one map of histories per run.
+pub type Results = Arc<Vec<Arc<Mutex<RunResults>>>>;
+
+/// Builds the strategy for one table of the given size.
+pub type NewStrategy = Arc<dyn Fn(usize) -> Box<dyn Strategy> + Send + Sync>;
+
+/// The histories and strategy counters of several runs of one strategy.
+pub struct Runs {
+    pub results: Results,
+    pub counters: Vec<Arc<Mutex<Counters>>>,
+}
+
+pub fn run_several_simulations(new_strategy: NewStrategy, total_runs: usize, number_of_philosophers: usize, iterations: i32) -> Runs {
+    let results = create_results_vector(total_runs, number_of_philosophers);
+    let counters: Vec<Arc<Mutex<Counters>>> = (0..total_runs).map(|_| Arc::new(Mutex::new(Vec::new()))).collect();
+    let pool = ThreadPool::new(20);
+    for i in 0..total_runs {
+        let store = Arc::clone(&results[i]);
+        let counter_store = Arc::clone(&counters[i]);
+        let new_strategy = Arc::clone(&new_strategy);
+        pool.execute(move || {
+            run_simulation(&new_strategy, number_of_philosophers, iterations, &store, &counter_store, i)
+        });
+    }
+    drop(pool);
+    Runs {
+        results,
+        counters,
+    }
+}
+
+/// Runs every philosopher of one table on its own thread.
+///
+/// The threads meet at a barrier after every step, so no philosopher can finish its run
+/// before its neighbours have started theirs.
+fn run_simulation(new_strategy: &NewStrategy, number_of_philosophers: usize, iterations: i32, results: &Arc<Mutex<RunResults>>, counters: &Arc<Mutex<Counters>>, run_number: usize) {
+    info!("{}: Simulating {} philosophers for {} steps", run_number, number_of_philosophers, iterations);
+
+    let mut table_interactions: Vec<TableInteraction> = Table::new(number_of_philosophers)
+        .get_interactions();
+    let mut strategy = new_strategy(number_of_philosophers);
+
+    let pool = ThreadPool::new(number_of_philosophers);
+    let barrier = Arc::new(Barrier::new(number_of_philosophers));
+
+    for _ in 0..number_of_philosophers {
+        let table_interaction = table_interactions.pop().unwrap();
+
+        let mut p = Philosopher::new(
+            table_interaction.position,
+            strategy.seat(table_interaction));
+
+        let results_copy = Arc::clone(results);
+        let barrier = Arc::clone(&barrier);
+
+        pool.execute(move || {
+            for __ in 0..iterations {
+                p.act();
+                barrier.wait();
+            }
+            p.write(&mut results_copy.lock().unwrap());
+        })
+    }
+
+    drop(pool);
+    *counters.lock().unwrap() = strategy.counters();
+}
+
+/// Runs one table on the calling thread, moving every philosopher one step per round in seat order.
+///
+/// Unlike the threaded runs the outcome depends only on the strategy, which makes it
+/// reproducible.
+pub fn run_in_lockstep(strategy: &mut dyn Strategy, number_of_philosophers: usize, rounds: usize) -> RunResults {
+    run_in_lockstep_on(strategy, &Topology::ring(number_of_philosophers), rounds)
+}
+
+/// Runs one table laid out as the topology in lockstep.
+///
+/// # Panics
+///
+/// Panics if the strategy does not fit the topology.
+pub fn run_in_lockstep_on(strategy: &mut dyn Strategy, topology: &Topology, rounds: usize) -> RunResults {
+    assert!(strategy.fits(topology), "The strategy does not fit the topology");
+    let number_of_philosophers = topology.philosophers();
+    let mut philosophers: Vec<Philosopher> = Table::with_topology(topology.clone())
+        .get_interactions()
+        .into_iter()
+        .map(|t| Philosopher::new(t.position, strategy.seat(t)))
+        .collect();
+
+    for _ in 0..rounds {
+        for p in philosophers.iter_mut() {
+            p.act();
+        }
+    }
+
+    let mut results = HashMap::with_capacity(number_of_philosophers);
+    for p in &philosophers {
+        p.write(&mut results);
+    }
+    results
+}
+
+fn create_results_vector(outer_vec_size: usize, hash_map_size: usize) -> Results {
+    let mut v: Vec<Arc<Mutex<RunResults>>> = Vec::with_capacity(outer_vec_size);
+    for __ in 0..outer_vec_size {
+        v.push(Arc::new(Mutex::new(HashMap::with_capacity(hash_map_size))))
+    }
+    Arc::new(v)
+}
+
+/// Fails the test if two neighbours of a round table ate in the same step.
+#[cfg(test)]
+pub fn assert_neighbours_never_eat_together(results: &RunResults) {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+
+    let size = results.len();
+    for id in 0..size {
+        let neighbour = (id + 1) % size;
+        let together = results[&id].iter().zip(&results[&neighbour])
+            .position(|(ours, theirs)| *ours == State::Eating && *theirs == State::Eating);
+        assert_eq!(together, None, "philosophers {} and {} ate together", id, neighbour);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::analysis::Counters;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::simulation::{run_in_lockstep, run_in_lockstep_on, run_several_simulations};
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::table::TableInteraction;
+    use crate::dining_philosophers::topology::Topology;
+
+    struct AlwaysEating;
+
+    impl StateMachine for AlwaysEating {
+        fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+            Box::new(AlwaysEating)
+        }
+
+        fn state(&self) -> State {
+            State::Eating
+        }
+    }
+
+    impl Strategy for AlwaysEating {
+        fn seat(&mut self, _table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+            Box::new(AlwaysEating)
+        }
+    }
+
+    struct SeatCounter {
+        seated: usize,
+    }
+
+    impl Strategy for SeatCounter {
+        fn seat(&mut self, _table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+            self.seated += 1;
+            Box::new(AlwaysEating)
+        }
+
+        fn counters(&self) -> Counters {
+            vec![("seated", self.seated)]
+        }
+    }
+
+    #[test]
+    fn several_simulations_fill_every_run() {
+        let runs = run_several_simulations(Arc::new(|_| Box::new(AlwaysEating)), 3, 2, 4);
+
+        assert_eq!(runs.results.len(), 3);
+        assert_eq!(runs.counters.len(), 3);
+        for run in runs.results.iter() {
+            let run = run.lock().unwrap();
+            assert_eq!(run.len(), 2);
+            assert!(run.values().all(|history| history.len() == 5));
+        }
+    }
+
+    #[test]
+    fn lockstep_run_records_every_philosopher() {
+        let results = run_in_lockstep(&mut AlwaysEating, 3, 4);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[&2], vec![State::Eating; 5]);
+    }
+
+    #[test]
+    fn lockstep_run_seats_a_philosopher_for_every_place_in_the_topology() {
+        let results = run_in_lockstep_on(&mut AlwaysEating, &Topology::ring(4), 2);
+
+        assert_eq!(results.len(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "The strategy does not fit the topology")]
+    fn lockstep_run_refuses_a_strategy_that_does_not_fit() {
+        run_in_lockstep_on(&mut AlwaysEating, &Topology::star(4), 2);
+    }
+
+    #[test]
+    fn several_simulations_keep_the_counters_of_every_run() {
+        let runs = run_several_simulations(Arc::new(|_| Box::new(SeatCounter { seated: 0 })), 2, 3, 1);
+
+        for counters in runs.counters.iter() {
+            assert_eq!(*counters.lock().unwrap(), vec![("seated", 3)]);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+
+/// A solution to the dining philosophers problem, set up for one table.
+///
+/// A new strategy is created for every run, so any state shared between the
+/// philosophers of a table lives inside it.
+pub trait Strategy {
+    /// Builds the starting state of the philosopher at the given seat.
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send>;
+
+    /// Named counts the strategy kept while the table ran, read once every philosopher is done.
+    fn counters(&self) -> Counters {
+        Vec::new()
+    }
+
+    /// Whether the philosophers can sit at a table with this topology. Most strategies keep their
+    /// own picture of the round table, so only the ring fits unless a strategy says otherwise.
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.is_ring()
+    }
+
+    /// Whether the philosophers take their forks from the shared `Table`, so that they can sit at
+    /// one table with philosophers of other strategies.
+    fn shares_table(&self) -> bool {
+        true
+    }
+}
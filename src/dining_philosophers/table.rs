@@ -1,29 +1,43 @@
 use std::sync::{Arc, Mutex};
 
-use crate::dining_philosophers::fork::Fork;
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::topology::Topology;
 
 #[derive(Debug, PartialEq)]
 pub struct Table {
+    topology: Topology,
     forks: Vec<Option<Fork>>,
+    /// The philosopher each fork has been preempted for, who gets it once its holder puts it down.
+    claims: Vec<Option<usize>>,
+    /// The steps each philosopher has gone hungry since its last meal, as it reported them.
+    starvation: Vec<usize>,
 }
 
 impl Table {
+    /// The round table of `Topology::ring`.
     pub fn new(size: usize) -> Table {
         assert!(size > 0);
 
-        let mut forks = Vec::with_capacity(size);
+        Table::with_topology(Topology::ring(size))
+    }
+
+    pub fn with_topology(topology: Topology) -> Table {
+        let mut forks = Vec::with_capacity(topology.forks());
 
-        for _ in 0..size {
-            forks.push(Some(Fork {}));
+        for id in 0..topology.forks() {
+            forks.push(Some(Fork::new(id)));
         }
 
         Table {
+            claims: vec![None; topology.forks()],
+            starvation: vec![0; topology.philosophers()],
             forks,
+            topology,
         }
     }
 
     pub fn get_interactions(self) -> Vec<TableInteraction> {
-        let size = self.forks.len();
+        let size = self.topology.philosophers();
         let mut table_interactions = Vec::with_capacity(size);
         let table = Mutex::new(self);
         let arc = Arc::new(table);
@@ -42,9 +56,87 @@ impl Table {
         }
     }
 
-    fn return_fork(&mut self, fork: Fork, position: usize) {
+    /// Whether the philosopher may put the fork back in the slot.
+    fn check_return(&self, fork: &Fork, position: usize, philosopher: usize) -> Result<(), ForkError> {
+        if position >= self.forks.len() {
+            return Err(ForkError::NoSuchSlot { fork: fork.id(), slot: position });
+        }
+        if fork.id() != position {
+            return Err(ForkError::WrongSlot { fork: fork.id(), slot: position });
+        }
+        if self.forks[position].is_some() {
+            return Err(ForkError::AlreadyReturned { slot: position });
+        }
+        if fork.holder() != Some(philosopher) {
+            return Err(ForkError::NotHolder { fork: fork.id(), holder: fork.holder(), philosopher });
+        }
+        Ok(())
+    }
+
+    /// Puts the fork back in its slot, unless it belongs elsewhere or someone else is holding it,
+    /// in which case the fork is handed back with the reason.
+    fn return_fork(&mut self, fork: Fork, position: usize, philosopher: usize) -> Result<(), (ForkError, Fork)> {
+        match self.check_return(&fork, position, philosopher) {
+            Ok(()) => {
+                self.put_back(fork, position);
+                Ok(())
+            }
+            Err(e) => Err((e, fork)),
+        }
+    }
+
+    fn put_back(&mut self, mut fork: Fork, position: usize) {
+        fork.put_down();
         self.forks[position] = Some(fork);
     }
+
+    fn is_free_for(&self, position: usize, philosopher: usize) -> bool {
+        position < self.forks.len()
+            && self.forks[position].is_some()
+            && self.claims[position].is_none_or(|claimant| claimant == philosopher)
+    }
+
+    /// Takes a fork unless it is in use or was preempted for someone else.
+    fn get_fork_for(&mut self, position: usize, philosopher: usize) -> Option<Fork> {
+        if !self.is_free_for(position, philosopher) {
+            return None;
+        }
+        self.claims[position] = None;
+        let mut fork = self.get_fork(position);
+        if let Some(fork) = fork.as_mut() {
+            fork.pick_up(philosopher);
+        }
+        fork
+    }
+
+    /// Claims a fork in use, unless someone already has.
+    fn preempt(&mut self, position: usize, philosopher: usize) -> bool {
+        let claimable = self.forks[position].is_none() && self.claims[position].is_none();
+        if claimable {
+            self.claims[position] = Some(philosopher);
+        }
+        claimable
+    }
+
+    fn is_claimed_from(&self, position: usize, philosopher: usize) -> bool {
+        self.claims[position].is_some_and(|claimant| claimant != philosopher)
+    }
+}
+
+/// What a philosopher can see of one neighbour.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Neighbour {
+    pub position: usize,
+    /// The steps the neighbour has gone hungry since its last meal.
+    pub starvation: usize,
+}
+
+/// A read-only snapshot of the philosophers on either side: the ones sharing the left and the
+/// right fork. A philosopher who shares a fork with nobody is its own neighbour on that side.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Neighbours {
+    pub left: Neighbour,
+    pub right: Neighbour,
 }
 
 #[derive(Debug)]
@@ -61,34 +153,190 @@ impl PartialEq for TableInteraction {
 
 impl TableInteraction {
     pub fn get_left_fork(&self) -> Option<Fork> {
-        self.table.lock().unwrap().get_fork(self.position)
+        let position = self.left_fork_number();
+        self.table.lock().unwrap().get_fork_for(position, self.position)
     }
 
-    pub fn return_left_fork(&self, fork: Fork) {
-        self.table.lock().unwrap().return_fork(fork, self.position)
+    /// Puts the left fork back, or hands it back with the reason it does not belong there.
+    pub fn return_left_fork(&self, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        let position = self.left_fork_number();
+        self.table.lock().unwrap().return_fork(fork, position, self.position)
     }
     pub fn get_right_fork(&self) -> Option<Fork> {
+        let next_position = self.right_fork_number();
+        self.table.lock().unwrap().get_fork_for(next_position, self.position)
+    }
+    pub fn return_right_fork(&self, fork: Fork) -> Result<(), (ForkError, Fork)> {
+        let next_position = self.right_fork_number();
+        self.table.lock().unwrap().return_fork(fork, next_position, self.position)
+    }
+
+    /// Takes both forks at once, or neither if either one is in use.
+    pub fn get_both_forks(&self) -> Option<(Fork, Fork)> {
+        let left_position = self.left_fork_number();
+        let right_position = self.right_fork_number();
+        let mut t = self.table.lock().unwrap();
+        if left_position == right_position || !t.is_free_for(left_position, self.position) || !t.is_free_for(right_position, self.position) {
+            return None;
+        }
+        Some((t.get_fork_for(left_position, self.position).unwrap(), t.get_fork_for(right_position, self.position).unwrap()))
+    }
+
+    /// Puts back both forks taken with `get_both_forks`, or neither, handing them back with the
+    /// reason if the table refuses either one.
+    pub fn return_both_forks(&self, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        let left_position = self.left_fork_number();
+        let right_position = self.right_fork_number();
         let mut t = self.table.lock().unwrap();
-        let next_position = (self.position + 1) % t.forks.len();
-        t.get_fork(next_position)
+        let refused = match (t.check_return(&left_fork, left_position, self.position), t.check_return(&right_fork, right_position, self.position)) {
+            (Err(e), _) | (_, Err(e)) => Some(e),
+            _ if left_position == right_position => Some(ForkError::AlreadyReturned { slot: right_position }),
+            _ => None,
+        };
+        if let Some(e) = refused {
+            return Err((e, (left_fork, right_fork)));
+        }
+        t.put_back(left_fork, left_position);
+        t.put_back(right_fork, right_position);
+        Ok(())
     }
-    pub fn return_right_fork(&self, fork: Fork) {
+
+    /// Takes every fork this philosopher needs at once, or none if any one is in use.
+    pub fn get_all_forks(&self) -> Option<Vec<Fork>> {
+        self.get_forks(&self.fork_numbers())
+    }
+
+    /// Puts back forks taken with `get_all_forks`.
+    pub fn return_all_forks(&self, forks: Vec<Fork>) -> Result<(), (ForkError, Vec<Fork>)> {
+        self.return_forks(&self.fork_numbers(), forks)
+    }
+
+    /// Takes the numbered forks at once, or none if any one is in use.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this philosopher does not need one of the forks.
+    pub fn get_forks(&self, numbers: &[usize]) -> Option<Vec<Fork>> {
+        let mut t = self.table.lock().unwrap();
+        for number in numbers {
+            assert!(t.topology.needs(self.position).contains(number), "Philosopher {} does not need fork {}", self.position, number);
+        }
+        if !numbers.iter().all(|fork| t.is_free_for(*fork, self.position)) {
+            return None;
+        }
+        Some(numbers.iter().map(|fork| t.get_fork_for(*fork, self.position).unwrap()).collect())
+    }
+
+    /// Puts back forks taken with `get_forks`, in the order they were numbered. If there are more
+    /// or fewer forks than numbers, or any one of them does not belong where it is put, none of
+    /// them is put back and all of them are handed back with the reason.
+    pub fn return_forks(&self, numbers: &[usize], forks: Vec<Fork>) -> Result<(), (ForkError, Vec<Fork>)> {
+        if forks.len() != numbers.len() {
+            return Err((ForkError::Count { forks: forks.len(), slots: numbers.len() }, forks));
+        }
         let mut t = self.table.lock().unwrap();
-        let next_position = (self.position + 1) % t.forks.len();
-        t.return_fork(fork, next_position);
+        for (i, (fork, number)) in forks.iter().zip(numbers).enumerate() {
+            if let Err(e) = t.check_return(fork, *number, self.position) {
+                return Err((e, forks));
+            }
+            if numbers[..i].contains(number) {
+                return Err((ForkError::AlreadyReturned { slot: *number }, forks));
+            }
+        }
+        for (fork, number) in forks.into_iter().zip(numbers) {
+            t.put_back(fork, *number);
+        }
+        Ok(())
+    }
+
+    /// The numbers of the forks this philosopher needs.
+    pub fn fork_numbers(&self) -> Vec<usize> {
+        self.table.lock().unwrap().topology.needs(self.position).to_vec()
+    }
+
+    /// Whether the left fork could be taken right now, without taking it.
+    pub fn left_fork_on_table(&self) -> bool {
+        let position = self.left_fork_number();
+        self.table.lock().unwrap().is_free_for(position, self.position)
+    }
+    pub fn right_fork_on_table(&self) -> bool {
+        let next_position = self.right_fork_number();
+        self.table.lock().unwrap().is_free_for(next_position, self.position)
+    }
+
+    /// Claims the left fork from the neighbour holding it, who is expected to put it down.
+    /// Returns false if the fork is on the table or already claimed.
+    pub fn preempt_left_fork(&self) -> bool {
+        let position = self.left_fork_number();
+        self.table.lock().unwrap().preempt(position, self.position)
+    }
+    pub fn preempt_right_fork(&self) -> bool {
+        let next_position = self.right_fork_number();
+        self.table.lock().unwrap().preempt(next_position, self.position)
+    }
+
+    /// Whether a neighbour has claimed the left fork, which this philosopher should put down.
+    pub fn left_fork_claimed(&self) -> bool {
+        let position = self.left_fork_number();
+        self.table.lock().unwrap().is_claimed_from(position, self.position)
+    }
+    pub fn right_fork_claimed(&self) -> bool {
+        let next_position = self.right_fork_number();
+        self.table.lock().unwrap().is_claimed_from(next_position, self.position)
+    }
+
+    /// Adds a step to this philosopher's starvation counter.
+    pub fn starve(&self) {
+        self.table.lock().unwrap().starvation[self.position] += 1;
+    }
+
+    /// Resets this philosopher's starvation counter after a meal.
+    pub fn fed(&self) {
+        self.table.lock().unwrap().starvation[self.position] = 0;
+    }
+
+    pub fn starvation(&self) -> usize {
+        self.table.lock().unwrap().starvation[self.position]
+    }
+
+    /// The neighbours' starvation counters, which only they can change.
+    pub fn neighbours(&self) -> Neighbours {
+        let left_fork = self.left_fork_number();
+        let right_fork = self.right_fork_number();
+        let t = self.table.lock().unwrap();
+        let neighbour = |fork: usize| {
+            let position = t.topology.sharing(fork).into_iter()
+                .find(|position| *position != self.position)
+                .unwrap_or(self.position);
+            Neighbour { position, starvation: t.starvation[position] }
+        };
+        Neighbours {
+            left: neighbour(left_fork),
+            right: neighbour(right_fork),
+        }
+    }
+
+    /// The first fork this philosopher needs.
+    pub fn left_fork_number(&self) -> usize {
+        self.table.lock().unwrap().topology.needs(self.position)[0]
+    }
+    /// The last fork this philosopher needs.
+    pub fn right_fork_number(&self) -> usize {
+        *self.table.lock().unwrap().topology.needs(self.position).last().unwrap()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::dining_philosophers::fork::Fork;
-    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::fork::{Fork, ForkError};
+    use crate::dining_philosophers::table::{Neighbour, Table};
+    use crate::dining_philosophers::topology::Topology;
 
     #[test]
     fn construct_table() {
         let unit = Table::new(1);
 
-        assert_eq!(unit, Table { forks: vec![Some(Fork {})] });
+        assert_eq!(unit, Table { topology: Topology::ring(1), forks: vec![Some(Fork::new(0))], claims: vec![None], starvation: vec![0] });
     }
 
     #[test]
@@ -134,21 +382,27 @@ mod tests {
     fn return_fork_to_table() {
         let mut unit = Table::new(1);
         let position = 0;
-        let fork = unit.get_fork(position);
+        let fork = unit.get_fork_for(position, 0);
 
-        unit.return_fork(fork.unwrap(), position);
+        assert_eq!(unit.return_fork(fork.unwrap(), position, 0), Ok(()));
         let fork = unit.get_fork(position);
 
-        assert_ne!(fork, None);
+        assert_eq!(fork, Some(Fork::new(0)));
     }
 
     #[test]
-    #[should_panic]
-    fn return_fork_panics_when_position_larger_than_table_size() {
+    fn fork_records_who_picked_it_up() {
+        let mut unit = Table::new(2);
+
+        assert_eq!(unit.get_fork_for(1, 0).unwrap().holder(), Some(0));
+    }
+
+    #[test]
+    fn return_fork_refuses_a_slot_beyond_the_table() {
         let mut unit = Table::new(1);
-        let fork = unit.get_fork(0);
+        let fork = unit.get_fork_for(0, 0);
 
-        unit.return_fork(fork.unwrap(), 2);
+        assert_eq!(unit.return_fork(fork.unwrap(), 2, 0).unwrap_err().0, ForkError::NoSuchSlot { fork: 0, slot: 2 });
     }
 
     #[test]
@@ -189,19 +443,295 @@ mod tests {
         let unit = table_interactions.pop().unwrap();
         let fork = unit.get_left_fork().unwrap();
 
-        unit.return_left_fork(fork);
+        unit.return_left_fork(fork).unwrap();
 
         assert_ne!(unit.get_left_fork(), None);
     }
 
     #[test]
     fn table_interaction_returns_adjacent_fork() {
-        let mut table_interactions = Table::new(1).get_interactions();
-        let unit = table_interactions.pop().unwrap();
+        let mut table_interactions = Table::new(2).get_interactions();
+        let unit = table_interactions.remove(0);
+        let fork = unit.get_right_fork().unwrap();
+
+        assert_eq!(unit.return_right_fork(fork), Ok(()));
+
+        assert_ne!(unit.get_right_fork(), None);
+    }
+
+    #[test]
+    fn table_interaction_refuses_a_fork_in_the_wrong_slot() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let unit = table_interactions.remove(0);
+        let fork = unit.get_left_fork().unwrap();
+
+        let (e, fork) = unit.return_right_fork(fork).unwrap_err();
+        assert_eq!(e, ForkError::WrongSlot { fork: 0, slot: 1 });
+        assert_ne!(unit.get_right_fork(), None);
+
+        unit.return_left_fork(fork).unwrap();
+        assert_eq!(unit.get_left_fork().map(|fork| fork.id()), Some(0));
+    }
+
+    #[test]
+    fn table_interaction_refuses_a_second_return_to_a_slot() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let unit = table_interactions.remove(0);
         let fork = unit.get_left_fork().unwrap();
+        unit.return_left_fork(fork).unwrap();
+
+        assert_eq!(unit.return_left_fork(Fork::new(0)).unwrap_err().0, ForkError::AlreadyReturned { slot: 0 });
+    }
+
+    #[test]
+    fn table_interaction_refuses_a_fork_it_does_not_hold() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = table_interactions.remove(1);
+        let unit = table_interactions.remove(0);
+        let fork = neighbour.get_left_fork().unwrap();
+
+        assert_eq!(unit.return_right_fork(fork).unwrap_err().0, ForkError::NotHolder { fork: 1, holder: Some(1), philosopher: 0 });
+    }
+
+    #[test]
+    fn table_interaction_puts_back_none_of_several_forks_if_one_is_refused() {
+        let table_interactions = Table::with_topology(Topology::star(3)).get_interactions();
+        let centre = &table_interactions[0];
+        let forks = centre.get_all_forks().unwrap();
+
+        let (e, forks) = centre.return_forks(&[1, 0], forks).unwrap_err();
+        assert_eq!(e, ForkError::WrongSlot { fork: 0, slot: 1 });
+        assert_eq!(table_interactions[1].get_all_forks(), None);
+        assert_eq!(table_interactions[2].get_all_forks(), None);
+
+        centre.return_forks(&[0, 1], forks).unwrap();
+        assert!(table_interactions[1].get_all_forks().is_some());
+    }
+
+    #[test]
+    fn table_interaction_refuses_more_or_fewer_forks_than_slots() {
+        let table_interactions = Table::with_topology(Topology::star(3)).get_interactions();
+        let centre = &table_interactions[0];
+        let forks = centre.get_all_forks().unwrap();
+
+        let (e, forks) = centre.return_forks(&[0], forks).unwrap_err();
+        assert_eq!(e, ForkError::Count { forks: 2, slots: 1 });
+        assert_eq!(table_interactions[1].get_all_forks(), None);
+
+        let (e, forks) = centre.return_forks(&[0, 1, 1], forks).unwrap_err();
+        assert_eq!(e, ForkError::Count { forks: 2, slots: 3 });
+        centre.return_all_forks(forks).unwrap();
+    }
+
+    #[test]
+    fn table_interaction_numbers_its_forks() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let last = table_interactions.pop().unwrap();
+        let middle = table_interactions.pop().unwrap();
+
+        assert_eq!((middle.left_fork_number(), middle.right_fork_number()), (1, 2));
+        assert_eq!((last.left_fork_number(), last.right_fork_number()), (2, 0));
+    }
+
+    #[test]
+    fn table_interaction_gets_both_forks() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let unit = table_interactions.pop().unwrap();
+
+        assert_ne!(unit.get_both_forks(), None);
+        assert_eq!(unit.get_left_fork(), None);
+        assert_eq!(unit.get_right_fork(), None);
+    }
+
+    #[test]
+    fn table_interaction_returns_both_forks_or_neither() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let neighbour = table_interactions.remove(1);
+        let unit = table_interactions.remove(0);
+        let (left_fork, right_fork) = unit.get_both_forks().unwrap();
+
+        let (e, (right_fork, left_fork)) = unit.return_both_forks(right_fork, left_fork).unwrap_err();
+        assert_eq!(e, ForkError::WrongSlot { fork: 1, slot: 0 });
+        assert_eq!(neighbour.get_left_fork(), None);
+
+        unit.return_both_forks(left_fork, right_fork).unwrap();
+        assert_ne!(neighbour.get_left_fork(), None);
+        assert_ne!(unit.get_left_fork(), None);
+    }
 
-        unit.return_right_fork(fork);
+    #[test]
+    fn table_interaction_gets_neither_fork_when_one_is_taken() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let unit = table_interactions.pop().unwrap();
+        let _fork = unit.get_right_fork();
 
+        assert_eq!(unit.get_both_forks(), None);
         assert_ne!(unit.get_left_fork(), None);
     }
+
+    #[test]
+    fn table_interaction_cannot_get_both_forks_of_a_single_fork_table() {
+        let mut table_interactions = Table::new(1).get_interactions();
+        let unit = table_interactions.pop().unwrap();
+
+        assert_eq!(unit.get_both_forks(), None);
+    }
+
+    #[test]
+    fn table_interaction_looks_at_its_forks_without_taking_them() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = table_interactions.pop().unwrap();
+        let unit = table_interactions.pop().unwrap();
+        let _fork = neighbour.get_left_fork();
+
+        assert!(unit.left_fork_on_table());
+        assert!(!unit.right_fork_on_table());
+        assert!(unit.left_fork_on_table());
+    }
+
+    #[test]
+    fn table_interaction_sees_its_neighbours_starve() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let right = table_interactions.pop().unwrap();
+        let unit = table_interactions.pop().unwrap();
+        let left = table_interactions.pop().unwrap();
+        left.starve();
+        right.starve();
+        right.starve();
+
+        let neighbours = unit.neighbours();
+
+        assert_eq!(neighbours.left, Neighbour { position: 0, starvation: 1 });
+        assert_eq!(neighbours.right, Neighbour { position: 2, starvation: 2 });
+        assert_eq!(unit.starvation(), 0);
+    }
+
+    #[test]
+    fn table_interaction_resets_starvation_after_a_meal() {
+        let mut table_interactions = Table::new(2).get_interactions();
+        let neighbour = table_interactions.pop().unwrap();
+        let unit = table_interactions.pop().unwrap();
+        unit.starve();
+
+        unit.fed();
+
+        assert_eq!(neighbour.neighbours().left.starvation, 0);
+    }
+
+    #[test]
+    fn table_interaction_preempts_a_fork_in_use() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let holder = table_interactions.remove(1);
+        let unit = table_interactions.remove(0);
+        let _fork = holder.get_left_fork();
+
+        assert!(unit.preempt_right_fork());
+        assert!(holder.left_fork_claimed());
+        assert!(!unit.right_fork_claimed());
+    }
+
+    #[test]
+    fn table_interaction_cannot_preempt_a_fork_on_the_table() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let unit = table_interactions.remove(0);
+
+        assert!(!unit.preempt_right_fork());
+        assert_ne!(unit.get_right_fork(), None);
+    }
+
+    #[test]
+    fn table_interaction_cannot_preempt_a_fork_twice() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let right = table_interactions.remove(2);
+        let holder = table_interactions.remove(1);
+        let unit = table_interactions.remove(0);
+        let _fork = holder.get_left_fork();
+        unit.preempt_right_fork();
+
+        assert!(!right.preempt_left_fork());
+    }
+
+    #[test]
+    fn preempted_fork_goes_to_the_claimant() {
+        let mut table_interactions = Table::new(3).get_interactions();
+        let holder = table_interactions.remove(1);
+        let unit = table_interactions.remove(0);
+        let fork = holder.get_left_fork().unwrap();
+        unit.preempt_right_fork();
+
+        holder.return_left_fork(fork).unwrap();
+
+        assert_eq!(holder.get_left_fork(), None);
+        assert_eq!(holder.get_both_forks(), None);
+        assert_ne!(unit.get_right_fork(), None);
+        assert!(!holder.left_fork_claimed());
+    }
+
+    #[test]
+    fn table_interaction_gets_every_fork_it_needs_at_once() {
+        let mut table_interactions = Table::with_topology(Topology::star(4)).get_interactions();
+        let spoke = table_interactions.pop().unwrap();
+        let centre = table_interactions.remove(0);
+
+        let forks = centre.get_all_forks().unwrap();
+        assert_eq!(forks.len(), 3);
+        assert_eq!(spoke.get_all_forks(), None);
+
+        centre.return_all_forks(forks).unwrap();
+        assert_eq!(spoke.get_all_forks().map(|forks| forks.len()), Some(1));
+        assert_eq!(centre.get_all_forks(), None);
+    }
+
+    #[test]
+    fn table_interaction_finds_its_forks_in_the_topology() {
+        let topology = Topology::new(4, vec![vec![0, 1], vec![1, 0], vec![2, 3], vec![3, 2]]);
+        let table_interactions = Table::with_topology(topology).get_interactions();
+        let unit = &table_interactions[2];
+        unit.get_right_fork();
+
+        assert_eq!((unit.left_fork_number(), unit.right_fork_number()), (2, 3));
+        assert!(!table_interactions[3].left_fork_on_table());
+        assert!(table_interactions[0].left_fork_on_table() && table_interactions[0].right_fork_on_table());
+        assert_eq!(unit.neighbours().left.position, 3);
+        assert_eq!(unit.neighbours().right.position, 3);
+    }
+
+    #[test]
+    fn philosopher_sharing_a_fork_with_nobody_is_its_own_neighbour() {
+        let table_interactions = Table::with_topology(Topology::new(2, vec![vec![0, 1], vec![1]])).get_interactions();
+
+        assert_eq!(table_interactions[0].neighbours().left.position, 0);
+        assert_eq!(table_interactions[0].neighbours().right.position, 1);
+    }
+
+    #[test]
+    fn line_end_has_the_same_fork_on_either_side() {
+        let table_interactions = Table::with_topology(Topology::line(3)).get_interactions();
+        let end = &table_interactions[0];
+
+        assert_eq!(end.left_fork_number(), end.right_fork_number());
+        assert_eq!(end.get_both_forks(), None);
+        assert_ne!(end.get_all_forks(), None);
+    }
+
+    #[test]
+    fn table_interaction_takes_some_of_its_forks() {
+        let table_interactions = Table::with_topology(Topology::star(4)).get_interactions();
+        let centre = &table_interactions[0];
+
+        assert_eq!(centre.fork_numbers(), vec![0, 1, 2]);
+        let forks = centre.get_forks(&[0, 2]).unwrap();
+        assert!(table_interactions[2].get_all_forks().is_some());
+        assert_eq!(table_interactions[1].get_all_forks(), None);
+        assert_eq!(centre.get_forks(&[1, 2]), None);
+
+        centre.return_forks(&[0, 2], forks).unwrap();
+        assert!(table_interactions[1].get_all_forks().is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Philosopher 1 does not need fork 1")]
+    fn table_interaction_only_takes_forks_it_needs() {
+        Table::with_topology(Topology::star(3)).get_interactions()[1].get_forks(&[1]);
+    }
 }
@@ -0,0 +1,87 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::tanenbaum_impl::monitor::MonitorInteraction;
+use crate::dining_philosophers::tanenbaum_impl::thinking::Thinking;
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+    monitor_interaction: Option<MonitorInteraction>,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, monitor_interaction: MonitorInteraction) -> Eating {
+        Eating {
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+            monitor_interaction: Some(monitor_interaction),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.monitor_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(m) => {
+                debug!("{}: Put forks down and signal neighbours, to thinking", m.position());
+                let refused = m.put_down_forks(self.left_fork.take().unwrap(), self.right_fork.take().unwrap());
+                match keep_refused(m.position(), refused) {
+                    Some((left_fork, right_fork)) => Box::new(Eating::new(left_fork, right_fork, m)),
+                    None => Box::new(Thinking::new(m)),
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::tanenbaum_impl::eating::Eating;
+    use crate::dining_philosophers::tanenbaum_impl::monitor::{Monitor, MonitorInteraction};
+
+    fn eating() -> (Eating, Arc<Monitor>) {
+        let monitor = Arc::new(Monitor::new(3, Duration::from_millis(0)));
+        let table_interaction = Table::new(3).get_interactions().remove(0);
+        let m = MonitorInteraction { table_interaction, monitor: Arc::clone(&monitor) };
+        monitor.take_forks(0);
+        let (left_fork, right_fork) = m.pick_up_forks().unwrap();
+        (Eating::new(left_fork, right_fork, m), monitor)
+    }
+
+    #[test]
+    fn state_is_eating() {
+        assert_eq!(eating().0.state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_forks_down_and_wakes_a_hungry_neighbour() {
+        let (unit, monitor) = eating();
+        monitor.take_forks(1);
+        let mut unit: Box<dyn StateMachine> = Box::new(unit);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert!(monitor.wait_for_forks(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = eating().0;
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,128 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::tanenbaum_impl::eating::Eating;
+use crate::dining_philosophers::tanenbaum_impl::monitor::MonitorInteraction;
+
+/// Announced as hungry, waiting for a neighbour to signal that it may eat.
+#[derive(Debug, PartialEq)]
+pub struct Hungry {
+    monitor_interaction: Option<MonitorInteraction>,
+}
+
+impl Hungry {
+    pub fn new(monitor_interaction: MonitorInteraction) -> Hungry {
+        Hungry {
+            monitor_interaction: Some(monitor_interaction),
+        }
+    }
+}
+
+/// Eats once the monitor lets it, or stays hungry when someone who does not use the monitor holds
+/// one of the forks.
+pub fn pick_up(m: MonitorInteraction) -> Box<dyn StateMachine + Send> {
+    match m.pick_up_forks() {
+        Some((left_fork, right_fork)) => Box::new(Eating::new(left_fork, right_fork, m)),
+        None => {
+            debug!("{}: A fork is held outside the monitor, still hungry", m.position());
+            m.monitor.forks_missing(m.position());
+            Box::new(Hungry::new(m))
+        }
+    }
+}
+
+impl StateMachine for Hungry {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.monitor_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(m) if m.monitor.wait_for_forks(m.position()) => {
+                debug!("{}: Woken by a neighbour. Eating!", m.position());
+                pick_up(m)
+            }
+            Some(m) if m.monitor.retry(m.position()) => {
+                debug!("{}: Neighbours not eating. Eating!", m.position());
+                pick_up(m)
+            }
+            Some(m) => {
+                debug!("{}: Still hungry", m.position());
+                Box::new(Hungry::new(m))
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Hungry
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::tanenbaum_impl::hungry::Hungry;
+    use crate::dining_philosophers::tanenbaum_impl::monitor::{Monitor, MonitorInteraction};
+
+    fn seats(size: usize) -> (Vec<MonitorInteraction>, Arc<Monitor>) {
+        let monitor = Arc::new(Monitor::new(size, Duration::from_millis(0)));
+        let seats = Table::new(size).get_interactions().into_iter()
+            .map(|table_interaction| MonitorInteraction { table_interaction, monitor: Arc::clone(&monitor) })
+            .collect();
+        (seats, monitor)
+    }
+
+    #[test]
+    fn state_is_hungry() {
+        assert_eq!(Hungry::new(seats(2).0.remove(0)).state(), State::Hungry);
+    }
+
+    #[test]
+    fn stays_hungry_while_a_neighbour_eats() {
+        let (mut seats, monitor) = seats(3);
+        monitor.take_forks(1);
+        monitor.take_forks(0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Hungry::new(seats.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    fn eats_once_the_neighbour_puts_its_forks_down() {
+        let (mut seats, monitor) = seats(3);
+        monitor.take_forks(1);
+        monitor.take_forks(0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Hungry::new(seats.remove(0)));
+
+        monitor.put_forks(1);
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn waits_for_a_fork_held_outside_the_monitor() {
+        let (mut seats, monitor) = seats(3);
+        let fork = seats[1].table_interaction.get_left_fork().unwrap();
+        let outsider = seats.remove(1);
+        monitor.take_forks(0);
+        let mut unit: Box<dyn StateMachine> = Box::new(Hungry::new(seats.remove(0)));
+
+        unit = unit.transition();
+        assert_eq!(unit.state(), State::Hungry);
+
+        outsider.table_interaction.return_left_fork(fork).unwrap();
+        unit = unit.transition();
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Hungry::new(seats(2).0.remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
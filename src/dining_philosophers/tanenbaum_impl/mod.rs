@@ -0,0 +1,5 @@
+pub mod monitor;
+pub mod thinking;
+pub mod hungry;
+pub mod eating;
+pub mod tanenbaum;
@@ -0,0 +1,210 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+
+/// What the monitor knows about each philosopher.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Appetite {
+    Thinking,
+    Hungry,
+    Eating,
+}
+
+#[derive(Debug)]
+struct Appetites {
+    appetites: Vec<Appetite>,
+    woken: usize,
+}
+
+impl Appetites {
+    /// Lets a hungry philosopher eat when neither neighbour is eating.
+    fn test(&mut self, position: usize) -> bool {
+        let size = self.appetites.len();
+        let left = (position + size - 1) % size;
+        let right = (position + 1) % size;
+        let may_eat = self.appetites[position] == Appetite::Hungry
+            && self.appetites[left] != Appetite::Eating
+            && self.appetites[right] != Appetite::Eating;
+        if may_eat {
+            self.appetites[position] = Appetite::Eating;
+        }
+        may_eat
+    }
+}
+
+/// Tanenbaum's monitor: the only way to the forks of the `Table`.
+///
+/// A philosopher is handed both forks once it is hungry and neither neighbour is eating. A
+/// philosopher putting its forks down tests both neighbours and wakes any it let eat.
+///
+/// A philosopher may only wait for a neighbour's signal for `patience`, as it still has to meet
+/// the rest of the table at the end of every step.
+#[derive(Debug)]
+pub struct Monitor {
+    appetites: Mutex<Appetites>,
+    granted: Condvar,
+    patience: Duration,
+}
+
+impl Monitor {
+    pub fn new(number_of_philosophers: usize, patience: Duration) -> Monitor {
+        Monitor {
+            appetites: Mutex::new(Appetites {
+                appetites: vec![Appetite::Thinking; number_of_philosophers],
+                woken: 0,
+            }),
+            granted: Condvar::new(),
+            patience,
+        }
+    }
+
+    /// Announces that the philosopher is hungry, and returns whether it may eat straight away.
+    pub fn take_forks(&self, position: usize) -> bool {
+        let mut appetites = self.appetites.lock().unwrap();
+        appetites.appetites[position] = Appetite::Hungry;
+        appetites.test(position)
+    }
+
+    /// Waits up to the monitor's patience for a neighbour to let the philosopher eat.
+    pub fn wait_for_forks(&self, position: usize) -> bool {
+        let appetites = self.appetites.lock().unwrap();
+        let (appetites, _) = self.granted
+            .wait_timeout_while(appetites, self.patience, |a| a.appetites[position] != Appetite::Eating)
+            .unwrap();
+        appetites.appetites[position] == Appetite::Eating
+    }
+
+    /// Tests a hungry philosopher again. A philosopher is let eat as soon as it may, so this only
+    /// matters once someone who does not use the monitor has held one of its forks.
+    pub fn retry(&self, position: usize) -> bool {
+        self.appetites.lock().unwrap().test(position)
+    }
+
+    /// Takes back the go-ahead of a philosopher who found one of its forks in the hands of
+    /// someone who does not use the monitor, leaving it hungry.
+    pub fn forks_missing(&self, position: usize) {
+        self.appetites.lock().unwrap().appetites[position] = Appetite::Hungry;
+    }
+
+    pub fn put_forks(&self, position: usize) {
+        let mut appetites = self.appetites.lock().unwrap();
+        appetites.appetites[position] = Appetite::Thinking;
+        let size = appetites.appetites.len();
+        let woken = [(position + size - 1) % size, (position + 1) % size].iter()
+            .filter(|n| appetites.test(**n))
+            .count();
+        if woken > 0 {
+            appetites.woken += woken;
+            self.granted.notify_all();
+        }
+    }
+
+    /// The hungry philosophers a neighbour let eat when it put its forks down.
+    pub fn woken(&self) -> usize {
+        self.appetites.lock().unwrap().woken
+    }
+}
+
+/// A philosopher's way to the forks through the monitor.
+#[derive(Debug)]
+pub struct MonitorInteraction {
+    pub table_interaction: TableInteraction,
+    pub monitor: Arc<Monitor>,
+}
+
+impl PartialEq for MonitorInteraction {
+    fn eq(&self, other: &MonitorInteraction) -> bool {
+        self.table_interaction == other.table_interaction
+    }
+}
+
+impl MonitorInteraction {
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    /// Picks up both forks. The monitor guarantees they are on the table, unless someone who does
+    /// not use it is holding one.
+    pub fn pick_up_forks(&self) -> Option<(Fork, Fork)> {
+        self.table_interaction.get_both_forks()
+    }
+
+    /// Puts both forks down and signals the neighbours, unless the table refuses the forks, which
+    /// are handed back with the reason.
+    pub fn put_down_forks(&self, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        self.table_interaction.return_both_forks(left_fork, right_fork)?;
+        self.monitor.put_forks(self.position());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::dining_philosophers::tanenbaum_impl::monitor::Monitor;
+
+    fn monitor(size: usize) -> Monitor {
+        Monitor::new(size, Duration::from_millis(0))
+    }
+
+    #[test]
+    fn hungry_philosopher_eats_when_neighbours_do_not() {
+        let unit = monitor(5);
+
+        assert!(unit.take_forks(0));
+        assert!(unit.take_forks(2));
+    }
+
+    #[test]
+    fn hungry_philosopher_waits_while_a_neighbour_eats() {
+        let unit = monitor(5);
+        unit.take_forks(0);
+
+        assert!(!unit.take_forks(1));
+        assert!(!unit.take_forks(4));
+        assert!(!unit.wait_for_forks(1));
+    }
+
+    #[test]
+    fn putting_forks_down_lets_a_hungry_neighbour_eat() {
+        let unit = monitor(5);
+        unit.take_forks(0);
+        unit.take_forks(1);
+
+        unit.put_forks(0);
+
+        assert!(unit.wait_for_forks(1));
+        assert_eq!(unit.woken(), 1);
+    }
+
+    #[test]
+    fn neighbour_still_waits_for_its_other_neighbour() {
+        let unit = monitor(5);
+        unit.take_forks(0);
+        unit.take_forks(2);
+        unit.take_forks(1);
+
+        unit.put_forks(0);
+
+        assert!(!unit.wait_for_forks(1));
+        assert_eq!(unit.woken(), 0);
+    }
+
+    #[test]
+    fn waiting_philosopher_is_woken_by_its_neighbour() {
+        let unit = Arc::new(Monitor::new(3, Duration::from_secs(10)));
+        unit.take_forks(0);
+        unit.take_forks(1);
+
+        let neighbour = Arc::clone(&unit);
+        let waiter = thread::spawn(move || neighbour.wait_for_forks(1));
+        unit.put_forks(0);
+
+        assert!(waiter.join().unwrap());
+    }
+}
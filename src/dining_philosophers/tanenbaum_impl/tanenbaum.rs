@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::tanenbaum_impl::monitor::{Monitor, MonitorInteraction};
+use crate::dining_philosophers::tanenbaum_impl::thinking::Thinking;
+
+/// Tanenbaum's monitor solution: a hungry philosopher eats once neither neighbour is eating,
+/// and a philosopher that finishes eating signals any hungry neighbour it lets eat.
+pub struct Tanenbaum {
+    monitor: Arc<Monitor>,
+}
+
+impl Tanenbaum {
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two philosophers, who would share their only fork.
+    pub fn new(number_of_philosophers: usize) -> Tanenbaum {
+        Tanenbaum::with_patience(number_of_philosophers, Duration::from_millis(1))
+    }
+
+    /// Sets how long a hungry philosopher waits for a neighbour's signal within one step.
+    pub fn with_patience(number_of_philosophers: usize, patience: Duration) -> Tanenbaum {
+        assert!(number_of_philosophers > 1);
+        Tanenbaum {
+            monitor: Arc::new(Monitor::new(number_of_philosophers, patience)),
+        }
+    }
+}
+
+impl Strategy for Tanenbaum {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Thinking::new(MonitorInteraction {
+            table_interaction,
+            monitor: Arc::clone(&self.monitor),
+        }))
+    }
+
+    fn counters(&self) -> Counters {
+        vec![("woken by a neighbour", self.monitor.woken())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep, run_several_simulations};
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::tanenbaum_impl::tanenbaum::Tanenbaum;
+
+    fn impatient(size: usize) -> Tanenbaum {
+        Tanenbaum::with_patience(size, Duration::from_millis(0))
+    }
+
+    #[test]
+    fn everyone_eats() {
+        for size in 2..=16 {
+            let results = run_in_lockstep(&mut impatient(size), size, 4 * size);
+
+            for (id, history) in &results {
+                assert!(history.contains(&State::Eating), "philosopher {} of {} never ate", id, size);
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let results = run_in_lockstep(&mut impatient(5), 5, 100);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn hungry_philosophers_are_woken_by_their_neighbours() {
+        let mut unit = impatient(5);
+        let results = run_in_lockstep(&mut unit, 5, 50);
+
+        assert!(results.values().any(|history| history.contains(&State::Hungry)));
+        assert!(unit.counters()[0].1 > 0);
+    }
+
+    #[test]
+    fn runs_on_threads_without_two_neighbours_eating() {
+        let runs = run_several_simulations(Arc::new(|size| Box::new(Tanenbaum::new(size))), 2, 5, 50);
+
+        for run in runs.results.iter() {
+            let run = run.lock().unwrap();
+            for id in 0..5 {
+                let together = run[&id].iter().zip(&run[&((id + 1) % 5)])
+                    .any(|(ours, theirs)| *ours == State::Eating && *theirs == State::Eating);
+                assert!(!together);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn needs_two_philosophers() {
+        Tanenbaum::new(1);
+    }
+}
@@ -0,0 +1,92 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::tanenbaum_impl::hungry::{pick_up, Hungry};
+use crate::dining_philosophers::tanenbaum_impl::monitor::MonitorInteraction;
+
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    monitor_interaction: Option<MonitorInteraction>,
+}
+
+impl Thinking {
+    pub fn new(monitor_interaction: MonitorInteraction) -> Thinking {
+        Thinking {
+            monitor_interaction: Some(monitor_interaction),
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.monitor_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(m) => match m.monitor.take_forks(m.position()) {
+                true => {
+                    debug!("{}: Neighbours not eating. Eating!", m.position());
+                    pick_up(m)
+                }
+                false => {
+                    debug!("{}: A neighbour is eating, to hungry", m.position());
+                    Box::new(Hungry::new(m))
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::tanenbaum_impl::monitor::{Monitor, MonitorInteraction};
+    use crate::dining_philosophers::tanenbaum_impl::thinking::Thinking;
+
+    fn seats(size: usize) -> Vec<MonitorInteraction> {
+        let monitor = Arc::new(Monitor::new(size, Duration::from_millis(0)));
+        Table::new(size).get_interactions().into_iter()
+            .map(|table_interaction| MonitorInteraction { table_interaction, monitor: Arc::clone(&monitor) })
+            .collect()
+    }
+
+    #[test]
+    fn state_is_thinking() {
+        assert_eq!(Thinking::new(seats(2).remove(0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn eats_when_no_neighbour_is_eating() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats(3).remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn becomes_hungry_next_to_an_eating_neighbour() {
+        let mut seats = seats(3);
+        let mut neighbour: Box<dyn StateMachine> = Box::new(Thinking::new(seats.remove(1)));
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats.remove(0)));
+
+        neighbour = neighbour.transition();
+        unit = unit.transition();
+
+        assert_eq!(neighbour.state(), State::Eating);
+        assert_eq!(unit.state(), State::Hungry);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(seats(2).remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
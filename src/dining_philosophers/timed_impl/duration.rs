@@ -0,0 +1,160 @@
+use rand::Rng;
+
+/// The distribution a number of ticks is drawn from. Every draw is at least one tick.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Duration {
+    /// Always the given number of ticks.
+    Fixed(usize),
+    /// Any number of ticks from the first to the second, both included. Build it with
+    /// `Duration::uniform`, which checks the bounds.
+    Uniform(usize, usize),
+    /// The number of tries until the first success, where every try succeeds with the given
+    /// probability. Build it with `Duration::geometric`, which checks the probability.
+    Geometric(f64),
+    /// An exponentially distributed time with the given mean, rounded up to whole ticks. Build it
+    /// with `Duration::exponential`, which checks the mean.
+    Exponential(f64),
+}
+
+impl Duration {
+    /// Any number of ticks from `low` to `high`, both included.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low` is greater than `high`.
+    pub fn uniform(low: usize, high: usize) -> Duration {
+        assert!(low <= high, "A uniform duration cannot run from {} down to {} ticks", low, high);
+        Duration::Uniform(low, high)
+    }
+
+    /// The tries until the first success, each succeeding with probability `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `p` is above zero and at most one.
+    pub fn geometric(p: f64) -> Duration {
+        assert!(p > 0_f64 && p <= 1_f64, "A geometric duration needs a probability above 0 and at most 1, got {}", p);
+        Duration::Geometric(p)
+    }
+
+    /// An exponential time with the given mean.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `mean` is a finite number of ticks no less than zero.
+    pub fn exponential(mean: f64) -> Duration {
+        assert!(mean >= 0_f64 && mean.is_finite(), "An exponential duration needs a finite mean of no less than 0 ticks, got {}", mean);
+        Duration::Exponential(mean)
+    }
+
+    pub fn sample<R: Rng>(&self, rng: &mut R) -> usize {
+        let ticks = match *self {
+            Duration::Fixed(ticks) => ticks,
+            Duration::Uniform(low, high) => rng.gen_range(low, high + 1),
+            Duration::Geometric(p) => {
+                if p >= 1_f64 {
+                    1
+                } else {
+                    ((1_f64 - rng.gen::<f64>()).ln() / (1_f64 - p).ln()).ceil() as usize
+                }
+            }
+            Duration::Exponential(mean) => (-mean * (1_f64 - rng.gen::<f64>()).ln()).ceil() as usize,
+        };
+        ticks.max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::timed_impl::duration::Duration;
+
+    fn samples(duration: Duration) -> Vec<usize> {
+        let mut rng = SeededRng::new(11);
+        (0..10_000).map(|_| duration.sample(&mut rng)).collect()
+    }
+
+    fn mean(samples: &[usize]) -> f64 {
+        samples.iter().sum::<usize>() as f64 / samples.len() as f64
+    }
+
+    #[test]
+    fn fixed_is_always_the_same() {
+        assert!(samples(Duration::Fixed(3)).iter().all(|ticks| *ticks == 3));
+    }
+
+    #[test]
+    fn fixed_lasts_at_least_one_tick() {
+        assert!(samples(Duration::Fixed(0)).iter().all(|ticks| *ticks == 1));
+    }
+
+    #[test]
+    fn uniform_covers_both_bounds() {
+        let samples = samples(Duration::uniform(2, 4));
+
+        assert!(samples.iter().all(|ticks| (2..=4).contains(ticks)));
+        assert!(samples.contains(&2));
+        assert!(samples.contains(&4));
+    }
+
+    #[test]
+    fn uniform_with_single_bound_is_fixed() {
+        assert!(samples(Duration::uniform(3, 3)).iter().all(|ticks| *ticks == 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn uniform_rejects_bounds_in_the_wrong_order() {
+        Duration::uniform(4, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn geometric_rejects_a_probability_of_zero() {
+        Duration::geometric(0_f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn geometric_rejects_a_probability_above_one() {
+        Duration::geometric(1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponential_rejects_a_negative_mean() {
+        Duration::exponential(-1_f64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn exponential_rejects_a_mean_that_is_not_a_number() {
+        Duration::exponential(f64::NAN);
+    }
+
+    #[test]
+    fn geometric_has_mean_of_one_over_p() {
+        let mean = mean(&samples(Duration::geometric(0.25)));
+
+        assert!((mean - 4_f64).abs() < 0.2, "mean {}", mean);
+    }
+
+    #[test]
+    fn certain_geometric_lasts_one_tick() {
+        assert!(samples(Duration::geometric(1_f64)).iter().all(|ticks| *ticks == 1));
+    }
+
+    #[test]
+    fn exponential_has_roughly_the_given_mean() {
+        // Rounding up to whole ticks adds about half a tick.
+        let mean = mean(&samples(Duration::exponential(5_f64)));
+
+        assert!((mean - 5.5).abs() < 0.3, "mean {}", mean);
+        assert!(samples(Duration::exponential(5_f64)).iter().all(|ticks| *ticks >= 1));
+    }
+
+    #[test]
+    fn is_reproducible_from_seed() {
+        assert_eq!(samples(Duration::exponential(2_f64)), samples(Duration::exponential(2_f64)));
+    }
+}
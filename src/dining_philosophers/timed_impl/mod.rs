@@ -0,0 +1,3 @@
+pub mod duration;
+pub mod timed;
+pub mod timing;
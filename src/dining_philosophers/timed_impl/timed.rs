@@ -0,0 +1,142 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::timed_impl::duration::Duration;
+
+/// Wraps another state machine so that every meal and every spell of thinking lasts a sampled
+/// number of ticks.
+///
+/// A philosopher that starts thinking stays put for the sampled time before the wrapped machine
+/// reaches for forks; if it then fails to get them it is hungry and tries again on every tick.
+/// While it lingers the wrapped machine is not asked to act, so it does not answer its
+/// neighbours either.
+pub struct Timed {
+    inner: Option<Box<dyn StateMachine + Send>>,
+    rng: Option<SeededRng>,
+    thinking: Duration,
+    eating: Duration,
+    /// The ticks left in the current state, counting this one, before the wrapped machine moves.
+    remaining: usize,
+}
+
+impl Timed {
+    pub fn new(inner: Box<dyn StateMachine + Send>, thinking: Duration, eating: Duration, mut rng: SeededRng) -> Timed {
+        let remaining = match inner.state() {
+            State::Thinking => thinking.sample(&mut rng),
+            State::Eating => eating.sample(&mut rng),
+            _ => 0,
+        };
+        Timed {
+            inner: Some(inner),
+            rng: Some(rng),
+            thinking,
+            eating,
+            remaining,
+        }
+    }
+}
+
+impl StateMachine for Timed {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match (self.inner.take(), self.rng.take()) {
+            (Some(inner), Some(mut rng)) => {
+                if self.remaining > 1 {
+                    return Box::new(Timed { inner: Some(inner), rng: Some(rng), remaining: self.remaining - 1, ..*self });
+                }
+
+                let before = inner.state();
+                let mut inner = inner;
+                inner = inner.transition();
+                let remaining = match inner.state() {
+                    State::Eating => self.eating.sample(&mut rng),
+                    State::Thinking if before != State::Thinking => self.thinking.sample(&mut rng),
+                    _ => 0,
+                };
+                Box::new(Timed { inner: Some(inner), rng: Some(rng), remaining, ..*self })
+            }
+            _ => { panic!("No longer valid") }
+        }
+    }
+
+    fn state(&self) -> State {
+        match &self.inner {
+            None => { panic!("No longer valid") }
+            Some(inner) => inner.state(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::random::SeededRng;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+    use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+    use crate::dining_philosophers::timed_impl::duration::Duration;
+    use crate::dining_philosophers::timed_impl::timed::Timed;
+
+    fn timed(table_interaction: TableInteraction, thinking: usize, eating: usize) -> Box<dyn StateMachine> {
+        let philosopher = Thinking::with_policy(table_interaction, Policy::resource_hierarchy());
+        Box::new(Timed::new(Box::new(philosopher), Duration::Fixed(thinking), Duration::Fixed(eating), SeededRng::new(0)))
+    }
+
+    fn history(mut unit: Box<dyn StateMachine>, ticks: usize) -> Vec<State> {
+        let mut history = vec![unit.state()];
+        for _ in 0..ticks {
+            unit = unit.transition();
+            history.push(unit.state());
+        }
+        history
+    }
+
+    #[test]
+    fn single_ticks_change_nothing() {
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+
+        let history = history(timed(table_interaction, 1, 1), 4);
+
+        assert_eq!(history, vec![State::Thinking, State::LeftThinking, State::Eating, State::Thinking, State::LeftThinking]);
+    }
+
+    #[test]
+    fn lingers_for_the_sampled_ticks() {
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+
+        let history = history(timed(table_interaction, 2, 3), 8);
+
+        assert_eq!(history, vec![
+            State::Thinking, State::Thinking,
+            State::LeftThinking,
+            State::Eating, State::Eating, State::Eating,
+            State::Thinking, State::Thinking,
+            State::LeftThinking,
+        ]);
+    }
+
+    #[test]
+    fn hungry_philosopher_tries_on_every_tick() {
+        let mut interactions = Table::new(2).get_interactions();
+        let table_interaction = interactions.remove(0);
+        let neighbour = interactions.remove(0);
+        let fork = neighbour.get_right_fork().unwrap();
+
+        let mut unit = timed(table_interaction, 2, 1);
+        unit = unit.transition(); // done thinking, fork 0 is taken
+        unit = unit.transition(); // still waiting
+        neighbour.return_right_fork(fork).unwrap();
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::LeftThinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let table_interaction = Table::new(2).get_interactions().remove(0);
+        let philosopher = Thinking::new(table_interaction);
+        let mut unit = Timed::new(Box::new(philosopher), Duration::Fixed(1), Duration::Fixed(1), SeededRng::new(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,118 @@
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::timed_impl::duration::Duration;
+use crate::dining_philosophers::timed_impl::timed::Timed;
+use crate::dining_philosophers::topology::Topology;
+
+/// Runs another strategy with meals and thoughts of sampled length.
+///
+/// Each seat draws its durations from its own generator, seeded from `seed` and its position.
+pub struct Timing {
+    inner: Box<dyn Strategy>,
+    thinking: Duration,
+    eating: Duration,
+    seed: u64,
+}
+
+impl Timing {
+    pub fn new(inner: Box<dyn Strategy>, thinking: Duration, eating: Duration, seed: u64) -> Timing {
+        Timing {
+            inner,
+            thinking,
+            eating,
+            seed,
+        }
+    }
+}
+
+impl Strategy for Timing {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        let rng = SeededRng::new(self.seed.wrapping_add(table_interaction.position as u64));
+        Box::new(Timed::new(self.inner.seat(table_interaction), self.thinking, self.eating, rng))
+    }
+
+    fn counters(&self) -> Counters {
+        self.inner.counters()
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        self.inner.fits(topology)
+    }
+
+    fn shares_table(&self) -> bool {
+        self.inner.shares_table()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::timed_impl::duration::Duration;
+    use crate::dining_philosophers::timed_impl::timing::Timing;
+    use crate::dining_philosophers::waiter_impl::arbitrator::GrantOrder;
+    use crate::dining_philosophers::waiter_impl::waiter::Waiter;
+
+    fn meal_lengths(history: &[State]) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        let mut current = 0;
+        for state in history {
+            match state {
+                State::Eating => current += 1,
+                _ if current > 0 => {
+                    lengths.push(current);
+                    current = 0;
+                }
+                _ => {}
+            }
+        }
+        lengths
+    }
+
+    #[test]
+    fn every_meal_lasts_the_fixed_time() {
+        let mut unit = Timing::new(Box::new(ResourceHierarchy::new()), Duration::Fixed(1), Duration::Fixed(4), 0);
+
+        let results = run_in_lockstep(&mut unit, 5, 100);
+
+        for history in results.values() {
+            let lengths = meal_lengths(history);
+            assert!(!lengths.is_empty());
+            assert!(lengths.iter().all(|length| *length == 4), "{:?}", lengths);
+        }
+    }
+
+    #[test]
+    fn long_meals_take_more_of_the_time() {
+        let eaten = |eating| {
+            let mut unit = Timing::new(Box::new(ResourceHierarchy::new()), Duration::Fixed(1), eating, 0);
+            let results = run_in_lockstep(&mut unit, 5, 200);
+            results.values().flatten().filter(|s| **s == State::Eating).count()
+        };
+
+        assert!(eaten(Duration::exponential(8_f64)) > eaten(Duration::Fixed(1)));
+    }
+
+    #[test]
+    fn is_reproducible_from_seed() {
+        let run = || {
+            let mut unit = Timing::new(Box::new(ResourceHierarchy::new()), Duration::geometric(0.3), Duration::uniform(1, 5), 9);
+            run_in_lockstep(&mut unit, 5, 100)
+        };
+
+        assert_eq!(run(), run());
+    }
+
+    #[test]
+    fn keeps_the_counters_of_the_wrapped_strategy() {
+        let mut unit = Timing::new(Box::new(Waiter::new(3, GrantOrder::Fifo)), Duration::Fixed(2), Duration::Fixed(2), 0);
+        run_in_lockstep(&mut unit, 3, 10);
+
+        assert_eq!(unit.counters().len(), 2);
+    }
+}
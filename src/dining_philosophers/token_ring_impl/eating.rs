@@ -0,0 +1,87 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::token_ring_impl::ring::RingInteraction;
+use crate::dining_philosophers::token_ring_impl::thinking::Thinking;
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+    ring_interaction: Option<RingInteraction>,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, ring_interaction: RingInteraction) -> Eating {
+        Eating {
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+            ring_interaction: Some(ring_interaction),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.ring_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(r) => {
+                debug!("{}: Put forks down, to thinking", r.position());
+                let refused = r.put_down_forks(self.left_fork.take().unwrap(), self.right_fork.take().unwrap());
+                match keep_refused(r.position(), refused) {
+                    Some((left_fork, right_fork)) => Box::new(Eating::new(left_fork, right_fork, r)),
+                    None => Box::new(Thinking::new(r)),
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::token_ring_impl::eating::Eating;
+    use crate::dining_philosophers::token_ring_impl::ring::{Passing, Ring, RingInteraction};
+
+    fn eating() -> (Eating, Arc<Mutex<Ring>>) {
+        let ring = Arc::new(Mutex::new(Ring::new(3, Passing::Nobody)));
+        for position in 0..3 {
+            ring.lock().unwrap().join(position);
+        }
+        let table_interaction = Table::new(3).get_interactions().remove(0);
+        let r = RingInteraction { table_interaction, ring: Arc::clone(&ring) };
+        let (left_fork, right_fork) = r.pick_up_forks().unwrap();
+        (Eating::new(left_fork, right_fork, r), ring)
+    }
+
+    #[test]
+    fn state_is_eating() {
+        assert_eq!(eating().0.state(), State::Eating);
+    }
+
+    #[test]
+    fn passes_the_token_on_after_eating() {
+        let (unit, ring) = eating();
+        let mut unit: Box<dyn StateMachine> = Box::new(unit);
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(ring.lock().unwrap().holder(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = eating().0;
+
+        unit.transition();
+        unit.transition();
+    }
+}
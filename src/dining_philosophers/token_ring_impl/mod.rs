@@ -0,0 +1,4 @@
+pub mod ring;
+pub mod thinking;
+pub mod eating;
+pub mod token_ring;
@@ -0,0 +1,210 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+
+/// Who the token holder lets eat besides itself.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Passing {
+    /// Only the token holder may eat.
+    Nobody,
+    /// Every other seat after the holder, stopping short of its left neighbour.
+    EveryOtherSeat,
+}
+
+/// The token passed around the seats of the ring by position, and the seats its holder has made
+/// eligible. Seats taken by other strategies at a mixed table are skipped.
+#[derive(Debug)]
+pub struct Ring {
+    holder: usize,
+    seated: Vec<bool>,
+    eligible: Vec<bool>,
+    passing: Passing,
+    passes: usize,
+    passed_on: usize,
+}
+
+impl Ring {
+    /// A ring for a table of the given size, with none of its seats taken yet.
+    pub fn new(number_of_philosophers: usize, passing: Passing) -> Ring {
+        Ring {
+            holder: 0,
+            seated: vec![false; number_of_philosophers],
+            eligible: vec![false; number_of_philosophers],
+            passing,
+            passes: 0,
+            passed_on: 0,
+        }
+    }
+
+    /// Takes a seat in the ring. The token starts at the first seat taken.
+    pub fn join(&mut self, position: usize) {
+        self.seated[position] = true;
+        if !self.seated[self.holder] {
+            self.holder = position;
+        }
+        self.pass_eligibility();
+    }
+
+    pub fn holder(&self) -> usize {
+        self.holder
+    }
+
+    /// The number of times the token has moved on.
+    pub fn passes(&self) -> usize {
+        self.passes
+    }
+
+    /// The meals eaten by philosophers the holder made eligible.
+    pub fn passed_on(&self) -> usize {
+        self.passed_on
+    }
+
+    pub fn may_eat(&self, position: usize) -> bool {
+        position == self.holder || self.eligible[position]
+    }
+
+    /// Records a finished meal. The holder hands the token to the next seat of the ring on its
+    /// right, and any other philosopher has used up its eligibility.
+    pub fn ate(&mut self, position: usize) {
+        if position == self.holder {
+            let size = self.seated.len();
+            self.holder = (1..=size).map(|offset| (self.holder + offset) % size)
+                .find(|position| self.seated[*position])
+                .unwrap_or(self.holder);
+            self.passes += 1;
+            self.pass_eligibility();
+        } else {
+            self.eligible[position] = false;
+            self.passed_on += 1;
+        }
+    }
+
+    fn pass_eligibility(&mut self) {
+        let size = self.eligible.len();
+        self.eligible = vec![false; size];
+        if self.passing == Passing::EveryOtherSeat {
+            for offset in (2..size.saturating_sub(1)).step_by(2) {
+                let position = (self.holder + offset) % size;
+                self.eligible[position] = self.seated[position];
+            }
+        }
+    }
+}
+
+/// A philosopher's way to the forks, which it may only take while the ring lets it eat.
+#[derive(Debug)]
+pub struct RingInteraction {
+    pub table_interaction: TableInteraction,
+    pub ring: Arc<Mutex<Ring>>,
+}
+
+impl PartialEq for RingInteraction {
+    fn eq(&self, other: &RingInteraction) -> bool {
+        self.table_interaction == other.table_interaction
+    }
+}
+
+impl RingInteraction {
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    /// Picks up both forks if the philosopher may eat and neither fork is in use.
+    pub fn pick_up_forks(&self) -> Option<(Fork, Fork)> {
+        let ring = self.ring.lock().unwrap();
+        match ring.may_eat(self.position()) {
+            true => self.table_interaction.get_both_forks(),
+            false => None,
+        }
+    }
+
+    /// Puts both forks down and tells the ring the philosopher has eaten, unless the table refuses
+    /// the forks, which are handed back with the reason.
+    pub fn put_down_forks(&self, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        self.table_interaction.return_both_forks(left_fork, right_fork)?;
+        self.ring.lock().unwrap().ate(self.position());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::token_ring_impl::ring::{Passing, Ring};
+
+    fn eligible(unit: &Ring, size: usize) -> Vec<usize> {
+        (0..size).filter(|p| unit.may_eat(*p)).collect()
+    }
+
+    /// A ring with every seat taken.
+    fn ring(size: usize, passing: Passing) -> Ring {
+        let mut unit = Ring::new(size, passing);
+        for position in 0..size {
+            unit.join(position);
+        }
+        unit
+    }
+
+    #[test]
+    fn only_the_holder_may_eat_without_passing() {
+        let unit = ring(5, Passing::Nobody);
+
+        assert_eq!(eligible(&unit, 5), vec![0]);
+    }
+
+    #[test]
+    fn holder_passes_eligibility_to_every_other_seat() {
+        assert_eq!(eligible(&ring(5, Passing::EveryOtherSeat), 5), vec![0, 2]);
+        assert_eq!(eligible(&ring(6, Passing::EveryOtherSeat), 6), vec![0, 2, 4]);
+        assert_eq!(eligible(&ring(3, Passing::EveryOtherSeat), 3), vec![0]);
+    }
+
+    #[test]
+    fn holder_hands_the_token_to_its_right_neighbour() {
+        let mut unit = ring(5, Passing::EveryOtherSeat);
+
+        unit.ate(0);
+
+        assert_eq!(unit.holder(), 1);
+        assert_eq!(eligible(&unit, 5), vec![1, 3]);
+        assert_eq!(unit.passes(), 1);
+    }
+
+    #[test]
+    fn token_wraps_around_the_table() {
+        let mut unit = ring(3, Passing::Nobody);
+
+        for position in 0..3 {
+            unit.ate(position);
+        }
+
+        assert_eq!(unit.holder(), 0);
+    }
+
+    #[test]
+    fn token_only_visits_the_seats_of_the_ring() {
+        let mut unit = Ring::new(6, Passing::EveryOtherSeat);
+        for position in 3..6 {
+            unit.join(position);
+        }
+        assert_eq!(eligible(&unit, 6), vec![3, 5]);
+
+        unit.ate(3);
+        unit.ate(4);
+
+        assert_eq!(unit.holder(), 5);
+        assert_eq!(eligible(&unit, 6), vec![3, 5]);
+        unit.ate(5);
+        assert_eq!(unit.holder(), 3);
+    }
+
+    #[test]
+    fn eligible_philosopher_eats_once() {
+        let mut unit = ring(5, Passing::EveryOtherSeat);
+
+        unit.ate(2);
+
+        assert!(!unit.may_eat(2));
+        assert_eq!((unit.holder(), unit.passes(), unit.passed_on()), (0, 0, 1));
+    }
+}
@@ -0,0 +1,99 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::token_ring_impl::eating::Eating;
+use crate::dining_philosophers::token_ring_impl::ring::RingInteraction;
+
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    ring_interaction: Option<RingInteraction>,
+}
+
+impl Thinking {
+    pub fn new(ring_interaction: RingInteraction) -> Thinking {
+        Thinking {
+            ring_interaction: Some(ring_interaction),
+        }
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.ring_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(r) => match r.pick_up_forks() {
+                Some((left_fork, right_fork)) => {
+                    debug!("{}: Allowed by the token. Eating!", r.position());
+                    Box::new(Eating::new(left_fork, right_fork, r))
+                }
+                None => {
+                    debug!("{}: Not allowed or forks in use, still thinking", r.position());
+                    Box::new(Thinking::new(r))
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::token_ring_impl::ring::{Passing, Ring, RingInteraction};
+    use crate::dining_philosophers::token_ring_impl::thinking::Thinking;
+
+    fn seats(size: usize, passing: Passing) -> Vec<RingInteraction> {
+        let ring = Arc::new(Mutex::new(Ring::new(size, passing)));
+        Table::new(size).get_interactions().into_iter()
+            .map(|table_interaction| {
+                ring.lock().unwrap().join(table_interaction.position);
+                RingInteraction { table_interaction, ring: Arc::clone(&ring) }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn state_is_thinking() {
+        assert_eq!(Thinking::new(seats(2, Passing::Nobody).remove(0)).state(), State::Thinking);
+    }
+
+    #[test]
+    fn token_holder_eats() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats(3, Passing::Nobody).remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn keeps_thinking_without_the_token() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats(5, Passing::Nobody).remove(2)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    fn eligible_philosopher_eats() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats(5, Passing::EveryOtherSeat).remove(2)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(seats(2, Passing::Nobody).remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::token_ring_impl::ring::{Passing, Ring, RingInteraction};
+use crate::dining_philosophers::token_ring_impl::thinking::Thinking;
+
+/// A token goes round the table by position, one seat after every meal of its holder. Only the
+/// holder, and the philosophers it passes eligibility to, may take forks. At a mixed table the
+/// token skips the seats of the other strategies.
+pub struct TokenRing {
+    ring: Arc<Mutex<Ring>>,
+}
+
+impl TokenRing {
+    /// The holder passes eligibility to every other seat, so up to half the table can eat.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than two philosophers, who would share their only fork.
+    pub fn new(number_of_philosophers: usize) -> TokenRing {
+        TokenRing::with_passing(number_of_philosophers, Passing::EveryOtherSeat)
+    }
+
+    pub fn with_passing(number_of_philosophers: usize, passing: Passing) -> TokenRing {
+        assert!(number_of_philosophers > 1);
+        TokenRing {
+            ring: Arc::new(Mutex::new(Ring::new(number_of_philosophers, passing))),
+        }
+    }
+}
+
+impl Strategy for TokenRing {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        self.ring.lock().unwrap().join(table_interaction.position);
+        Box::new(Thinking::new(RingInteraction {
+            table_interaction,
+            ring: Arc::clone(&self.ring),
+        }))
+    }
+
+    fn counters(&self) -> Counters {
+        let ring = self.ring.lock().unwrap();
+        vec![("token passes", ring.passes()), ("meals passed on", ring.passed_on())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::analysis::RunResults;
+    use crate::dining_philosophers::mixed::Mixed;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::{assert_neighbours_never_eat_together, run_in_lockstep};
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::token_ring_impl::ring::Passing;
+    use crate::dining_philosophers::token_ring_impl::token_ring::TokenRing;
+
+    fn meals(history: &[State]) -> usize {
+        history.iter().filter(|s| **s == State::Eating).count()
+    }
+
+    fn total_meals(results: &RunResults) -> usize {
+        results.values().map(|history| meals(history)).sum()
+    }
+
+    #[test]
+    fn everyone_eats() {
+        for &passing in &[Passing::Nobody, Passing::EveryOtherSeat] {
+            for size in 2..=16 {
+                let results = run_in_lockstep(&mut TokenRing::with_passing(size, passing), size, 4 * size);
+
+                for (id, history) in &results {
+                    assert!(history.contains(&State::Eating), "{:?}: philosopher {} of {} never ate", passing, id, size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let results = run_in_lockstep(&mut TokenRing::new(5), 5, 100);
+
+        assert_neighbours_never_eat_together(&results);
+    }
+
+    #[test]
+    fn without_passing_one_philosopher_eats_at_a_time_in_turn() {
+        let results = run_in_lockstep(&mut TokenRing::with_passing(5, Passing::Nobody), 5, 100);
+
+        let most_eating = (0..=100)
+            .map(|step| (0..5).filter(|id| results[id][step] == State::Eating).count())
+            .max();
+        assert_eq!(most_eating, Some(1));
+        let counts: Vec<usize> = (0..5).map(|id| meals(&results[&id])).collect();
+        assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1, "{:?}", counts);
+    }
+
+    #[test]
+    fn passing_eligibility_feeds_more_philosophers() {
+        let mut alone = TokenRing::with_passing(6, Passing::Nobody);
+        let mut passing = TokenRing::new(6);
+        let alone_meals = total_meals(&run_in_lockstep(&mut alone, 6, 60));
+        let passing_meals = total_meals(&run_in_lockstep(&mut passing, 6, 60));
+
+        assert!(passing_meals > alone_meals);
+        assert!(passing.counters()[1].1 > 0);
+        assert_eq!(alone.counters()[1], ("meals passed on", 0));
+    }
+
+    #[test]
+    fn eats_next_to_another_strategy() {
+        for &passing in &[Passing::Nobody, Passing::EveryOtherSeat] {
+            let mut mixed = Mixed::new()
+                .seating("resource-hierarchy", Box::new(ResourceHierarchy::new()), 3)
+                .seating("token-ring", Box::new(TokenRing::with_passing(6, passing)), 3)
+                .rotated(2);
+
+            let results = run_in_lockstep(&mut mixed, 6, 60);
+
+            for (id, name) in mixed.seats().iter().enumerate() {
+                assert!(meals(&results[&id]) > 1, "{:?}: {} at seat {} ate {} times", passing, name, id, meals(&results[&id]));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn needs_two_philosophers() {
+        TokenRing::new(1);
+    }
+}
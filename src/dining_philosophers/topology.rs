@@ -0,0 +1,223 @@
+/// Which forks every philosopher needs to eat, and so which philosophers conflict.
+///
+/// A strategy that reaches for a left and a right fork uses the first and the last fork a
+/// philosopher needs, so it only fits a topology where every philosopher needs two.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Topology {
+    forks: usize,
+    needs: Vec<Vec<usize>>,
+}
+
+impl Topology {
+    /// # Panics
+    ///
+    /// Panics if there are no philosophers, a philosopher needs no fork or a fork does not exist.
+    pub fn new(forks: usize, needs: Vec<Vec<usize>>) -> Topology {
+        assert!(!needs.is_empty());
+        for (position, forks_needed) in needs.iter().enumerate() {
+            assert!(!forks_needed.is_empty(), "Philosopher {} needs no fork", position);
+            assert!(forks_needed.iter().all(|fork| *fork < forks), "Philosopher {} needs a fork that does not exist", position);
+        }
+        Topology { forks, needs }
+    }
+
+    /// One fork for every edge of the conflict graph, needed by the two philosophers it joins.
+    /// Each philosopher lists its forks in the order of the edges.
+    pub fn from_conflicts(philosophers: usize, edges: &[(usize, usize)]) -> Topology {
+        let mut needs = vec![Vec::new(); philosophers];
+        for (fork, &(a, b)) in edges.iter().enumerate() {
+            needs[a].push(fork);
+            needs[b].push(fork);
+        }
+        Topology::new(edges.len(), needs)
+    }
+
+    /// The round table: philosopher `i` needs forks `i` and `i + 1`, the last one wrapping round to
+    /// fork 0. A lone philosopher has a single fork on either side.
+    pub fn ring(philosophers: usize) -> Topology {
+        Topology::new(philosophers, (0..philosophers).map(|i| vec![i, (i + 1) % philosophers]).collect())
+    }
+
+    /// The ring without the fork between the last philosopher and the first, who need one fork each.
+    pub fn line(philosophers: usize) -> Topology {
+        let edges: Vec<(usize, usize)> = (1..philosophers).map(|i| (i - 1, i)).collect();
+        Topology::from_conflicts(philosophers, &edges)
+    }
+
+    /// Philosopher 0 shares a fork with each of the others, who share nothing else.
+    pub fn star(philosophers: usize) -> Topology {
+        let edges: Vec<(usize, usize)> = (1..philosophers).map(|i| (0, i)).collect();
+        Topology::from_conflicts(philosophers, &edges)
+    }
+
+    /// Philosophers in rows and columns, sharing a fork with each one beside, above or below them.
+    pub fn grid(rows: usize, columns: usize) -> Topology {
+        let mut edges = Vec::new();
+        for row in 0..rows {
+            for column in 0..columns {
+                let position = row * columns + column;
+                if column + 1 < columns {
+                    edges.push((position, position + 1));
+                }
+                if row + 1 < rows {
+                    edges.push((position, position + columns));
+                }
+            }
+        }
+        Topology::from_conflicts(rows * columns, &edges)
+    }
+
+    /// Every pair of philosophers shares a fork.
+    pub fn complete(philosophers: usize) -> Topology {
+        let edges: Vec<(usize, usize)> = (0..philosophers)
+            .flat_map(|a| (a + 1..philosophers).map(move |b| (a, b)))
+            .collect();
+        Topology::from_conflicts(philosophers, &edges)
+    }
+
+    /// A preset by name: `ring`, `line`, `star`, `grid` (two rows) or `complete`. There is none
+    /// for a table without philosophers, a grid with an odd number of them, or a lone philosopher
+    /// who would share no fork in any preset but the ring.
+    pub fn preset(name: &str, philosophers: usize) -> Option<Topology> {
+        match name {
+            "ring" if philosophers > 0 => Some(Topology::ring(philosophers)),
+            "line" if philosophers > 1 => Some(Topology::line(philosophers)),
+            "star" if philosophers > 1 => Some(Topology::star(philosophers)),
+            "grid" if philosophers > 0 && philosophers.is_multiple_of(2) => Some(Topology::grid(2, philosophers / 2)),
+            "complete" if philosophers > 1 => Some(Topology::complete(philosophers)),
+            _ => None,
+        }
+    }
+
+    pub fn philosophers(&self) -> usize {
+        self.needs.len()
+    }
+
+    pub fn forks(&self) -> usize {
+        self.forks
+    }
+
+    pub fn needs(&self, position: usize) -> &[usize] {
+        &self.needs[position]
+    }
+
+    /// The philosophers who need the fork.
+    pub fn sharing(&self, fork: usize) -> Vec<usize> {
+        (0..self.philosophers())
+            .filter(|position| self.needs[*position].contains(&fork))
+            .collect()
+    }
+
+    /// Whether every philosopher needs two forks, a left and a right one.
+    pub fn pairs(&self) -> bool {
+        self.needs.iter().all(|forks_needed| forks_needed.len() == 2)
+    }
+
+    /// Whether every fork is shared by exactly two philosophers, and so lies on an edge of the
+    /// conflict graph.
+    pub fn is_conflict_graph(&self) -> bool {
+        (0..self.forks).all(|fork| self.sharing(fork).len() == 2)
+    }
+
+    /// Whether this is the round table of `Topology::ring`.
+    pub fn is_ring(&self) -> bool {
+        *self == Topology::ring(self.philosophers())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::topology::Topology;
+
+    #[test]
+    fn ring_shares_each_fork_between_neighbours() {
+        let unit = Topology::ring(3);
+
+        assert_eq!((unit.philosophers(), unit.forks()), (3, 3));
+        assert_eq!(unit.needs(2), &[2, 0]);
+        assert_eq!(unit.sharing(0), vec![0, 2]);
+        assert!(unit.pairs() && unit.is_ring());
+    }
+
+    #[test]
+    fn line_ends_need_one_fork() {
+        let unit = Topology::line(3);
+
+        assert_eq!(unit.forks(), 2);
+        assert_eq!((unit.needs(0), unit.needs(1), unit.needs(2)), (&[0][..], &[0, 1][..], &[1][..]));
+        assert!(!unit.pairs());
+    }
+
+    #[test]
+    fn star_centre_needs_a_fork_per_spoke() {
+        let unit = Topology::star(4);
+
+        assert_eq!(unit.needs(0), &[0, 1, 2]);
+        assert_eq!(unit.needs(3), &[2]);
+    }
+
+    #[test]
+    fn grid_shares_forks_with_the_philosophers_beside_above_and_below() {
+        let unit = Topology::grid(2, 3);
+
+        assert_eq!(unit.forks(), 7);
+        assert_eq!(unit.needs(1).len(), 3);
+        assert_eq!(unit.needs(0).len(), 2);
+        assert!(!unit.is_ring());
+    }
+
+    #[test]
+    fn complete_graph_shares_a_fork_between_every_pair() {
+        let unit = Topology::complete(4);
+
+        assert_eq!(unit.forks(), 6);
+        assert!((0..4).all(|position| unit.needs(position).len() == 3));
+    }
+
+    #[test]
+    fn two_separate_rings_pair_their_forks_but_are_no_ring() {
+        let unit = Topology::new(4, vec![vec![0, 1], vec![1, 0], vec![2, 3], vec![3, 2]]);
+
+        assert!(unit.pairs());
+        assert!(!unit.is_ring());
+    }
+
+    #[test]
+    fn conflict_graphs_share_every_fork_between_two() {
+        assert!(Topology::ring(2).is_conflict_graph());
+        assert!(Topology::grid(2, 3).is_conflict_graph());
+        assert!(!Topology::ring(1).is_conflict_graph());
+        assert!(!Topology::new(2, vec![vec![0], vec![0], vec![0, 1]]).is_conflict_graph());
+    }
+
+    #[test]
+    fn presets_by_name() {
+        assert_eq!(Topology::preset("star", 5), Some(Topology::star(5)));
+        assert_eq!(Topology::preset("grid", 6), Some(Topology::grid(2, 3)));
+        assert_eq!(Topology::preset("grid", 5), None);
+        assert_eq!(Topology::preset("torus", 5), None);
+    }
+
+    #[test]
+    fn no_preset_for_tables_it_cannot_lay_out() {
+        assert_eq!(Topology::preset("ring", 1), Some(Topology::ring(1)));
+        for name in ["ring", "line", "star", "grid", "complete"].iter() {
+            assert_eq!(Topology::preset(name, 0), None, "{}", name);
+        }
+        for name in ["line", "star", "complete"].iter() {
+            assert_eq!(Topology::preset(name, 1), None, "{}", name);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Philosopher 0 needs no fork")]
+    fn every_philosopher_needs_a_fork() {
+        Topology::line(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs a fork that does not exist")]
+    fn forks_must_exist() {
+        Topology::new(1, vec![vec![0, 1]]);
+    }
+}
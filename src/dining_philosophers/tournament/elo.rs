@@ -0,0 +1,137 @@
+/// The rating every strategy starts from.
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// How far one game can move a rating.
+pub const K: f64 = 32.0;
+
+/// One line of the leaderboard.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Standing {
+    pub name: &'static str,
+    pub rating: f64,
+    pub matches: usize,
+}
+
+/// Elo ratings of the strategies in a tournament.
+///
+/// Every pair of strategies at a table plays one game per match, won by the strategy with the
+/// higher score and drawn when the scores are equal. All the games of a match are rated against
+/// the ratings from before it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Leaderboard {
+    standings: Vec<Standing>,
+}
+
+impl Leaderboard {
+    pub fn new(names: &[&'static str]) -> Leaderboard {
+        Leaderboard {
+            standings: names.iter()
+                .map(|name| Standing { name, rating: INITIAL_RATING, matches: 0 })
+                .collect(),
+        }
+    }
+
+    pub fn rating(&self, name: &str) -> Option<f64> {
+        self.standings.iter()
+            .find(|standing| standing.name == name)
+            .map(|standing| standing.rating)
+    }
+
+    /// Rates one match from the score of every strategy at the table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a strategy is not on the leaderboard.
+    pub fn record(&mut self, scores: &[(&'static str, f32)]) {
+        let ratings: Vec<f64> = scores.iter()
+            .map(|(name, _)| self.rating(name).unwrap_or_else(|| panic!("{} is not on the leaderboard", name)))
+            .collect();
+        for (i, (name, score)) in scores.iter().enumerate() {
+            let change: f64 = scores.iter().enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(j, (_, other))| K * (result(*score, *other) - expected(ratings[i], ratings[j])))
+                .sum();
+            let standing = self.standings.iter_mut().find(|standing| standing.name == *name).unwrap();
+            standing.rating += change;
+            standing.matches += 1;
+        }
+    }
+
+    /// The standings from the highest rating down, in the order the strategies joined on a tie.
+    pub fn ranking(&self) -> Vec<Standing> {
+        let mut ranking = self.standings.clone();
+        ranking.sort_by(|a, b| b.rating.partial_cmp(&a.rating).unwrap());
+        ranking
+    }
+}
+
+/// The chance of a strategy rated `rating` beating one rated `other`.
+fn expected(rating: f64, other: f64) -> f64 {
+    1.0 / (1.0 + 10_f64.powf((other - rating) / 400.0))
+}
+
+fn result(score: f32, other: f32) -> f64 {
+    match score.partial_cmp(&other) {
+        Some(std::cmp::Ordering::Greater) => 1.0,
+        Some(std::cmp::Ordering::Less) => 0.0,
+        _ => 0.5,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::tournament::elo::{expected, Leaderboard, Standing, INITIAL_RATING, K};
+
+    #[test]
+    fn everyone_starts_level() {
+        let unit = Leaderboard::new(&["waiter", "backoff"]);
+
+        assert_eq!(unit.ranking(), vec![
+            Standing { name: "waiter", rating: INITIAL_RATING, matches: 0 },
+            Standing { name: "backoff", rating: INITIAL_RATING, matches: 0 },
+        ]);
+    }
+
+    #[test]
+    fn equal_ratings_expect_a_draw() {
+        assert_eq!(expected(1500.0, 1500.0), 0.5);
+        assert!(expected(1700.0, 1500.0) > 0.75);
+    }
+
+    #[test]
+    fn the_higher_score_wins_half_of_k_between_equals() {
+        let mut unit = Leaderboard::new(&["waiter", "backoff"]);
+
+        unit.record(&[("waiter", 0.25), ("backoff", 0.5)]);
+
+        assert_eq!(unit.rating("backoff"), Some(INITIAL_RATING + K / 2.0));
+        assert_eq!(unit.rating("waiter"), Some(INITIAL_RATING - K / 2.0));
+        assert_eq!(unit.ranking()[0], Standing { name: "backoff", rating: INITIAL_RATING + K / 2.0, matches: 1 });
+    }
+
+    #[test]
+    fn equal_scores_are_a_draw() {
+        let mut unit = Leaderboard::new(&["waiter", "backoff"]);
+
+        unit.record(&[("waiter", 0.5), ("backoff", 0.5)]);
+
+        assert_eq!(unit.rating("waiter"), Some(INITIAL_RATING));
+    }
+
+    #[test]
+    fn every_pair_at_a_table_plays_a_game() {
+        let mut unit = Leaderboard::new(&["a", "b", "c"]);
+
+        unit.record(&[("a", 0.75), ("b", 0.5), ("c", 0.25)]);
+
+        assert_eq!(unit.rating("a"), Some(INITIAL_RATING + K));
+        assert_eq!(unit.rating("b"), Some(INITIAL_RATING));
+        assert_eq!(unit.rating("c"), Some(INITIAL_RATING - K));
+    }
+
+    #[test]
+    #[should_panic(expected = "waiter is not on the leaderboard")]
+    fn only_rates_entrants() {
+        Leaderboard::new(&["backoff"]).record(&[("waiter", 0.5), ("backoff", 0.5)]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod elo;
+#[allow(clippy::module_inception)]
+pub mod tournament;
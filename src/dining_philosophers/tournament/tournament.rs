@@ -0,0 +1,179 @@
+use std::fmt;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::dining_philosophers::analysis::{score_by_strategy, Seating};
+use crate::dining_philosophers::mixed::Mixed;
+use crate::dining_philosophers::random::SeededRng;
+use crate::dining_philosophers::registry::Registry;
+use crate::dining_philosophers::simulation::run_in_lockstep;
+use crate::dining_philosophers::tournament::elo::Leaderboard;
+
+/// How the matches are drawn. All randomness comes from `seed`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Settings {
+    pub matches: usize,
+    pub smallest_table: usize,
+    pub largest_table: usize,
+    /// The most strategies seated at one table.
+    pub strategies_per_table: usize,
+    pub rounds: usize,
+    pub seed: u64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            matches: 200,
+            smallest_table: 3,
+            largest_table: 8,
+            strategies_per_table: 3,
+            rounds: 100,
+            seed: 0,
+        }
+    }
+}
+
+/// One table of the tournament: who sat where and how each strategy scored.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Match {
+    pub seating: Seating,
+    pub scores: Vec<(&'static str, f32)>,
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} seats [{}]:", self.seating.len(), self.seating.join(", "))?;
+        for (name, score) in &self.scores {
+            write!(f, " {} {}", name, score)?;
+        }
+        Ok(())
+    }
+}
+
+/// Every match in the order it was played, and the leaderboard after the last one.
+#[derive(Debug, PartialEq)]
+pub struct Outcome {
+    pub matches: Vec<Match>,
+    pub leaderboard: Leaderboard,
+}
+
+/// Plays the entrants against each other at mixed tables of random sizes. Each table seats two
+/// or more of them, each at a run of neighbouring seats, rotated by a random number of seats.
+/// The tables run in lockstep, so the same seed plays the same matches. Entrants that do not
+/// share the forks of the `Table` cannot sit at a mixed table and are turned away.
+///
+/// # Panics
+///
+/// Panics if there are fewer than two entrants left, an entrant is not registered, or the
+/// smallest table cannot seat two strategies.
+pub fn play(registry: &Registry, entrants: &[&'static str], settings: &Settings) -> Outcome {
+    assert!(settings.smallest_table > 1 && settings.smallest_table <= settings.largest_table && settings.strategies_per_table > 1);
+    let entrants: Vec<&'static str> = entrants.iter().cloned()
+        .filter(|name| {
+            let new_strategy = registry.get(name).unwrap_or_else(|| panic!("Unknown strategy {}", name));
+            let shares_table = new_strategy(settings.smallest_table).shares_table();
+            if !shares_table {
+                info!("{} keeps forks of its own and sits the tournament out", name);
+            }
+            shares_table
+        })
+        .collect();
+    assert!(entrants.len() > 1);
+    let mut rng = SeededRng::new(settings.seed);
+    let mut leaderboard = Leaderboard::new(&entrants);
+    let mut matches = Vec::with_capacity(settings.matches);
+
+    for number in 0..settings.matches {
+        let size = rng.gen_range(settings.smallest_table, settings.largest_table + 1);
+        let most = settings.strategies_per_table.min(size).min(entrants.len());
+        let count = rng.gen_range(2, most + 1);
+        let mut chosen: Vec<&'static str> = entrants.choose_multiple(&mut rng, count).cloned().collect();
+        chosen.shuffle(&mut rng);
+
+        let mut mixed = chosen.iter().enumerate().fold(Mixed::new(), |mixed, (i, name)| {
+            let new_strategy = registry.get(name).unwrap_or_else(|| panic!("Unknown strategy {}", name));
+            let seats = size / count + if i < size % count { 1 } else { 0 };
+            mixed.seating(name, new_strategy(size), seats)
+        }).rotated(rng.gen_range(0, size));
+        let results = run_in_lockstep(&mut mixed, size, settings.rounds);
+
+        let played = Match {
+            seating: mixed.seats(),
+            scores: score_by_strategy(&results, &mixed.seats()),
+        };
+        info!("Match {}: {}", number, played);
+        leaderboard.record(&played.scores);
+        matches.push(played);
+    }
+
+    Outcome { matches, leaderboard }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::registry::Registry;
+    use crate::dining_philosophers::tournament::elo::INITIAL_RATING;
+    use crate::dining_philosophers::tournament::tournament::{play, Settings};
+
+    fn settings(seed: u64) -> Settings {
+        Settings { matches: 20, rounds: 40, seed, ..Settings::default() }
+    }
+
+    const ENTRANTS: [&str; 4] = ["resource-hierarchy", "waiter", "backoff", "always-thinking"];
+
+    #[test]
+    fn same_seed_plays_the_same_matches() {
+        let registry = Registry::new();
+
+        assert_eq!(play(&registry, &ENTRANTS, &settings(3)), play(&registry, &ENTRANTS, &settings(3)));
+        assert_ne!(play(&registry, &ENTRANTS, &settings(3)).matches, play(&registry, &ENTRANTS, &settings(4)).matches);
+    }
+
+    #[test]
+    fn tables_vary_in_size_and_seat_several_strategies() {
+        let outcome = play(&Registry::new(), &ENTRANTS, &settings(0));
+
+        for played in &outcome.matches {
+            assert!((3..=8).contains(&played.seating.len()), "{}", played);
+            assert!((2..=3).contains(&played.scores.len()), "{}", played);
+        }
+        let sizes: Vec<usize> = outcome.matches.iter().map(|played| played.seating.len()).collect();
+        assert!(sizes.iter().any(|size| *size != sizes[0]));
+    }
+
+    #[test]
+    fn strategies_change_seats_between_matches() {
+        let outcome = play(&Registry::new(), &ENTRANTS, &settings(0));
+
+        let first_seats: Vec<&str> = outcome.matches.iter().map(|played| played.seating[0]).collect();
+        for name in ENTRANTS.iter() {
+            assert!(first_seats.contains(name), "{} never sat at seat 0", name);
+        }
+    }
+
+    #[test]
+    fn the_baseline_comes_last() {
+        let outcome = play(&Registry::new(), &ENTRANTS, &settings(0));
+
+        let ranking = outcome.leaderboard.ranking();
+        assert_eq!(ranking.last().unwrap().name, "always-thinking");
+        assert!(ranking[0].rating > INITIAL_RATING);
+        assert_eq!(ranking.iter().map(|standing| standing.matches).sum::<usize>(), outcome.matches.iter().map(|played| played.scores.len()).sum());
+    }
+
+    #[test]
+    fn turns_away_strategies_with_forks_of_their_own() {
+        let outcome = play(&Registry::new(), &["chandy-misra", "waiter", "backoff"], &settings(0));
+
+        assert!(outcome.matches.iter().all(|played| !played.seating.contains(&"chandy-misra")));
+        assert!(outcome.leaderboard.ranking().iter().all(|standing| standing.name != "chandy-misra"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn needs_two_entrants() {
+        play(&Registry::new(), &["waiter"], &settings(0));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+
+/// Runs a typestate philosopher inside the dynamic `Philosopher`. `StateMachine::transition` only
+/// borrows the state, so the adapter is where the check that it moves on once happens at run time.
+pub struct Adapter {
+    phase: Option<Phase>,
+}
+
+impl Adapter {
+    pub fn new(phase: Phase) -> Adapter {
+        Adapter { phase: Some(phase) }
+    }
+}
+
+impl StateMachine for Adapter {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.phase.take() {
+            None => { panic!("No longer valid") }
+            Some(phase) => Box::new(Adapter::new(phase.next())),
+        }
+    }
+
+    fn state(&self) -> State {
+        match &self.phase {
+            None => { panic!("No longer valid") }
+            Some(phase) => phase.state(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::philosopher::Philosopher;
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::typestate_impl::adapter::Adapter;
+    use crate::dining_philosophers::typestate_impl::phase::Phase;
+    use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+    fn thinking() -> Adapter {
+        Adapter::new(Phase::Thinking(Thinking::new(Table::new(2).get_interactions().pop().unwrap())))
+    }
+
+    #[test]
+    fn runs_inside_a_philosopher() {
+        let mut philosopher = Philosopher::new(0, Box::new(thinking()));
+
+        philosopher.act();
+        philosopher.act();
+
+        assert_eq!(philosopher.history(), &vec![State::Thinking, State::LeftThinking, State::Eating]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = thinking();
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,111 @@
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::typestate_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::right_thinking::RightThinking;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// A philosopher holding both its forks.
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Fork,
+    right_fork: Fork,
+    table_interaction: TableInteraction,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, table_interaction: TableInteraction) -> Eating {
+        Eating { left_fork, right_fork, table_interaction }
+    }
+
+    /// Puts the left fork back, or keeps eating when the table refuses it.
+    pub fn put_left(self) -> Result<RightThinking, (ForkError, Eating)> {
+        match self.table_interaction.return_left_fork(self.left_fork) {
+            Ok(()) => Ok(RightThinking::new(self.right_fork, self.table_interaction)),
+            Err((e, left_fork)) => Err((e, Eating::new(left_fork, self.right_fork, self.table_interaction))),
+        }
+    }
+
+    pub fn put_right(self) -> Result<LeftThinking, (ForkError, Eating)> {
+        match self.table_interaction.return_right_fork(self.right_fork) {
+            Ok(()) => Ok(LeftThinking::new(self.left_fork, self.table_interaction)),
+            Err((e, right_fork)) => Err((e, Eating::new(self.left_fork, right_fork, self.table_interaction))),
+        }
+    }
+
+    /// Puts both forks back, or neither when the table refuses either one.
+    pub fn put_both(self) -> Result<Thinking, (ForkError, Eating)> {
+        match self.table_interaction.return_both_forks(self.left_fork, self.right_fork) {
+            Ok(()) => Ok(Thinking::new(self.table_interaction)),
+            Err((e, (left_fork, right_fork))) => Err((e, Eating::new(left_fork, right_fork, self.table_interaction))),
+        }
+    }
+
+    /// Puts the left fork down first.
+    pub fn next(self) -> Phase {
+        let position = self.table_interaction.position;
+        debug!("{}: Drop left, to right thinking", position);
+        match self.put_left() {
+            Ok(right_thinking) => Phase::RightThinking(right_thinking),
+            Err((e, eating)) => {
+                error!("{}: Kept a fork the table refused: {}", position, e);
+                Phase::Eating(eating)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::fork::{Fork, ForkError};
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::typestate_impl::eating::Eating;
+
+    fn eating() -> (Eating, TableInteraction) {
+        let mut interactions = Table::new(2).get_interactions();
+        let table_interaction = interactions.pop().unwrap();
+        let (left_fork, right_fork) = table_interaction.get_both_forks().unwrap();
+        (Eating::new(left_fork, right_fork, table_interaction), interactions.pop().unwrap())
+    }
+
+    #[test]
+    fn puts_left_fork_down_first() {
+        assert_eq!(eating().0.next().state(), State::RightThinking);
+    }
+
+    #[test]
+    fn put_right_keeps_the_left_fork() {
+        let (unit, neighbour) = eating();
+
+        unit.put_right().unwrap();
+
+        assert!(neighbour.left_fork_on_table());
+        assert!(!neighbour.right_fork_on_table());
+    }
+
+    #[test]
+    fn put_both_returns_every_fork() {
+        let (unit, neighbour) = eating();
+        let table = Arc::clone(&neighbour.table);
+
+        unit.put_both().unwrap();
+
+        assert_eq!(*table.lock().unwrap(), Table::new(2));
+    }
+
+    #[test]
+    fn keeps_eating_when_the_table_refuses_a_fork() {
+        let (unit, neighbour) = eating();
+        let Eating { left_fork, right_fork, table_interaction } = unit;
+        let unit = Eating::new(Fork::new(left_fork.id()), right_fork, table_interaction);
+
+        let (e, unit) = unit.put_both().unwrap_err();
+
+        assert_eq!(e, ForkError::NotHolder { fork: 1, holder: None, philosopher: 1 });
+        assert!(!neighbour.left_fork_on_table() && !neighbour.right_fork_on_table());
+        assert_eq!(unit.next().state(), State::Eating);
+    }
+}
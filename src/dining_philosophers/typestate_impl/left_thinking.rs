@@ -0,0 +1,84 @@
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::typestate_impl::eating::Eating;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// A philosopher holding its left fork and nothing else.
+#[derive(Debug, PartialEq)]
+pub struct LeftThinking {
+    left_fork: Fork,
+    table_interaction: TableInteraction,
+}
+
+impl LeftThinking {
+    pub fn new(left_fork: Fork, table_interaction: TableInteraction) -> LeftThinking {
+        LeftThinking { left_fork, table_interaction }
+    }
+
+    /// Picks up the right fork and eats, or keeps holding the left one when it is taken.
+    pub fn take_right(self) -> Result<Eating, LeftThinking> {
+        match self.table_interaction.get_right_fork() {
+            Some(fork) => Ok(Eating::new(self.left_fork, fork, self.table_interaction)),
+            None => Err(self),
+        }
+    }
+
+    /// Puts the left fork back, or keeps holding it when the table refuses it.
+    pub fn put_left(self) -> Result<Thinking, (ForkError, LeftThinking)> {
+        match self.table_interaction.return_left_fork(self.left_fork) {
+            Ok(()) => Ok(Thinking::new(self.table_interaction)),
+            Err((e, left_fork)) => Err((e, LeftThinking::new(left_fork, self.table_interaction))),
+        }
+    }
+
+    /// Eats if the right fork is free, otherwise gives way and puts the left one back.
+    pub fn next(self) -> Phase {
+        let position = self.table_interaction.position;
+        match self.take_right() {
+            Ok(eating) => {
+                debug!("{}: Got right. Eating!", position);
+                Phase::Eating(eating)
+            }
+            Err(left_thinking) => {
+                debug!("{}: Not right, back to thinking", position);
+                match left_thinking.put_left() {
+                    Ok(thinking) => Phase::Thinking(thinking),
+                    Err((e, left_thinking)) => {
+                        error!("{}: Kept a fork the table refused: {}", position, e);
+                        Phase::LeftThinking(left_thinking)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::typestate_impl::left_thinking::LeftThinking;
+
+    #[test]
+    fn eats_when_right_fork_is_free() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_left_fork().unwrap();
+        let unit = LeftThinking::new(fork, table_interaction);
+
+        assert_eq!(unit.next().state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_left_fork_back_when_right_is_taken() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_left_fork().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+        let unit = LeftThinking::new(fork, table_interaction);
+
+        assert_eq!(unit.next().state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(1));
+    }
+}
@@ -0,0 +1,7 @@
+pub mod thinking;
+pub mod left_thinking;
+pub mod right_thinking;
+pub mod eating;
+pub mod phase;
+pub mod adapter;
+pub mod typestate;
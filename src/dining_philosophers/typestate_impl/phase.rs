@@ -0,0 +1,55 @@
+use crate::dining_philosophers::philosopher::state_machine::State;
+use crate::dining_philosophers::typestate_impl::eating::Eating;
+use crate::dining_philosophers::typestate_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::typestate_impl::right_thinking::RightThinking;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// Whichever state a philosopher is in, for when that is only known at run time.
+#[derive(Debug, PartialEq)]
+pub enum Phase {
+    Thinking(Thinking),
+    LeftThinking(LeftThinking),
+    RightThinking(RightThinking),
+    Eating(Eating),
+}
+
+impl Phase {
+    pub fn next(self) -> Phase {
+        match self {
+            Phase::Thinking(thinking) => thinking.next(),
+            Phase::LeftThinking(left_thinking) => left_thinking.next(),
+            Phase::RightThinking(right_thinking) => right_thinking.next(),
+            Phase::Eating(eating) => eating.next(),
+        }
+    }
+
+    pub fn state(&self) -> State {
+        match self {
+            Phase::Thinking(_) => State::Thinking,
+            Phase::LeftThinking(_) => State::LeftThinking,
+            Phase::RightThinking(_) => State::RightThinking,
+            Phase::Eating(_) => State::Eating,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::typestate_impl::phase::Phase;
+    use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+    #[test]
+    fn goes_round_the_states_of_a_lone_pair() {
+        let mut unit = Phase::Thinking(Thinking::new(Table::new(2).get_interactions().pop().unwrap()));
+        let mut states = Vec::new();
+
+        for _ in 0..4 {
+            unit = unit.next();
+            states.push(unit.state());
+        }
+
+        assert_eq!(states, vec![State::LeftThinking, State::Eating, State::RightThinking, State::Eating]);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::typestate_impl::eating::Eating;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// A philosopher holding its right fork and nothing else.
+#[derive(Debug, PartialEq)]
+pub struct RightThinking {
+    right_fork: Fork,
+    table_interaction: TableInteraction,
+}
+
+impl RightThinking {
+    pub fn new(right_fork: Fork, table_interaction: TableInteraction) -> RightThinking {
+        RightThinking { right_fork, table_interaction }
+    }
+
+    /// Picks up the left fork and eats, or keeps holding the right one when it is taken.
+    pub fn take_left(self) -> Result<Eating, RightThinking> {
+        match self.table_interaction.get_left_fork() {
+            Some(fork) => Ok(Eating::new(fork, self.right_fork, self.table_interaction)),
+            None => Err(self),
+        }
+    }
+
+    /// Puts the right fork back, or keeps holding it when the table refuses it.
+    pub fn put_right(self) -> Result<Thinking, (ForkError, RightThinking)> {
+        match self.table_interaction.return_right_fork(self.right_fork) {
+            Ok(()) => Ok(Thinking::new(self.table_interaction)),
+            Err((e, right_fork)) => Err((e, RightThinking::new(right_fork, self.table_interaction))),
+        }
+    }
+
+    /// Eats if the left fork is free, otherwise gives way and puts the right one back.
+    pub fn next(self) -> Phase {
+        let position = self.table_interaction.position;
+        match self.take_left() {
+            Ok(eating) => {
+                debug!("{}: Got left. Eating!", position);
+                Phase::Eating(eating)
+            }
+            Err(right_thinking) => {
+                debug!("{}: Not left, back to thinking", position);
+                match right_thinking.put_right() {
+                    Ok(thinking) => Phase::Thinking(thinking),
+                    Err((e, right_thinking)) => {
+                        error!("{}: Kept a fork the table refused: {}", position, e);
+                        Phase::RightThinking(right_thinking)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::typestate_impl::right_thinking::RightThinking;
+
+    #[test]
+    fn eats_when_left_fork_is_free() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_right_fork().unwrap();
+        let unit = RightThinking::new(fork, table_interaction);
+
+        assert_eq!(unit.next().state(), State::Eating);
+    }
+
+    #[test]
+    fn puts_right_fork_back_when_left_is_taken() {
+        let table_interaction = Table::new(1).get_interactions().pop().unwrap();
+        let fork = table_interaction.get_right_fork().unwrap();
+        let table = Arc::clone(&table_interaction.table);
+        let unit = RightThinking::new(fork, table_interaction);
+
+        assert_eq!(unit.next().state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(1));
+    }
+}
@@ -0,0 +1,104 @@
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::typestate_impl::left_thinking::LeftThinking;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::right_thinking::RightThinking;
+
+/// A philosopher holding no fork.
+///
+/// Every transition consumes the state it leaves, so a state cannot be moved on twice:
+///
+/// ```compile_fail,E0382
+/// use dining_philosophers::dining_philosophers::table::Table;
+/// use dining_philosophers::dining_philosophers::typestate_impl::thinking::Thinking;
+///
+/// let thinking = Thinking::new(Table::new(2).get_interactions().pop().unwrap());
+/// thinking.next();
+/// thinking.next();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    table_interaction: TableInteraction,
+}
+
+impl Thinking {
+    pub fn new(table_interaction: TableInteraction) -> Thinking {
+        Thinking { table_interaction }
+    }
+
+    /// Picks up the left fork, or keeps thinking when it is taken.
+    pub fn take_left(self) -> Result<LeftThinking, Thinking> {
+        match self.table_interaction.get_left_fork() {
+            Some(fork) => Ok(LeftThinking::new(fork, self.table_interaction)),
+            None => Err(self),
+        }
+    }
+
+    /// Picks up the right fork, or keeps thinking when it is taken.
+    pub fn take_right(self) -> Result<RightThinking, Thinking> {
+        match self.table_interaction.get_right_fork() {
+            Some(fork) => Ok(RightThinking::new(fork, self.table_interaction)),
+            None => Err(self),
+        }
+    }
+
+    /// Reaches for the left fork first, then the right.
+    pub fn next(self) -> Phase {
+        let position = self.table_interaction.position;
+        self.take_left()
+            .map(|left_thinking| {
+                debug!("{}: Got left, to left thinking", position);
+                Phase::LeftThinking(left_thinking)
+            })
+            .or_else(|thinking| thinking.take_right().map(|right_thinking| {
+                debug!("{}: Got right, to right thinking", position);
+                Phase::RightThinking(right_thinking)
+            }))
+            .unwrap_or_else(|thinking| {
+                debug!("{}: No forks, still thinking", position);
+                Phase::Thinking(thinking)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+    #[test]
+    fn takes_left_fork_first() {
+        let unit = Thinking::new(Table::new(2).get_interactions().pop().unwrap());
+
+        assert_eq!(unit.next().state(), State::LeftThinking);
+    }
+
+    #[test]
+    fn takes_right_fork_when_left_is_taken() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let _left_fork = table_interaction.get_left_fork().unwrap();
+        let unit = Thinking::new(table_interaction);
+
+        assert_eq!(unit.next().state(), State::RightThinking);
+    }
+
+    #[test]
+    fn keeps_thinking_without_forks() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let _forks = table_interaction.get_both_forks().unwrap();
+        let unit = Thinking::new(table_interaction);
+
+        assert_eq!(unit.next().state(), State::Thinking);
+    }
+
+    #[test]
+    fn failed_take_hands_back_the_same_state() {
+        let table_interaction = Table::new(2).get_interactions().pop().unwrap();
+        let _left_fork = table_interaction.get_left_fork().unwrap();
+        let unit = Thinking::new(table_interaction);
+
+        let unit = unit.take_left().unwrap_err();
+
+        assert!(unit.take_right().is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::topology::Topology;
+use crate::dining_philosophers::typestate_impl::adapter::Adapter;
+use crate::dining_philosophers::typestate_impl::phase::Phase;
+use crate::dining_philosophers::typestate_impl::thinking::Thinking;
+
+/// The philosophers of the original state machine, who give way, written with typestates.
+pub struct Typestate {}
+
+impl Typestate {
+    pub fn new() -> Typestate {
+        Typestate {}
+    }
+}
+
+impl Default for Typestate {
+    fn default() -> Self {
+        Typestate::new()
+    }
+}
+
+impl Strategy for Typestate {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Adapter::new(Phase::Thinking(Thinking::new(table_interaction))))
+    }
+
+    fn fits(&self, topology: &Topology) -> bool {
+        topology.pairs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+    use crate::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::ResourceHierarchy;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::typestate_impl::typestate::Typestate;
+
+    #[test]
+    fn behaves_like_the_original_state_machine() {
+        for size in 1..=6 {
+            let typestate = run_in_lockstep(&mut Typestate::new(), size, 20);
+            let original = run_in_lockstep(&mut ResourceHierarchy::with_policy(|_| Policy::default()), size, 20);
+
+            assert_eq!(typestate, original, "{} philosophers", size);
+        }
+    }
+
+    #[test]
+    fn neighbours_never_eat_together() {
+        let size = 5;
+        let results = run_in_lockstep(&mut Typestate::new(), size, 50);
+
+        for position in 0..size {
+            let right = &results[&((position + 1) % size)];
+            for (state, right_state) in results[&position].iter().zip(right) {
+                assert!(!(*state == State::Eating && *right_state == State::Eating));
+            }
+        }
+    }
+}
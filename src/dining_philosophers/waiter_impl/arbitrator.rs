@@ -0,0 +1,218 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::fork::{Fork, ForkError};
+use crate::dining_philosophers::table::TableInteraction;
+
+/// The order in which waiting philosophers are served when they compete for a fork.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GrantOrder {
+    /// A neighbour who asked earlier is served first.
+    Fifo,
+    /// A neighbour who has gone without through more of the table's meals is served first.
+    Hunger,
+}
+
+/// The waiter: hands out both forks of a seat at once, or none.
+#[derive(Debug)]
+pub struct Arbitrator {
+    grant_order: GrantOrder,
+    /// Seats that asked and have not been served yet, in the order they first asked.
+    queue: Vec<usize>,
+    /// The number of meals served at the table when each seat last ate.
+    last_meal: Vec<usize>,
+    grants: usize,
+    refusals: usize,
+}
+
+impl Arbitrator {
+    pub fn new(number_of_philosophers: usize, grant_order: GrantOrder) -> Arbitrator {
+        Arbitrator {
+            grant_order,
+            queue: Vec::new(),
+            last_meal: vec![0; number_of_philosophers],
+            grants: 0,
+            refusals: 0,
+        }
+    }
+
+    pub fn grants(&self) -> usize {
+        self.grants
+    }
+
+    pub fn refusals(&self) -> usize {
+        self.refusals
+    }
+
+    pub fn request(&mut self, table_interaction: &TableInteraction) -> Option<(Fork, Fork)> {
+        let position = table_interaction.position;
+        if !self.queue.contains(&position) {
+            self.queue.push(position);
+        }
+
+        let forks = match self.neighbour_goes_first(position) {
+            true => None,
+            false => table_interaction.get_both_forks(),
+        };
+
+        match forks {
+            Some(_) => {
+                self.queue.retain(|p| *p != position);
+                self.grants += 1;
+                self.last_meal[position] = self.grants;
+            }
+            None => {
+                self.refusals += 1;
+            }
+        }
+        forks
+    }
+
+    /// Puts both forks back on the table, or hands them back with the reason it refused them.
+    pub fn release(&mut self, table_interaction: &TableInteraction, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        table_interaction.return_both_forks(left_fork, right_fork)
+    }
+
+    fn neighbour_goes_first(&self, position: usize) -> bool {
+        let size = self.last_meal.len();
+        let neighbours = [(position + size - 1) % size, (position + 1) % size];
+        neighbours.iter()
+            .filter(|n| **n != position)
+            .any(|n| self.has_priority(*n, position))
+    }
+
+    fn has_priority(&self, waiting: usize, position: usize) -> bool {
+        let queued_at = |p: usize| self.queue.iter().position(|q| *q == p);
+        match (queued_at(waiting), queued_at(position)) {
+            (Some(theirs), Some(ours)) => match self.grant_order {
+                GrantOrder::Fifo => theirs < ours,
+                GrantOrder::Hunger => (self.hunger(waiting), ours) > (self.hunger(position), theirs),
+            },
+            _ => false,
+        }
+    }
+
+    fn hunger(&self, position: usize) -> usize {
+        self.grants - self.last_meal[position]
+    }
+}
+
+/// A philosopher's line to the waiter, shared the way `TableInteraction` shares the table.
+#[derive(Debug)]
+pub struct ArbitratorInteraction {
+    pub table_interaction: TableInteraction,
+    pub arbitrator: Arc<Mutex<Arbitrator>>,
+}
+
+impl PartialEq for ArbitratorInteraction {
+    fn eq(&self, other: &ArbitratorInteraction) -> bool {
+        self.table_interaction == other.table_interaction
+    }
+}
+
+impl ArbitratorInteraction {
+    pub fn position(&self) -> usize {
+        self.table_interaction.position
+    }
+
+    pub fn ask_for_forks(&self) -> Option<(Fork, Fork)> {
+        self.arbitrator.lock().unwrap().request(&self.table_interaction)
+    }
+
+    pub fn return_forks(&self, left_fork: Fork, right_fork: Fork) -> Result<(), (ForkError, (Fork, Fork))> {
+        self.arbitrator.lock().unwrap().release(&self.table_interaction, left_fork, right_fork)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::table::{Table, TableInteraction};
+    use crate::dining_philosophers::waiter_impl::arbitrator::{Arbitrator, GrantOrder};
+
+    fn seats(size: usize) -> Vec<TableInteraction> {
+        Table::new(size).get_interactions()
+    }
+
+    #[test]
+    fn grants_both_forks_when_free() {
+        let seats = seats(3);
+        let mut unit = Arbitrator::new(3, GrantOrder::Fifo);
+
+        assert_ne!(unit.request(&seats[0]), None);
+        assert_eq!((unit.grants(), unit.refusals()), (1, 0));
+    }
+
+    #[test]
+    fn refuses_when_a_neighbour_is_eating() {
+        let seats = seats(3);
+        let mut unit = Arbitrator::new(3, GrantOrder::Fifo);
+        let _forks = unit.request(&seats[0]);
+
+        assert_eq!(unit.request(&seats[1]), None);
+        assert_eq!((unit.grants(), unit.refusals()), (1, 1));
+    }
+
+    #[test]
+    fn returned_forks_can_be_granted_again() {
+        let seats = seats(3);
+        let mut unit = Arbitrator::new(3, GrantOrder::Fifo);
+        let (left, right) = unit.request(&seats[0]).unwrap();
+
+        unit.release(&seats[0], left, right).unwrap();
+
+        assert_ne!(unit.request(&seats[1]), None);
+    }
+
+    #[test]
+    fn fifo_serves_the_neighbour_who_asked_first() {
+        let seats = seats(5);
+        let mut unit = Arbitrator::new(5, GrantOrder::Fifo);
+        let (left, right) = unit.request(&seats[1]).unwrap();
+        unit.request(&seats[2]); // refused, seat 1 is eating
+        unit.release(&seats[1], left, right).unwrap();
+
+        assert_eq!(unit.request(&seats[3]), None);
+        assert_ne!(unit.request(&seats[2]), None);
+    }
+
+    #[test]
+    fn fifo_lets_a_later_philosopher_eat_when_no_neighbour_waits() {
+        let seats = seats(5);
+        let mut unit = Arbitrator::new(5, GrantOrder::Fifo);
+        let _forks = unit.request(&seats[1]);
+        unit.request(&seats[2]); // refused, seat 1 is eating
+
+        assert_ne!(unit.request(&seats[4]), None);
+    }
+
+    #[test]
+    fn hunger_serves_the_neighbour_who_missed_more_meals() {
+        let seats = seats(5);
+        let mut unit = Arbitrator::new(5, GrantOrder::Hunger);
+        let (left, right) = unit.request(&seats[3]).unwrap();
+        unit.release(&seats[3], left, right).unwrap();
+        let (left_0, right_0) = unit.request(&seats[0]).unwrap();
+        let (left_2, right_2) = unit.request(&seats[2]).unwrap();
+        unit.request(&seats[3]); // refused, seat 2 is eating
+        unit.request(&seats[4]); // refused, seat 0 is eating
+        unit.release(&seats[0], left_0, right_0).unwrap();
+        unit.release(&seats[2], left_2, right_2).unwrap();
+
+        assert_eq!(unit.request(&seats[3]), None);
+        assert_ne!(unit.request(&seats[4]), None);
+    }
+
+    #[test]
+    fn hunger_serves_the_earlier_request_on_a_tie() {
+        let seats = seats(5);
+        let mut unit = Arbitrator::new(5, GrantOrder::Hunger);
+        let (left_0, right_0) = unit.request(&seats[0]).unwrap();
+        let (left_2, right_2) = unit.request(&seats[2]).unwrap();
+        unit.request(&seats[4]); // refused, seat 0 is eating
+        unit.request(&seats[3]); // refused, seat 2 is eating
+        unit.release(&seats[0], left_0, right_0).unwrap();
+        unit.release(&seats[2], left_2, right_2).unwrap();
+
+        assert_eq!(unit.request(&seats[3]), None);
+        assert_ne!(unit.request(&seats[4]), None);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::dining_philosophers::fork::{keep_refused, Fork};
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::waiter_impl::arbitrator::ArbitratorInteraction;
+use crate::dining_philosophers::waiter_impl::thinking::Thinking;
+
+#[derive(Debug, PartialEq)]
+pub struct Eating {
+    left_fork: Option<Fork>,
+    right_fork: Option<Fork>,
+    arbitrator_interaction: Option<ArbitratorInteraction>,
+}
+
+impl Eating {
+    pub fn new(left_fork: Fork, right_fork: Fork, arbitrator_interaction: ArbitratorInteraction) -> Eating {
+        Eating {
+            left_fork: Some(left_fork),
+            right_fork: Some(right_fork),
+            arbitrator_interaction: Some(arbitrator_interaction),
+        }
+    }
+
+    /// Back to thinking, unless the table refuses the forks and the philosopher keeps eating with them.
+    fn return_forks(&mut self, arbitrator_interaction: ArbitratorInteraction) -> Box<dyn StateMachine + Send> {
+        let refused = arbitrator_interaction.return_forks(self.left_fork.take().unwrap(), self.right_fork.take().unwrap());
+        match keep_refused(arbitrator_interaction.position(), refused) {
+            Some((left_fork, right_fork)) => Box::new(Eating::new(left_fork, right_fork, arbitrator_interaction)),
+            None => Box::new(Thinking::new(arbitrator_interaction)),
+        }
+    }
+}
+
+impl StateMachine for Eating {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.arbitrator_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(a) => {
+                debug!("{}: Both forks back to the waiter, to thinking", a.position());
+                self.return_forks(a)
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Eating
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::waiter_impl::arbitrator::{Arbitrator, ArbitratorInteraction, GrantOrder};
+    use crate::dining_philosophers::waiter_impl::eating::Eating;
+
+    fn seat() -> ArbitratorInteraction {
+        let arbitrator = Arc::new(Mutex::new(Arbitrator::new(2, GrantOrder::Fifo)));
+        ArbitratorInteraction { table_interaction: Table::new(2).get_interactions().remove(0), arbitrator }
+    }
+
+    #[test]
+    fn state_is_eating() {
+        let seat = seat();
+        let (left_fork, right_fork) = seat.ask_for_forks().unwrap();
+        let unit = Eating::new(left_fork, right_fork, seat);
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn returns_both_forks_and_thinks() {
+        let seat = seat();
+        let table = Arc::clone(&seat.table_interaction.table);
+        let (left_fork, right_fork) = seat.ask_for_forks().unwrap();
+        let mut unit: Box<dyn StateMachine> = Box::new(Eating::new(left_fork, right_fork, seat));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+        assert_eq!(*table.lock().unwrap(), Table::new(2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let seat = seat();
+        let (left_fork, right_fork) = seat.ask_for_forks().unwrap();
+        let mut unit = Eating::new(left_fork, right_fork, seat);
+
+        unit.transition();
+        unit.transition();
+    }
+}
@@ -0,0 +1,4 @@
+pub mod arbitrator;
+pub mod thinking;
+pub mod eating;
+pub mod waiter;
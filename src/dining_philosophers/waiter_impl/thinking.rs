@@ -0,0 +1,100 @@
+use crate::dining_philosophers::fork::Fork;
+use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+use crate::dining_philosophers::waiter_impl::arbitrator::ArbitratorInteraction;
+use crate::dining_philosophers::waiter_impl::eating::Eating;
+
+#[derive(Debug, PartialEq)]
+pub struct Thinking {
+    arbitrator_interaction: Option<ArbitratorInteraction>,
+}
+
+impl Thinking {
+    pub fn new(arbitrator_interaction: ArbitratorInteraction) -> Thinking {
+        Thinking {
+            arbitrator_interaction: Some(arbitrator_interaction),
+        }
+    }
+
+    fn take_forks(&mut self, left_fork: Fork, right_fork: Fork, arbitrator_interaction: ArbitratorInteraction) -> Eating {
+        Eating::new(left_fork, right_fork, arbitrator_interaction)
+    }
+}
+
+impl StateMachine for Thinking {
+    fn transition(&mut self) -> Box<dyn StateMachine + Send> {
+        match self.arbitrator_interaction.take() {
+            None => { panic!("No longer valid") }
+            Some(a) => {
+                match a.ask_for_forks() {
+                    None => {
+                        debug!("{}: Refused by the waiter, still thinking", a.position());
+                        Box::new(Thinking::new(a))
+                    }
+                    Some((left_fork, right_fork)) => {
+                        debug!("{}: Granted both forks. Eating!", a.position());
+                        Box::new(self.take_forks(left_fork, right_fork, a))
+                    }
+                }
+            }
+        }
+    }
+
+    fn state(&self) -> State {
+        State::Thinking
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
+    use crate::dining_philosophers::table::Table;
+    use crate::dining_philosophers::waiter_impl::arbitrator::{Arbitrator, ArbitratorInteraction, GrantOrder};
+    use crate::dining_philosophers::waiter_impl::thinking::Thinking;
+
+    fn seats(size: usize) -> Vec<ArbitratorInteraction> {
+        let arbitrator = Arc::new(Mutex::new(Arbitrator::new(size, GrantOrder::Fifo)));
+        Table::new(size).get_interactions()
+            .into_iter()
+            .map(|t| ArbitratorInteraction { table_interaction: t, arbitrator: Arc::clone(&arbitrator) })
+            .collect()
+    }
+
+    #[test]
+    fn state_is_thinking() {
+        let unit = Thinking::new(seats(2).remove(0));
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    fn changes_to_eating_when_granted() {
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats(2).remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Eating);
+    }
+
+    #[test]
+    fn stays_thinking_when_refused() {
+        let mut seats = seats(3);
+        let neighbour = seats.remove(0);
+        let _forks = neighbour.ask_for_forks();
+        let mut unit: Box<dyn StateMachine> = Box::new(Thinking::new(seats.remove(0)));
+
+        unit = unit.transition();
+
+        assert_eq!(unit.state(), State::Thinking);
+    }
+
+    #[test]
+    #[should_panic]
+    fn cannot_call_transition_twice_on_same_instance() {
+        let mut unit = Thinking::new(seats(2).remove(0));
+
+        unit.transition();
+        unit.transition();
+    }
+}
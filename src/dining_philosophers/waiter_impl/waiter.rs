@@ -0,0 +1,83 @@
+use std::sync::{Arc, Mutex};
+
+use crate::dining_philosophers::analysis::Counters;
+use crate::dining_philosophers::philosopher::state_machine::StateMachine;
+use crate::dining_philosophers::strategy::Strategy;
+use crate::dining_philosophers::table::TableInteraction;
+use crate::dining_philosophers::waiter_impl::arbitrator::{Arbitrator, ArbitratorInteraction, GrantOrder};
+use crate::dining_philosophers::waiter_impl::thinking::Thinking;
+
+/// The waiter solution: philosophers ask one arbitrator for both forks at once,
+/// so nobody ever holds a single fork.
+pub struct Waiter {
+    arbitrator: Arc<Mutex<Arbitrator>>,
+}
+
+impl Waiter {
+    pub fn new(number_of_philosophers: usize, grant_order: GrantOrder) -> Waiter {
+        Waiter {
+            arbitrator: Arc::new(Mutex::new(Arbitrator::new(number_of_philosophers, grant_order))),
+        }
+    }
+}
+
+impl Strategy for Waiter {
+    fn seat(&mut self, table_interaction: TableInteraction) -> Box<dyn StateMachine + Send> {
+        Box::new(Thinking::new(ArbitratorInteraction {
+            table_interaction,
+            arbitrator: Arc::clone(&self.arbitrator),
+        }))
+    }
+
+    fn counters(&self) -> Counters {
+        let arbitrator = self.arbitrator.lock().unwrap();
+        vec![("grants", arbitrator.grants()), ("refusals", arbitrator.refusals())]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dining_philosophers::philosopher::state_machine::State;
+    use crate::dining_philosophers::simulation::run_in_lockstep;
+    use crate::dining_philosophers::strategy::Strategy;
+    use crate::dining_philosophers::waiter_impl::arbitrator::GrantOrder;
+    use crate::dining_philosophers::waiter_impl::waiter::Waiter;
+
+    #[test]
+    fn everyone_eats_in_either_grant_order() {
+        for &order in &[GrantOrder::Fifo, GrantOrder::Hunger] {
+            for size in 2..=16 {
+                let results = run_in_lockstep(&mut Waiter::new(size, order), size, 4 * size);
+
+                for (id, history) in &results {
+                    assert!(history.contains(&State::Eating), "{:?}: philosopher {} of {} never ate", order, id, size);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn nobody_holds_a_single_fork() {
+        let results = run_in_lockstep(&mut Waiter::new(5, GrantOrder::Fifo), 5, 100);
+
+        for history in results.values() {
+            assert!(history.iter().all(|s| *s == State::Thinking || *s == State::Eating));
+        }
+    }
+
+    #[test]
+    fn counts_every_request() {
+        let mut unit = Waiter::new(5, GrantOrder::Hunger);
+        let results = run_in_lockstep(&mut unit, 5, 10);
+
+        let counters = unit.counters();
+        let meals: usize = results.values()
+            .map(|history| history.iter().filter(|s| **s == State::Eating).count())
+            .sum();
+        let thinking_steps: usize = results.values()
+            .map(|history| history[..10].iter().filter(|s| **s == State::Thinking).count())
+            .sum();
+
+        assert_eq!(counters, vec![("grants", meals), ("refusals", thinking_steps - meals)]);
+    }
+}
@@ -0,0 +1,6 @@
+#[macro_use]
+extern crate log;
+extern crate rand;
+
+pub mod thread_pool;
+pub mod dining_philosophers;
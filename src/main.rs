@@ -1,85 +1,281 @@
 extern crate env_logger;
 #[macro_use]
 extern crate log;
+extern crate dining_philosophers;
 
-use std::collections::HashMap;
+use std::env;
+use std::process;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
-use crate::dining_philosophers::analysis::compute_average_score;
-use crate::dining_philosophers::philosopher::philosopher::Philosopher;
-use crate::dining_philosophers::philosopher::state_machine::{State, StateMachine};
-use crate::dining_philosophers::resource_hierarchy_impl::thinking::Thinking;
-use crate::dining_philosophers::table::{Table, TableInteraction};
-use crate::thread_pool::thread_pool::ThreadPool;
-
-mod thread_pool;
-mod dining_philosophers;
+use dining_philosophers::dining_philosophers::adversary_impl::adversary::Adversary;
+use dining_philosophers::dining_philosophers::adversary_impl::robustness::robustness_report;
+use dining_philosophers::dining_philosophers::analysis::{average_counters, compute_average_fairness, compute_average_score, compute_average_score_by_philosopher, compute_average_score_by_strategy, compute_average_session_throughput, compute_average_wait_time, compute_longest_starvation, fairness_one_run, longest_starvation_one_run, score_one_run, RunResults};
+use dining_philosophers::dining_philosophers::drinking_impl::drinkers::Drinkers;
+use dining_philosophers::dining_philosophers::footman_impl::footman::Footman;
+use dining_philosophers::dining_philosophers::lehmann_rabin_impl::lehmann_rabin::LehmannRabin;
+use dining_philosophers::dining_philosophers::mixed::Mixed;
+use dining_philosophers::dining_philosophers::optimiser::evolution::{evolve, Settings};
+use dining_philosophers::dining_philosophers::optimiser::fitness::Fitness;
+use dining_philosophers::dining_philosophers::policy_table_impl::policy_table::PolicyTable;
+use dining_philosophers::dining_philosophers::policy_table_impl::scripted::Scripted;
+use dining_philosophers::dining_philosophers::q_learning_impl::q_learning::{QLearning, Training};
+use dining_philosophers::dining_philosophers::q_learning_impl::q_table::QTable;
+use dining_philosophers::dining_philosophers::registry::Registry;
+use dining_philosophers::dining_philosophers::resource_hierarchy_impl::fork_choice::ForkChoice;
+use dining_philosophers::dining_philosophers::resource_hierarchy_impl::policy::Policy;
+use dining_philosophers::dining_philosophers::resource_hierarchy_impl::release::Release;
+use dining_philosophers::dining_philosophers::resource_hierarchy_impl::resource_hierarchy::{NewPolicy, ResourceHierarchy};
+use dining_philosophers::dining_philosophers::simulation::{NewStrategy, Runs, run_in_lockstep, run_in_lockstep_on, run_several_simulations};
+use dining_philosophers::dining_philosophers::topology::Topology;
+use dining_philosophers::dining_philosophers::tournament::tournament::{play, Settings as TournamentSettings};
+use dining_philosophers::dining_philosophers::waiter_impl::arbitrator::GrantOrder;
+use dining_philosophers::dining_philosophers::waiter_impl::waiter::Waiter;
 
+static NUMBER_OF_PHILOSOPHERS: usize = 5;
+static ITERATIONS_PER_RUN: i32 = 200;
+static TOTAL_RUNS: usize = 50;
 
+/// Runs the strategy named on the command line, or every registered strategy when none is named.
+/// `policy-table <file>` runs philosophers following the policy table in the file instead, and
+/// `optimise [seed]` evolves a policy table, `q-learning <file> [episodes]` trains the
+/// Q-table saved in the file, or a new one, and runs it frozen, `mixed` seats several
+/// strategies at one table and scores each of them, `robustness` seats an adversary among
+/// the philosophers of every strategy, `tournament [seed]` ranks the strategies on an Elo
+/// leaderboard, `topology <preset> [size]` runs every strategy that fits a table laid out as
+/// `ring`, `line`, `star`, `grid` or `complete`, and `drinking [preset] [size]` runs drinking
+/// philosophers on such a table.
 fn main() {
     env_logger::init();
-    static NUMBER_OF_PHILOSOPHERS: usize = 5;
-    static ITERATIONS_PER_RUN: i32 = 200;
-    static TOTAL_RUNS: usize = 50;
-    let mut results: Arc<Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>>> = create_results_vector(TOTAL_RUNS, NUMBER_OF_PHILOSOPHERS);
+    let registry = Registry::new();
+    let names = match env::args().nth(1) {
+        Some(ref name) if name == "policy-table" => {
+            run_policy_table(env::args().nth(2));
+            return;
+        }
+        Some(ref name) if name == "q-learning" => {
+            q_learning(env::args().nth(2), env::args().nth(3));
+            return;
+        }
+        Some(ref name) if name == "optimise" => {
+            optimise(env::args().nth(2));
+            return;
+        }
+        Some(ref name) if name == "mixed" => {
+            run_mixed();
+            return;
+        }
+        Some(ref name) if name == "tournament" => {
+            tournament(&registry, env::args().nth(2));
+            return;
+        }
+        Some(ref name) if name == "topology" => {
+            run_on_topology(&registry, env::args().nth(2), env::args().nth(3));
+            return;
+        }
+        Some(ref name) if name == "drinking" => {
+            drinking(env::args().nth(2), env::args().nth(3));
+            return;
+        }
+        Some(ref name) if name == "robustness" => {
+            for damage in robustness_report(&registry, &Adversary::ALL, NUMBER_OF_PHILOSOPHERS, ITERATIONS_PER_RUN as usize) {
+                info!("{} next to a {}: score {} -> {} ({:+}), {} starved neighbours",
+                      damage.strategy, damage.adversary, damage.baseline, damage.score, damage.score_change(), damage.starved_neighbours);
+            }
+            return;
+        }
+        Some(name) => {
+            if registry.get(&name).is_none() {
+                error!("Unknown strategy {}, expected one of {:?}", name, registry.names());
+                process::exit(1);
+            }
+            vec![name]
+        }
+        None => registry.names().iter().map(|name| name.to_string()).collect(),
+    };
 
-    run_several_simulations(&mut results, TOTAL_RUNS, NUMBER_OF_PHILOSOPHERS, ITERATIONS_PER_RUN);
+    for name in &names {
+        run(name, registry.get(name).unwrap());
+    }
 
-    info!("*** Final Score = {:#?} ***", compute_average_score(&results));
-}
+    if names.iter().any(|name| name == "footman") {
+        for limit in 1..=NUMBER_OF_PHILOSOPHERS {
+            run(&format!("footman admitting {}", limit), Arc::new(move |size| Box::new(Footman::with_limit(size, limit))));
+        }
+    }
+
+    if names.iter().any(|name| name == "lehmann-rabin") {
+        let mut lehmann_rabin = LehmannRabin::new(NUMBER_OF_PHILOSOPHERS, 0);
+        run_in_lockstep(&mut lehmann_rabin, NUMBER_OF_PHILOSOPHERS, ITERATIONS_PER_RUN as usize);
+        info!("*** lehmann-rabin: meals by seat in one run = {:?} ***", lehmann_rabin.meals());
+    }
 
-fn run_several_simulations(results: &mut Arc<Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>>>, total_runs: usize, number_of_philosophers: usize, iterations: i32) {
-    let pool = ThreadPool::new(20);
-    for i in 0..total_runs {
-        let store = Arc::clone(&results[i]);
-        pool.execute(move || {
-            run_simulation(number_of_philosophers, iterations, &store, i)
-        });
+    if names.iter().any(|name| name == "resource-hierarchy") {
+        let releases: Vec<(&str, NewPolicy)> = vec![
+            ("left-first", |_| Policy::new(ForkChoice::AlwaysLeft).releasing(Release::LeftFirst)),
+            ("right-first", |_| Policy::new(ForkChoice::AlwaysLeft).releasing(Release::RightFirst)),
+            ("both-at-once", |_| Policy::new(ForkChoice::AlwaysLeft).releasing(Release::BothAtOnce)),
+            ("random", |position| Policy::new(ForkChoice::AlwaysLeft).releasing(Release::random(position as u64))),
+        ];
+        for (release, policy) in releases {
+            run(&format!("give way, release {}", release), Arc::new(move |_| Box::new(ResourceHierarchy::with_policy(policy))));
+        }
     }
 }
 
-fn run_simulation(number_of_philosophers: usize, iterations: i32, results: &Arc<Mutex<HashMap<usize, Vec<State>>>>, run_number: usize) {
-    info!("{}: Simulating {} philosophers for {} steps", run_number, number_of_philosophers, iterations);
+fn run_policy_table(path: Option<String>) {
+    let path = path.unwrap_or_else(|| {
+        error!("Expected the path of a policy table file");
+        process::exit(1);
+    });
+    let policy = Arc::new(PolicyTable::load(&path).unwrap_or_else(|e| {
+        error!("Rejected {}: {}", path, e);
+        process::exit(1);
+    }));
+    run(&path, Arc::new(move |_| Box::new(Scripted::new(Arc::clone(&policy), 0))));
+}
 
-    let mut table_interactions: Vec<TableInteraction> = Table::new(number_of_philosophers)
-        .get_interactions();
+fn optimise(seed: Option<String>) {
+    let seed = seed.map_or(0, |seed| seed.parse().unwrap_or_else(|_| {
+        error!("Expected a number as the seed, got {}", seed);
+        process::exit(1);
+    }));
+    let settings = Settings { seed, ..Settings::default() };
+    let fitness = Fitness { seed, ..Fitness::default() };
 
-    let pool = ThreadPool::new(number_of_philosophers);
+    let outcome = evolve(&settings, &fitness);
 
-    for _ in 0..number_of_philosophers {
-        let table_interaction = table_interactions.pop().unwrap();
+    info!("*** Best fitness = {} with {:?} ***", outcome.fitness, outcome.best);
+    info!("Best policy table:\n{}", outcome.best.policy_table());
+    let policy = Arc::new(outcome.best.policy_table());
+    run("optimised policy", Arc::new(move |_| Box::new(Scripted::new(Arc::clone(&policy), seed))));
+}
 
-        let mut p = Philosopher::new(
-            table_interaction.position,
-            Box::new(Thinking::new(table_interaction)));
+fn tournament(registry: &Registry, seed: Option<String>) {
+    let seed = seed.map_or(0, |seed| seed.parse().unwrap_or_else(|_| {
+        error!("Expected a number as the seed, got {}", seed);
+        process::exit(1);
+    }));
+    let outcome = play(registry, &registry.names(), &TournamentSettings { seed, ..TournamentSettings::default() });
 
-        let results_copy = Arc::clone(results);
+    info!("*** Leaderboard after {} matches, seed {} ***", outcome.matches.len(), seed);
+    for (place, standing) in outcome.leaderboard.ranking().iter().enumerate() {
+        info!("    {}. {} {:.0} ({} matches)", place + 1, standing.name, standing.rating, standing.matches);
+    }
+}
 
-        pool.execute(move || {
-            for __ in 0..iterations {
-                p.act();
-            }
-            p.write(&mut results_copy.lock().unwrap());
-        })
+fn q_learning(path: Option<String>, episodes: Option<String>) {
+    let path = path.unwrap_or_else(|| {
+        error!("Expected the path of a Q-table file");
+        process::exit(1);
+    });
+    let training = Training {
+        episodes: episodes.map_or(Training::default().episodes, |episodes| episodes.parse().unwrap_or_else(|_| {
+            error!("Expected a number of episodes, got {}", episodes);
+            process::exit(1);
+        })),
+        ..Training::default()
+    };
+    let q_table = match Path::new(&path).exists() {
+        true => QTable::load(&path).unwrap_or_else(|e| {
+            error!("Cannot read the Q-table in {}: {}", path, e);
+            process::exit(1);
+        }),
+        false => QTable::new(),
+    };
+    let q_table = Arc::new(Mutex::new(q_table));
+
+    let scores = training.train(&q_table);
+    for (episode, chunk) in scores.chunks(100).enumerate() {
+        info!("Episodes {} to {}: mean score {}", episode * 100, episode * 100 + chunk.len() - 1, chunk.iter().sum::<f32>() / chunk.len() as f32);
     }
+    if let Err(e) = q_table.lock().unwrap().save(&path) {
+        error!("Cannot save the Q-table to {}: {}", path, e);
+        process::exit(1);
+    }
+    run(&format!("frozen {}", path), Arc::new(move |_| Box::new(QLearning::frozen(Arc::clone(&q_table), 0))));
+}
+
+fn topology(preset: Option<String>, size: Option<String>) -> (String, Topology) {
+    let size = size.map_or(NUMBER_OF_PHILOSOPHERS, |size| size.parse().unwrap_or_else(|_| {
+        error!("Expected a number of philosophers, got {}", size);
+        process::exit(1);
+    }));
+    let preset = preset.unwrap_or_else(|| String::from("ring"));
+    let topology = Topology::preset(&preset, size).unwrap_or_else(|| {
+        error!("No {} topology for {} philosophers", preset, size);
+        process::exit(1);
+    });
+    (preset, topology)
 }
 
-fn create_results_vector(outer_vec_size: usize, hash_map_size: usize) -> Arc<Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>>> {
-    let mut v: Vec<Arc<Mutex<HashMap<usize, Vec<State>>>>> = Vec::with_capacity(outer_vec_size);
-    for __ in 0..outer_vec_size {
-        v.push(Arc::new(Mutex::new(HashMap::with_capacity(hash_map_size))))
+/// One lockstep run of every registered strategy that fits the topology.
+fn run_on_topology(registry: &Registry, preset: Option<String>, size: Option<String>) {
+    let (preset, topology) = topology(preset, size);
+    let size = topology.philosophers();
+
+    for name in registry.names() {
+        if !registry.builds_for(name, size) {
+            info!("*** {}: cannot seat {} philosophers ***", name, size);
+            continue;
+        }
+        let mut strategy = registry.get(name).unwrap()(size);
+        if !strategy.fits(&topology) {
+            info!("*** {}: does not fit a {} ***", name, preset);
+            continue;
+        }
+        let results = run_in_lockstep_on(strategy.as_mut(), &topology, ITERATIONS_PER_RUN as usize);
+        info!("*** {} on a {}: Score = {} ***", name, preset, score_one_run(&results));
+        info!("    fairness = {}", fairness_one_run(&results));
+        info!("    longest streak without eating = {}", longest_starvation_one_run(&results));
     }
-    Arc::new(v)
 }
 
-pub struct AlwaysThinking {}
+/// Lockstep runs of drinking philosophers, one for every seed.
+fn drinking(preset: Option<String>, size: Option<String>) {
+    let (preset, topology) = topology(preset, size);
+    if !topology.is_conflict_graph() {
+        error!("No drinking philosophers round a {} of {}: every bottle needs two of them", preset, topology.philosophers());
+        process::exit(1);
+    }
+    let results: Vec<Arc<Mutex<RunResults>>> = (0..TOTAL_RUNS as u64)
+        .map(|seed| Arc::new(Mutex::new(run_in_lockstep_on(&mut Drinkers::new(&topology, seed), &topology, ITERATIONS_PER_RUN as usize))))
+        .collect();
+
+    info!("*** drinking philosophers on a {}: {} sessions per step ***", preset, compute_average_session_throughput(&results));
+    info!("    steps thirsty per session = {}", compute_average_wait_time(&results));
+    info!("    longest streak without drinking = {}", compute_longest_starvation(&results));
+}
 
-impl StateMachine for AlwaysThinking {
-    fn transition(&mut self) -> Box<StateMachine + Send> {
-        Box::new(AlwaysThinking {})
+/// Two waiter clients, one greedy philosopher and resource hierarchy philosophers at the other seats.
+fn mixed_table(size: usize) -> Mixed {
+    assert!(size >= 5, "A mixed table needs at least 5 philosophers, not {}", size);
+    Mixed::new()
+        .seating("waiter", Box::new(Waiter::new(size, GrantOrder::Fifo)), 2)
+        .seating("resource-hierarchy", Box::new(ResourceHierarchy::new()), size - 3)
+        .seating("greedy", Box::new(Adversary::Greedy), 1)
+}
+
+fn run_mixed() {
+    let seating = mixed_table(NUMBER_OF_PHILOSOPHERS).seats();
+    let runs = run("mixed table", Arc::new(|size| Box::new(mixed_table(size))));
+
+    for (strategy, score) in compute_average_score_by_strategy(&runs.results, &seating) {
+        info!("    {} score = {}", strategy, score);
     }
+    for (id, score) in compute_average_score_by_philosopher(&runs.results) {
+        info!("    philosopher {} ({}) score = {}", id, seating[id], score);
+    }
+}
+
+fn run(name: &str, new_strategy: NewStrategy) -> Runs {
+    let runs = run_several_simulations(new_strategy, TOTAL_RUNS, NUMBER_OF_PHILOSOPHERS, ITERATIONS_PER_RUN);
 
-    fn state(&self) -> State {
-        State::Thinking
+    info!("*** {}: Final Score = {:#?} ***", name, compute_average_score(&runs.results));
+    info!("    fairness = {}", compute_average_fairness(&runs.results));
+    info!("    longest streak without eating = {}", compute_longest_starvation(&runs.results));
+    for (counter, average) in average_counters(&runs.counters) {
+        info!("    {} per run = {}", counter, average);
     }
+    runs
 }
@@ -1,4 +1,4 @@
 mod message;
 mod worker;
+#[allow(clippy::module_inception)]
 pub mod thread_pool;
-